@@ -1,15 +1,18 @@
 use hello_world::greeter_client::GreeterClient;
 use hello_world::HelloRequest;
-use opentelemetry::{global, propagation::Injector};
+use opentelemetry::global;
+use opentelemetry_http::grpc::GrpcClientLayer;
 use opentelemetry_sdk::{
     propagation::TraceContextPropagator, runtime::Tokio, trace::TracerProvider,
 };
 use opentelemetry_stdout::SpanExporter;
 
 use opentelemetry::{
-    trace::{SpanKind, TraceContextExt, Tracer},
+    trace::{FutureExt, TraceContextExt, Tracer},
     Context, KeyValue,
 };
+use tonic::transport::Channel;
+use tower_layer::Layer;
 
 fn init_tracer() {
     global::set_text_map_propagator(TraceContextPropagator::new());
@@ -21,19 +24,6 @@ fn init_tracer() {
     global::set_tracer_provider(provider);
 }
 
-struct MetadataMap<'a>(&'a mut tonic::metadata::MetadataMap);
-
-impl<'a> Injector for MetadataMap<'a> {
-    /// Set a key and value in the MetadataMap.  Does nothing if the key or value are not valid inputs
-    fn set(&mut self, key: &str, value: String) {
-        if let Ok(key) = tonic::metadata::MetadataKey::from_bytes(key.as_bytes()) {
-            if let Ok(val) = tonic::metadata::MetadataValue::try_from(&value) {
-                self.0.insert(key, val);
-            }
-        }
-    }
-}
-
 #[allow(clippy::derive_partial_eq_without_eq)] // tonic don't derive Eq for generated types. We shouldn't manually change it.
 pub mod hello_world {
     tonic::include_proto!("helloworld");
@@ -41,23 +31,18 @@ pub mod hello_world {
 
 async fn greet() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
     let tracer = global::tracer("example/client");
-    let span = tracer
-        .span_builder("Greeter/client")
-        .with_kind(SpanKind::Client)
-        .with_attributes([KeyValue::new("component", "grpc")])
-        .start(&tracer);
+    let span = tracer.start("greet");
     let cx = Context::current_with_span(span);
-    let mut client = GreeterClient::connect("http://[::1]:50051").await?;
 
-    let mut request = tonic::Request::new(HelloRequest {
-        name: "Tonic".into(),
-    });
+    // The layer records a client span for each call, parented to the current
+    // context, and propagates its context to the server.
+    let channel = Channel::from_static("http://[::1]:50051").connect().await?;
+    let mut client = GreeterClient::new(GrpcClientLayer::new().layer(channel));
 
-    global::get_text_map_propagator(|propagator| {
-        propagator.inject_context(&cx, &mut MetadataMap(request.metadata_mut()))
+    let request = tonic::Request::new(HelloRequest {
+        name: "Tonic".into(),
     });
-
-    let response = client.say_hello(request).await;
+    let response = client.say_hello(request).with_context(cx.clone()).await;
 
     let status = match response {
         Ok(_res) => "OK".to_string(),
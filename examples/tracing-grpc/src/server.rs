@@ -1,10 +1,7 @@
 use hello_world::greeter_server::{Greeter, GreeterServer};
 use hello_world::{HelloReply, HelloRequest};
-use opentelemetry::{
-    global,
-    propagation::Extractor,
-    trace::{Span, SpanKind, Tracer},
-};
+use opentelemetry::{global, trace::TraceContextExt, Context};
+use opentelemetry_http::grpc::GrpcServerLayer;
 use opentelemetry_sdk::{
     propagation::TraceContextPropagator, runtime::Tokio, trace::TracerProvider,
 };
@@ -26,29 +23,9 @@ pub mod hello_world {
     tonic::include_proto!("helloworld"); // The string specified here must match the proto package name
 }
 
-struct MetadataMap<'a>(&'a tonic::metadata::MetadataMap);
-
-impl<'a> Extractor for MetadataMap<'a> {
-    /// Get a value for a key from the MetadataMap.  If the value can't be converted to &str, returns None
-    fn get(&self, key: &str) -> Option<&str> {
-        self.0.get(key).and_then(|metadata| metadata.to_str().ok())
-    }
-
-    /// Collect all the keys from the MetadataMap.
-    fn keys(&self) -> Vec<&str> {
-        self.0
-            .keys()
-            .map(|key| match key {
-                tonic::metadata::KeyRef::Ascii(v) => v.as_str(),
-                tonic::metadata::KeyRef::Binary(v) => v.as_str(),
-            })
-            .collect::<Vec<_>>()
-    }
-}
-
-fn expensive_fn<S: Span>(to_print: String, span: &mut S) {
+fn expensive_fn(to_print: String) {
     std::thread::sleep(std::time::Duration::from_millis(20));
-    span.add_event(to_print, vec![]);
+    Context::current().span().add_event(to_print, vec![]);
 }
 
 #[derive(Debug, Default)]
@@ -60,16 +37,9 @@ impl Greeter for MyGreeter {
         &self,
         request: Request<HelloRequest>, // Accept request of type HelloRequest
     ) -> Result<Response<HelloReply>, Status> {
-        let parent_cx =
-            global::get_text_map_propagator(|prop| prop.extract(&MetadataMap(request.metadata())));
-        let tracer = global::tracer("example/server");
-        let mut span = tracer
-            .span_builder("Greeter/server")
-            .with_kind(SpanKind::Server)
-            .start_with_context(&tracer, &parent_cx);
-
+        // The handler runs within the server span recorded by the layer.
         let name = request.into_inner().name;
-        expensive_fn(format!("Got name: {name:?}"), &mut span);
+        expensive_fn(format!("Got name: {name:?}"));
 
         // Return an instance of type HelloReply
         let reply = hello_world::HelloReply {
@@ -88,6 +58,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>
     let greeter = MyGreeter::default();
 
     Server::builder()
+        .layer(GrpcServerLayer::new())
         .add_service(GreeterServer::new(greeter))
         .serve(addr)
         .await?;
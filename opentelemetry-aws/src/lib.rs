@@ -0,0 +1,59 @@
+//! Integrations of [AWS X-Ray] with OpenTelemetry.
+//!
+//! The [`trace::XrayPropagator`] reads and writes span contexts in the
+//! `X-Amzn-Trace-Id` header, which Application Load Balancers, API Gateway and
+//! Lambda use to pass the trace on. The [`trace::XrayIdGenerator`] generates
+//! trace ids X-Ray accepts, as it requires them to start with their creation
+//! time.
+//!
+//! ```
+//! use opentelemetry::global;
+//! use opentelemetry_aws::trace::{XrayIdGenerator, XrayPropagator};
+//! use opentelemetry_sdk::trace::{Config, TracerProvider};
+//!
+//! global::set_text_map_propagator(XrayPropagator::new());
+//!
+//! let provider = TracerProvider::builder()
+//!     .with_config(Config::default().with_id_generator(XrayIdGenerator::default()))
+//!     .build();
+//! global::set_tracer_provider(provider);
+//! ```
+//!
+//! *Compiler support: [requires `rustc` 1.65+][msrv]*
+//!
+//! [AWS X-Ray]: https://docs.aws.amazon.com/xray/latest/devguide/aws-xray.html
+//! [msrv]: #supported-rust-versions
+//!
+//! # Supported Rust Versions
+//!
+//! OpenTelemetry is built against the latest stable release. The minimum
+//! supported version is 1.65. The current OpenTelemetry version is not
+//! guaranteed to build on Rust versions earlier than the minimum supported
+//! version.
+//!
+//! The current stable Rust compiler and the three most recent minor versions
+//! before it will always be supported. For example, if the current stable
+//! compiler version is 1.65, the minimum supported version will not be
+//! increased past 1.62, three minor versions prior. Increasing the minimum
+//! supported compiler version is not considered a semver breaking change as
+//! long as doing so complies with this policy.
+#![warn(
+    future_incompatible,
+    missing_debug_implementations,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    unreachable_pub,
+    unused
+)]
+#![cfg_attr(
+    docsrs,
+    feature(doc_cfg, doc_auto_cfg),
+    deny(rustdoc::broken_intra_doc_links)
+)]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/open-telemetry/opentelemetry-rust/main/assets/logo.svg"
+)]
+#![cfg_attr(test, deny(warnings))]
+
+pub mod trace;
@@ -0,0 +1,63 @@
+use opentelemetry::trace::{SpanId, TraceId};
+use opentelemetry_sdk::trace::{IdGenerator, RandomIdGenerator};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// An [`IdGenerator`] generating trace ids accepted by X-Ray.
+///
+/// X-Ray rejects traces whose id doesn't start with the epoch seconds of the
+/// trace start, so the first 4 bytes of the generated trace ids hold the
+/// current time in seconds and the remaining 12 bytes are random. Span ids
+/// are random like the ones of the [`RandomIdGenerator`].
+///
+/// # Examples
+///
+/// ```
+/// use opentelemetry_aws::trace::XrayIdGenerator;
+/// use opentelemetry_sdk::trace::{Config, TracerProvider};
+///
+/// let provider = TracerProvider::builder()
+///     .with_config(Config::default().with_id_generator(XrayIdGenerator::default()))
+///     .build();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct XrayIdGenerator {
+    random: RandomIdGenerator,
+}
+
+impl IdGenerator for XrayIdGenerator {
+    fn new_trace_id(&self) -> TraceId {
+        let epoch_seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default();
+        let random = u128::from_be_bytes(self.random.new_trace_id().to_bytes());
+
+        TraceId::from_u128((u128::from(epoch_seconds as u32) << 96) | (random & ((1 << 96) - 1)))
+    }
+
+    fn new_span_id(&self) -> SpanId {
+        self.random.new_span_id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trace_id_starts_with_epoch_seconds() {
+        let before = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let trace_id = XrayIdGenerator::default().new_trace_id();
+        let after = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        let epoch_seconds = u64::from_str_radix(&trace_id.to_string()[..8], 16).unwrap();
+        assert!((before..=after).contains(&epoch_seconds));
+        assert_ne!(trace_id, XrayIdGenerator::default().new_trace_id());
+    }
+}
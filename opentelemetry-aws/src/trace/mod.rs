@@ -0,0 +1,6 @@
+//! X-Ray trace propagation and id generation.
+mod id_generator;
+mod xray_propagator;
+
+pub use id_generator::XrayIdGenerator;
+pub use xray_propagator::XrayPropagator;
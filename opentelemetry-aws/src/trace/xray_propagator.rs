@@ -0,0 +1,377 @@
+use opentelemetry::{
+    global::{self, Error},
+    propagation::{
+        text_map_propagator::FieldIter, Extractor, Injector, PropagationError, TextMapPropagator,
+    },
+    trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState},
+    Context,
+};
+
+const AWS_XRAY_TRACE_HEADER: &str = "x-amzn-trace-id";
+const AWS_XRAY_VERSION: &str = "1";
+const HEADER_ROOT_KEY: &str = "Root";
+const HEADER_PARENT_KEY: &str = "Parent";
+const HEADER_SAMPLED_KEY: &str = "Sampled";
+const HEADER_LINEAGE_KEY: &str = "Lineage";
+const SAMPLED: &str = "1";
+const NOT_SAMPLED: &str = "0";
+const REQUESTED_SAMPLING_DECISION: &str = "?";
+const MAX_LINEAGE_LEN: usize = 256;
+
+/// The lineage of an extracted X-Ray header, injected again unchanged.
+#[derive(Clone, Debug, PartialEq)]
+struct Lineage(String);
+
+/// Propagates span contexts in the [AWS X-Ray tracing header] format.
+///
+/// The `X-Amzn-Trace-Id` header holds the trace id as `Root`, the span id of
+/// the caller as `Parent` and the sampling decision as `Sampled`, e.g.
+///
+/// ```text
+/// X-Amzn-Trace-Id: Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1
+/// ```
+///
+/// Headers without a `Parent`, like the ones load balancers add to requests
+/// not traced yet, don't hold a span context, so spans started for them begin
+/// a new trace. A requested sampling decision (`Sampled=?`) is extracted as
+/// not sampled. The `Lineage` field, which AWS services use to detect
+/// request loops, is kept in the extracted context and injected again
+/// unchanged into requests made within it.
+///
+/// # Examples
+///
+/// ```
+/// use opentelemetry::{global, trace::{TraceContextExt, Tracer}, Context};
+/// use opentelemetry_aws::trace::XrayPropagator;
+/// use std::collections::HashMap;
+///
+/// global::set_text_map_propagator(XrayPropagator::new());
+///
+/// // Continue the trace of an incoming request.
+/// let mut headers = HashMap::new();
+/// headers.insert(
+///     "x-amzn-trace-id".to_string(),
+///     "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1".to_string(),
+/// );
+/// let parent_cx = global::get_text_map_propagator(|propagator| propagator.extract(&headers));
+/// assert!(parent_cx.span().span_context().is_sampled());
+///
+/// // Pass the trace on to downstream services.
+/// let span = global::tracer("example").start_with_context("request", &parent_cx);
+/// let cx = parent_cx.with_span(span);
+/// let mut outgoing = HashMap::new();
+/// global::get_text_map_propagator(|propagator| propagator.inject_context(&cx, &mut outgoing));
+/// ```
+///
+/// [AWS X-Ray tracing header]: https://docs.aws.amazon.com/xray/latest/devguide/xray-concepts.html#xray-concepts-tracingheader
+#[derive(Clone, Debug)]
+pub struct XrayPropagator {
+    fields: [String; 1],
+}
+
+impl Default for XrayPropagator {
+    fn default() -> Self {
+        XrayPropagator::new()
+    }
+}
+
+impl XrayPropagator {
+    /// Create a new `XrayPropagator`.
+    pub fn new() -> Self {
+        XrayPropagator {
+            fields: [AWS_XRAY_TRACE_HEADER.to_owned()],
+        }
+    }
+
+    /// Extract the span context and lineage from the header, if any.
+    fn extract_header(&self, extractor: &dyn Extractor) -> Option<(SpanContext, Option<Lineage>)> {
+        let header_value = extractor.get(AWS_XRAY_TRACE_HEADER)?;
+        match self.parse_header(header_value.trim()) {
+            Ok(extracted) => extracted,
+            Err(()) => {
+                global::handle_error(Error::Propagation(PropagationError::extract(
+                    "invalid X-Ray header format",
+                    "XrayPropagator",
+                )));
+                None
+            }
+        }
+    }
+
+    fn parse_header(
+        &self,
+        header_value: &str,
+    ) -> Result<Option<(SpanContext, Option<Lineage>)>, ()> {
+        let mut trace_id = None;
+        let mut span_id = None;
+        let mut trace_flags = TraceFlags::default();
+        let mut lineage = None;
+
+        for field in header_value.split(';').map(str::trim) {
+            if field.is_empty() {
+                continue;
+            }
+            let (key, value) = field.split_once('=').ok_or(())?;
+            let value = value.trim();
+            match key.trim() {
+                HEADER_ROOT_KEY => trace_id = Some(self.extract_trace_id(value)?),
+                HEADER_PARENT_KEY => span_id = Some(self.extract_span_id(value)?),
+                HEADER_SAMPLED_KEY => trace_flags = self.extract_trace_flags(value)?,
+                HEADER_LINEAGE_KEY => lineage = Some(self.extract_lineage(value)?),
+                // Other fields, e.g. `Self` added by load balancers, aren't propagated.
+                _ => {}
+            }
+        }
+
+        match (trace_id, span_id) {
+            (Some(trace_id), Some(span_id)) => {
+                let span_context =
+                    SpanContext::new(trace_id, span_id, trace_flags, true, TraceState::default());
+                if !span_context.is_valid() {
+                    return Err(());
+                }
+                Ok(Some((span_context, lineage)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Extract the trace id from `1-{8 hex digits epoch}-{24 hex digits}`.
+    fn extract_trace_id(&self, root: &str) -> Result<TraceId, ()> {
+        let mut parts = root.split('-');
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(AWS_XRAY_VERSION), Some(epoch), Some(random), None)
+                if epoch.len() == 8 && random.len() == 24 =>
+            {
+                TraceId::from_hex(&format!("{epoch}{random}")).map_err(|_| ())
+            }
+            _ => Err(()),
+        }
+    }
+
+    fn extract_span_id(&self, parent: &str) -> Result<SpanId, ()> {
+        if parent.len() != 16 {
+            return Err(());
+        }
+        SpanId::from_hex(parent).map_err(|_| ())
+    }
+
+    fn extract_trace_flags(&self, sampled: &str) -> Result<TraceFlags, ()> {
+        match sampled {
+            SAMPLED => Ok(TraceFlags::SAMPLED),
+            NOT_SAMPLED | REQUESTED_SAMPLING_DECISION => Ok(TraceFlags::default()),
+            _ => Err(()),
+        }
+    }
+
+    fn extract_lineage(&self, lineage: &str) -> Result<Lineage, ()> {
+        if lineage.is_empty()
+            || lineage.len() > MAX_LINEAGE_LEN
+            || !lineage.bytes().all(|b| b.is_ascii_graphic())
+        {
+            return Err(());
+        }
+        Ok(Lineage(lineage.to_string()))
+    }
+}
+
+impl TextMapPropagator for XrayPropagator {
+    fn inject_context(&self, cx: &Context, injector: &mut dyn Injector) {
+        let span = cx.span();
+        let span_context = span.span_context();
+        if !span_context.is_valid() {
+            return;
+        }
+
+        let trace_id = span_context.trace_id().to_string();
+        let sampled = if span_context.is_sampled() {
+            SAMPLED
+        } else {
+            NOT_SAMPLED
+        };
+        let mut header_value = format!(
+            "{HEADER_ROOT_KEY}={AWS_XRAY_VERSION}-{}-{};{HEADER_PARENT_KEY}={};{HEADER_SAMPLED_KEY}={sampled}",
+            &trace_id[..8],
+            &trace_id[8..],
+            span_context.span_id(),
+        );
+        if let Some(Lineage(lineage)) = cx.get::<Lineage>() {
+            header_value.push_str(&format!(";{HEADER_LINEAGE_KEY}={lineage}"));
+        }
+        injector.set(AWS_XRAY_TRACE_HEADER, header_value);
+    }
+
+    fn extract_with_context(&self, cx: &Context, extractor: &dyn Extractor) -> Context {
+        match self.extract_header(extractor) {
+            Some((span_context, Some(lineage))) => cx
+                .with_remote_span_context(span_context)
+                .with_value(lineage),
+            Some((span_context, None)) => cx.with_remote_span_context(span_context),
+            None => cx.clone(),
+        }
+    }
+
+    fn fields(&self) -> FieldIter<'_> {
+        FieldIter::new(self.fields.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    const TRACE_ID: u128 = 0x5759_e988_bd86_2e3f_e1be_46a9_9427_2793;
+    const SPAN_ID: u64 = 0x5399_5c3f_42cd_8ad8;
+
+    fn span_context(trace_flags: TraceFlags) -> SpanContext {
+        SpanContext::new(
+            TraceId::from_u128(TRACE_ID),
+            SpanId::from_u64(SPAN_ID),
+            trace_flags,
+            true,
+            TraceState::default(),
+        )
+    }
+
+    fn extract(header_value: &str) -> Context {
+        let mut headers = HashMap::new();
+        headers.insert(AWS_XRAY_TRACE_HEADER.to_string(), header_value.to_string());
+        XrayPropagator::new().extract(&headers)
+    }
+
+    fn inject(cx: &Context) -> Option<String> {
+        let mut headers = HashMap::new();
+        XrayPropagator::new().inject_context(cx, &mut headers);
+        headers.remove(AWS_XRAY_TRACE_HEADER)
+    }
+
+    #[test]
+    fn extract_valid_headers() {
+        // Examples from the X-Ray developer guide, plus whitespace and unknown fields.
+        let data = vec![
+            (
+                "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1",
+                TraceFlags::SAMPLED,
+            ),
+            (
+                "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=0",
+                TraceFlags::default(),
+            ),
+            (
+                "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=?",
+                TraceFlags::default(),
+            ),
+            (
+                "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8",
+                TraceFlags::default(),
+            ),
+            (
+                "Root=1-5759e988-bd862e3fe1be46a994272793; Parent=53995c3f42cd8ad8; Sampled=1",
+                TraceFlags::SAMPLED,
+            ),
+            (
+                "Parent=53995c3f42cd8ad8;Sampled=1;Root=1-5759e988-bd862e3fe1be46a994272793;",
+                TraceFlags::SAMPLED,
+            ),
+            (
+                "Self=1-67891234-12456789abcdef012345678;Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1",
+                TraceFlags::SAMPLED,
+            ),
+        ];
+
+        for (header_value, trace_flags) in data {
+            let cx = extract(header_value);
+            assert_eq!(
+                cx.span().span_context(),
+                &span_context(trace_flags),
+                "{header_value}"
+            );
+            assert!(cx.get::<Lineage>().is_none());
+        }
+    }
+
+    #[test]
+    fn extract_without_span_context() {
+        let data = vec![
+            "",
+            // Load balancers add only the root to requests not traced yet.
+            "Root=1-5759e988-bd862e3fe1be46a994272793",
+            "Root=2-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1",
+            "Root=1-5759e98-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1",
+            "Root=1-5759e988-bd862e3fe1be46a99427279g;Parent=53995c3f42cd8ad8;Sampled=1",
+            "Root=1-5759e988-bd862e3fe1be46a994272793-1;Parent=53995c3f42cd8ad8;Sampled=1",
+            "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad;Sampled=1",
+            "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=2",
+            "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled",
+            "Root=1-00000000-000000000000000000000000;Parent=53995c3f42cd8ad8;Sampled=1",
+        ];
+
+        for header_value in data {
+            let cx = extract(header_value);
+            assert!(!cx.has_active_span(), "{header_value}");
+            assert!(!cx.span().span_context().is_valid(), "{header_value}");
+        }
+        assert!(!XrayPropagator::new()
+            .extract(&HashMap::<String, String>::new())
+            .has_active_span());
+    }
+
+    #[test]
+    fn inject_header() {
+        let cx = Context::new().with_remote_span_context(span_context(TraceFlags::SAMPLED));
+        assert_eq!(
+            inject(&cx).as_deref(),
+            Some("Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1")
+        );
+
+        let cx = Context::new().with_remote_span_context(span_context(TraceFlags::default()));
+        assert_eq!(
+            inject(&cx).as_deref(),
+            Some("Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=0")
+        );
+
+        assert_eq!(inject(&Context::new()), None);
+        let cx = Context::new().with_remote_span_context(SpanContext::empty_context());
+        assert_eq!(inject(&cx), None);
+    }
+
+    #[test]
+    fn lineage_is_passed_through() {
+        let header_value = "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1;Lineage=a87bd80c:1|68fd508a:5|c512fbe3:2";
+        let cx = extract(header_value);
+        assert_eq!(
+            cx.get::<Lineage>(),
+            Some(&Lineage("a87bd80c:1|68fd508a:5|c512fbe3:2".to_string()))
+        );
+        assert_eq!(inject(&cx).as_deref(), Some(header_value));
+
+        // The lineage stays with the context of spans started within it.
+        let child = span_context(TraceFlags::SAMPLED);
+        let child = SpanContext::new(
+            child.trace_id(),
+            SpanId::from_u64(1),
+            child.trace_flags(),
+            false,
+            TraceState::default(),
+        );
+        assert_eq!(
+            inject(&cx.with_remote_span_context(child)).as_deref(),
+            Some("Root=1-5759e988-bd862e3fe1be46a994272793;Parent=0000000000000001;Sampled=1;Lineage=a87bd80c:1|68fd508a:5|c512fbe3:2")
+        );
+
+        let cx = extract(
+            "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1;Lineage=",
+        );
+        assert!(!cx.has_active_span());
+    }
+
+    #[test]
+    fn fields() {
+        let propagator = XrayPropagator::new();
+        assert_eq!(
+            propagator.fields().collect::<Vec<_>>(),
+            vec![AWS_XRAY_TRACE_HEADER]
+        );
+    }
+}
@@ -0,0 +1,774 @@
+//! [`tonic`] interceptors and [`tower`] middleware tracing gRPC servers and
+//! clients.
+//!
+//! The [`GrpcServerLayer`] continues the trace of incoming calls, extracting
+//! their context from the request metadata with the global propagator, and
+//! records a [`SpanKind::Server`] span for each call. The [`GrpcClientLayer`]
+//! records a [`SpanKind::Client`] span for each outgoing call and injects its
+//! context into the request metadata.
+//!
+//! Spans are named after the full method name, e.g.
+//! `helloworld.Greeter/SayHello`, carry the attributes of the [RPC semantic
+//! conventions] and end once the gRPC status of the call is received, which
+//! for streaming calls is after the last response message. Client spans are
+//! failed for any status other than `Ok`, server spans only for the
+//! `Unknown`, `DeadlineExceeded`, `Unimplemented`, `Internal`, `Unavailable`
+//! and `DataLoss` codes. The call durations are recorded in the
+//! `rpc.server.duration` and `rpc.client.duration` histograms.
+//!
+//! When only the context needs to be propagated, the
+//! [`InjectContextInterceptor`] and [`ExtractContextInterceptor`] can be used
+//! with tonic's interceptors instead.
+//!
+//! # Examples
+//!
+//! ```
+//! use opentelemetry_http::grpc::{GrpcClientLayer, GrpcServerLayer};
+//! use tower_layer::Layer;
+//!
+//! # fn example<S: Clone, C>(server: S, channel: C) {
+//! // With tonic: `Server::builder().layer(GrpcServerLayer::new())`
+//! let server = GrpcServerLayer::new().layer(server);
+//! // With tonic: `GreeterClient::new(GrpcClientLayer::new().layer(channel))`
+//! let client = GrpcClientLayer::new().layer(channel);
+//! # }
+//! ```
+//!
+//! [`tower`]: https://docs.rs/tower
+//! [RPC semantic conventions]: https://opentelemetry.io/docs/specs/semconv/rpc/grpc/
+use crate::instrumentation::{server_attributes, DurationMetric, Instruments};
+use crate::{HeaderExtractor, HeaderInjector, MetadataExtractor, MetadataInjector};
+use http::{HeaderMap, Request, Response};
+use http_body::{Body, Frame, SizeHint};
+use opentelemetry::{
+    global::{self, ObjectSafeTracerProvider},
+    metrics::{Histogram, MeterProvider},
+    trace::{FutureExt, SpanKind, Status, TraceContextExt, Tracer, WithContext},
+    Context, KeyValue,
+};
+use opentelemetry_semantic_conventions::attribute;
+use pin_project_lite::pin_project;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{ready, Context as TaskContext, Poll};
+use std::time::Instant;
+use tonic::service::Interceptor;
+use tonic::Code;
+use tower_layer::Layer;
+use tower_service::Service;
+
+/// A tonic [`Interceptor`] injecting the current context into the metadata of
+/// outgoing requests.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct InjectContextInterceptor;
+
+impl Interceptor for InjectContextInterceptor {
+    fn call(
+        &mut self,
+        mut request: tonic::Request<()>,
+    ) -> Result<tonic::Request<()>, tonic::Status> {
+        global::get_text_map_propagator(|propagator| {
+            propagator.inject(&mut MetadataInjector(request.metadata_mut()))
+        });
+        Ok(request)
+    }
+}
+
+/// A tonic [`Interceptor`] extracting the context of incoming requests from
+/// their metadata.
+///
+/// The extracted [`Context`] is stored in the request extensions, where it can
+/// be used as the parent of the spans started by the handler:
+///
+/// ```
+/// # use opentelemetry::Context;
+/// # fn handler(request: tonic::Request<()>) {
+/// let parent_cx = request.extensions().get::<Context>();
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ExtractContextInterceptor;
+
+impl Interceptor for ExtractContextInterceptor {
+    fn call(
+        &mut self,
+        mut request: tonic::Request<()>,
+    ) -> Result<tonic::Request<()>, tonic::Status> {
+        let cx = global::get_text_map_propagator(|propagator| {
+            propagator.extract(&MetadataExtractor(request.metadata()))
+        });
+        request.extensions_mut().insert(cx);
+        Ok(request)
+    }
+}
+
+/// A [`Layer`] tracing the calls handled by gRPC servers.
+///
+/// See the [module documentation](self) for details.
+#[derive(Clone, Debug)]
+pub struct GrpcServerLayer {
+    instruments: Instruments,
+}
+
+impl Default for GrpcServerLayer {
+    fn default() -> Self {
+        GrpcServerLayer::new()
+    }
+}
+
+impl GrpcServerLayer {
+    /// Create a layer using the global tracer and meter providers.
+    pub fn new() -> Self {
+        GrpcServerLayer {
+            instruments: Instruments::new(DurationMetric::RpcServer),
+        }
+    }
+
+    /// Record spans with the given tracer provider.
+    pub fn with_tracer_provider<P: ObjectSafeTracerProvider>(mut self, provider: &P) -> Self {
+        self.instruments.set_tracer_provider(provider);
+        self
+    }
+
+    /// Record the call durations with the given meter provider.
+    pub fn with_meter_provider<P: MeterProvider>(mut self, provider: &P) -> Self {
+        self.instruments
+            .set_meter_provider(provider, DurationMetric::RpcServer);
+        self
+    }
+}
+
+impl<S> Layer<S> for GrpcServerLayer {
+    type Service = GrpcServerService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        GrpcServerService {
+            inner,
+            instruments: self.instruments.clone(),
+        }
+    }
+}
+
+/// A [`Service`] tracing the calls handled by a gRPC server, created by the
+/// [`GrpcServerLayer`].
+#[derive(Clone, Debug)]
+pub struct GrpcServerService<S> {
+    inner: S,
+    instruments: Instruments,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for GrpcServerService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    S::Error: fmt::Display,
+{
+    type Response = Response<ResponseBody<ResBody>>;
+    type Error = S::Error;
+    type Future = ResponseFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut TaskContext<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        let parent_cx = global::get_text_map_propagator(|propagator| {
+            propagator.extract(&HeaderExtractor(request.headers()))
+        });
+
+        let (span_name, attributes) = method_attributes(request.uri().path());
+        let span = self.instruments.tracer.build_with_context(
+            self.instruments
+                .tracer
+                .span_builder(span_name)
+                .with_kind(SpanKind::Server)
+                .with_attributes(attributes.clone()),
+            &parent_cx,
+        );
+        let cx = parent_cx.with_span(span);
+
+        let future = {
+            let _guard = cx.clone().attach();
+            self.inner.call(request)
+        };
+        ResponseFuture::new(future, cx, attributes, &self.instruments, false)
+    }
+}
+
+/// A [`Layer`] tracing the calls made by gRPC clients.
+///
+/// See the [module documentation](self) for details.
+#[derive(Clone, Debug)]
+pub struct GrpcClientLayer {
+    instruments: Instruments,
+}
+
+impl Default for GrpcClientLayer {
+    fn default() -> Self {
+        GrpcClientLayer::new()
+    }
+}
+
+impl GrpcClientLayer {
+    /// Create a layer using the global tracer and meter providers.
+    pub fn new() -> Self {
+        GrpcClientLayer {
+            instruments: Instruments::new(DurationMetric::RpcClient),
+        }
+    }
+
+    /// Record spans with the given tracer provider.
+    pub fn with_tracer_provider<P: ObjectSafeTracerProvider>(mut self, provider: &P) -> Self {
+        self.instruments.set_tracer_provider(provider);
+        self
+    }
+
+    /// Record the call durations with the given meter provider.
+    pub fn with_meter_provider<P: MeterProvider>(mut self, provider: &P) -> Self {
+        self.instruments
+            .set_meter_provider(provider, DurationMetric::RpcClient);
+        self
+    }
+}
+
+impl<S> Layer<S> for GrpcClientLayer {
+    type Service = GrpcClientService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        GrpcClientService {
+            inner,
+            instruments: self.instruments.clone(),
+        }
+    }
+}
+
+/// A [`Service`] tracing the calls made by a gRPC client, created by the
+/// [`GrpcClientLayer`].
+#[derive(Clone, Debug)]
+pub struct GrpcClientService<S> {
+    inner: S,
+    instruments: Instruments,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for GrpcClientService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    S::Error: fmt::Display,
+{
+    type Response = Response<ResponseBody<ResBody>>;
+    type Error = S::Error;
+    type Future = ResponseFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut TaskContext<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<ReqBody>) -> Self::Future {
+        let (span_name, mut attributes) = method_attributes(request.uri().path());
+        attributes.extend(server_attributes(request.uri()));
+
+        let parent_cx = Context::current();
+        let span = self.instruments.tracer.build_with_context(
+            self.instruments
+                .tracer
+                .span_builder(span_name)
+                .with_kind(SpanKind::Client)
+                .with_attributes(attributes.clone()),
+            &parent_cx,
+        );
+        let cx = parent_cx.with_span(span);
+        global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(&cx, &mut HeaderInjector(request.headers_mut()))
+        });
+
+        let future = {
+            let _guard = cx.clone().attach();
+            self.inner.call(request)
+        };
+        ResponseFuture::new(future, cx, attributes, &self.instruments, true)
+    }
+}
+
+/// Returns the span name and RPC attributes of a call to the given path.
+///
+/// gRPC paths have the form `/{package}.{service}/{method}`, other paths are
+/// used as span name as is.
+fn method_attributes(path: &str) -> (String, Vec<KeyValue>) {
+    let mut attributes = vec![KeyValue::new(attribute::RPC_SYSTEM, "grpc")];
+    let full_method = path.strip_prefix('/').unwrap_or(path);
+    if let Some((service, method)) = full_method.split_once('/') {
+        if !service.is_empty() && !method.is_empty() {
+            attributes.push(KeyValue::new(attribute::RPC_SERVICE, service.to_owned()));
+            attributes.push(KeyValue::new(attribute::RPC_METHOD, method.to_owned()));
+        }
+    }
+    (full_method.to_owned(), attributes)
+}
+
+pin_project! {
+    /// The response future of the [`GrpcServerService`] and
+    /// [`GrpcClientService`].
+    pub struct ResponseFuture<F> {
+        #[pin]
+        inner: WithContext<F>,
+        call: Option<CallState>,
+    }
+}
+
+impl<F> fmt::Debug for ResponseFuture<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseFuture").finish_non_exhaustive()
+    }
+}
+
+impl<F> ResponseFuture<F> {
+    fn new(
+        future: F,
+        cx: Context,
+        metric_attributes: Vec<KeyValue>,
+        instruments: &Instruments,
+        is_client: bool,
+    ) -> Self {
+        ResponseFuture {
+            inner: future.with_context(cx.clone()),
+            call: Some(CallState {
+                cx,
+                start: Instant::now(),
+                metric_attributes,
+                duration: instruments.duration.clone(),
+                is_client,
+            }),
+        }
+    }
+}
+
+impl<F, ResBody, E> Future for ResponseFuture<F>
+where
+    F: Future<Output = Result<Response<ResBody>, E>>,
+    E: fmt::Display,
+{
+    type Output = Result<Response<ResponseBody<ResBody>>, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let result = ready!(this.inner.poll(cx));
+        let call = this.call.take();
+
+        Poll::Ready(match result {
+            Ok(response) => {
+                // Trailers-only responses carry the status in their headers.
+                let call = match (call, tonic::Status::from_header_map(response.headers())) {
+                    (Some(call), Some(status)) => {
+                        call.finish(status.code(), status.message());
+                        None
+                    }
+                    (call, _) => call,
+                };
+                Ok(response.map(|inner| ResponseBody { inner, call }))
+            }
+            Err(err) => {
+                if let Some(call) = call {
+                    call.fail(&err);
+                }
+                Err(err)
+            }
+        })
+    }
+}
+
+pin_project! {
+    /// The response body of the [`GrpcServerService`] and
+    /// [`GrpcClientService`], ending the call span once the gRPC status is
+    /// received in the trailers.
+    ///
+    /// Dropping the body before the trailers are received ends the span with
+    /// the `Cancelled` code.
+    pub struct ResponseBody<B> {
+        #[pin]
+        inner: B,
+        call: Option<CallState>,
+    }
+
+    impl<B> PinnedDrop for ResponseBody<B> {
+        fn drop(this: Pin<&mut Self>) {
+            if let Some(call) = this.project().call.take() {
+                call.finish(Code::Cancelled, "the response was dropped");
+            }
+        }
+    }
+}
+
+impl<B> fmt::Debug for ResponseBody<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseBody").finish_non_exhaustive()
+    }
+}
+
+impl<B> Body for ResponseBody<B>
+where
+    B: Body,
+    B::Error: fmt::Display,
+{
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut TaskContext<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        let frame = {
+            // Streaming responses are produced while the body is polled.
+            let _guard = this.call.as_ref().map(|call| call.cx.clone().attach());
+            ready!(this.inner.poll_frame(cx))
+        };
+
+        match &frame {
+            Some(Ok(frame)) => {
+                if let Some(trailers) = frame.trailers_ref() {
+                    if let Some(call) = this.call.take() {
+                        call.finish_with_trailers(trailers);
+                    }
+                }
+            }
+            Some(Err(err)) => {
+                if let Some(call) = this.call.take() {
+                    call.fail(err);
+                }
+            }
+            None => {
+                if let Some(call) = this.call.take() {
+                    call.finish(Code::Unknown, "the response has no gRPC status");
+                }
+            }
+        }
+        Poll::Ready(frame)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        // Keep the body polled until the call is finished.
+        self.call.is_none() && self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+struct CallState {
+    cx: Context,
+    start: Instant,
+    metric_attributes: Vec<KeyValue>,
+    duration: Histogram<f64>,
+    is_client: bool,
+}
+
+impl CallState {
+    fn finish_with_trailers(self, trailers: &HeaderMap) {
+        match tonic::Status::from_header_map(trailers) {
+            Some(status) => self.finish(status.code(), status.message()),
+            None => self.finish(Code::Unknown, "the response has no gRPC status"),
+        }
+    }
+
+    /// Sets the status code attribute and span status from the gRPC status of
+    /// the call, ends the span and records the call duration.
+    fn finish(mut self, code: Code, message: &str) {
+        let span = self.cx.span();
+        let status_code = KeyValue::new(attribute::RPC_GRPC_STATUS_CODE, code as i64);
+        span.set_attribute(status_code.clone());
+        self.metric_attributes.push(status_code);
+
+        let is_error = if self.is_client {
+            code != Code::Ok
+        } else {
+            matches!(
+                code,
+                Code::Unknown
+                    | Code::DeadlineExceeded
+                    | Code::Unimplemented
+                    | Code::Internal
+                    | Code::Unavailable
+                    | Code::DataLoss
+            )
+        };
+        if is_error {
+            span.set_status(Status::error(message.to_owned()));
+        }
+        self.end();
+    }
+
+    /// Fails the span of a call which ended without a gRPC status.
+    fn fail<E: fmt::Display>(mut self, err: &E) {
+        let span = self.cx.span();
+        let error_type = KeyValue::new(attribute::ERROR_TYPE, std::any::type_name::<E>());
+        span.set_attribute(error_type.clone());
+        span.set_status(Status::error(err.to_string()));
+        self.metric_attributes.push(error_type);
+        self.end();
+    }
+
+    fn end(self) {
+        self.cx.span().end();
+        self.duration.record(
+            self.start.elapsed().as_secs_f64() * 1000.0,
+            &self.metric_attributes,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instrumentation::tests::{attribute, Telemetry};
+    use bytes::Bytes;
+    use opentelemetry::trace::{SpanId, TraceId};
+    use opentelemetry::Value;
+    use opentelemetry_sdk::propagation::TraceContextPropagator;
+    use opentelemetry_semantic_conventions::metric;
+    use std::collections::VecDeque;
+    use std::convert::Infallible;
+    use std::future::{poll_fn, ready, Ready};
+
+    /// A response body of data frames followed by optional trailers.
+    struct TestBody(VecDeque<Frame<Bytes>>);
+
+    impl TestBody {
+        fn new(grpc_status: Option<(Code, &str)>) -> Self {
+            let mut frames = VecDeque::from([Frame::data(Bytes::from_static(b"reply"))]);
+            if let Some((code, message)) = grpc_status {
+                let mut trailers = HeaderMap::new();
+                tonic::Status::new(code, message)
+                    .add_header(&mut trailers)
+                    .unwrap();
+                frames.push_back(Frame::trailers(trailers));
+            }
+            TestBody(frames)
+        }
+    }
+
+    impl Body for TestBody {
+        type Data = Bytes;
+        type Error = Infallible;
+
+        fn poll_frame(
+            mut self: Pin<&mut Self>,
+            _cx: &mut TaskContext<'_>,
+        ) -> Poll<Option<Result<Frame<Bytes>, Infallible>>> {
+            Poll::Ready(self.0.pop_front().map(Ok))
+        }
+    }
+
+    /// Responds with the given response, recording the request headers.
+    #[derive(Clone)]
+    struct TestService {
+        response: fn() -> Response<TestBody>,
+        headers: std::sync::Arc<std::sync::Mutex<Vec<HeaderMap>>>,
+    }
+
+    impl TestService {
+        fn new(response: fn() -> Response<TestBody>) -> Self {
+            TestService {
+                response,
+                headers: Default::default(),
+            }
+        }
+    }
+
+    impl Service<Request<()>> for TestService {
+        type Response = Response<TestBody>;
+        type Error = Infallible;
+        type Future = Ready<Result<Response<TestBody>, Infallible>>;
+
+        fn poll_ready(&mut self, _cx: &mut TaskContext<'_>) -> Poll<Result<(), Infallible>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: Request<()>) -> Self::Future {
+            self.headers.lock().unwrap().push(request.headers().clone());
+            ready(Ok((self.response)()))
+        }
+    }
+
+    async fn read_body<B: Body + Unpin>(mut body: B) {
+        while poll_fn(|cx| Pin::new(&mut body).poll_frame(cx))
+            .await
+            .is_some()
+        {}
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn server_span_ends_with_trailers() {
+        let telemetry = Telemetry::new();
+        let mut service = GrpcServerLayer::new()
+            .with_tracer_provider(&telemetry.tracer_provider)
+            .with_meter_provider(&telemetry.meter_provider)
+            .layer(TestService::new(|| {
+                Response::new(TestBody::new(Some((Code::Internal, "boom"))))
+            }));
+
+        let request = Request::post("/helloworld.Greeter/SayHello")
+            .header(
+                "traceparent",
+                "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            )
+            .body(())
+            .unwrap();
+        let response = service.call(request).await.unwrap();
+        read_body(response.into_body()).await;
+
+        let span = telemetry.span();
+        assert_eq!(span.name, "helloworld.Greeter/SayHello");
+        assert_eq!(span.span_kind, SpanKind::Server);
+        assert_eq!(
+            span.span_context.trace_id(),
+            TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap()
+        );
+        assert_eq!(
+            span.parent_span_id,
+            SpanId::from_hex("00f067aa0ba902b7").unwrap()
+        );
+        assert_eq!(span.status, Status::error("boom"));
+        for (key, value) in [
+            (attribute::RPC_SYSTEM, Value::from("grpc")),
+            (attribute::RPC_SERVICE, "helloworld.Greeter".into()),
+            (attribute::RPC_METHOD, "SayHello".into()),
+            (attribute::RPC_GRPC_STATUS_CODE, 13.into()),
+        ] {
+            assert_eq!(attribute(&span.attributes, key), Some(&value), "{key}");
+        }
+
+        let duration = telemetry.histogram::<f64>(metric::RPC_SERVER_DURATION);
+        assert_eq!(duration.count, 1);
+        assert_eq!(
+            attribute(&duration.attributes, attribute::RPC_GRPC_STATUS_CODE),
+            Some(&13.into())
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn server_span_not_failed_for_client_errors() {
+        let telemetry = Telemetry::new();
+        let mut service = GrpcServerLayer::new()
+            .with_tracer_provider(&telemetry.tracer_provider)
+            .with_meter_provider(&telemetry.meter_provider)
+            .layer(TestService::new(|| {
+                Response::new(TestBody::new(Some((Code::NotFound, "no such user"))))
+            }));
+
+        let response = service
+            .call(Request::post("/users.Users/Get").body(()).unwrap())
+            .await
+            .unwrap();
+        read_body(response.into_body()).await;
+
+        let span = telemetry.span();
+        assert_eq!(span.status, Status::Unset);
+        assert_eq!(
+            attribute(&span.attributes, attribute::RPC_GRPC_STATUS_CODE),
+            Some(&5.into())
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn client_span_ends_with_trailers_only_response() {
+        let telemetry = Telemetry::new();
+        let inner = TestService::new(|| {
+            let mut response = Response::new(TestBody::new(None));
+            tonic::Status::new(Code::NotFound, "no such user")
+                .add_header(response.headers_mut())
+                .unwrap();
+            response
+        });
+        let mut service = GrpcClientLayer::new()
+            .with_tracer_provider(&telemetry.tracer_provider)
+            .with_meter_provider(&telemetry.meter_provider)
+            .layer(inner.clone());
+
+        let request = Request::post("http://users.example/users.Users/Get")
+            .body(())
+            .unwrap();
+        // The span ends without reading the body.
+        let _response = service.call(request).await.unwrap();
+
+        let span = telemetry.span();
+        assert_eq!(span.name, "users.Users/Get");
+        assert_eq!(span.span_kind, SpanKind::Client);
+        assert_eq!(span.status, Status::error("no such user"));
+        for (key, value) in [
+            (attribute::RPC_SERVICE, Value::from("users.Users")),
+            (attribute::RPC_METHOD, "Get".into()),
+            (attribute::SERVER_ADDRESS, "users.example".into()),
+            (attribute::SERVER_PORT, 80.into()),
+            (attribute::RPC_GRPC_STATUS_CODE, 5.into()),
+        ] {
+            assert_eq!(attribute(&span.attributes, key), Some(&value), "{key}");
+        }
+
+        let headers = inner.headers.lock().unwrap();
+        assert_eq!(
+            headers[0].get("traceparent").unwrap(),
+            &format!(
+                "00-{}-{}-01",
+                span.span_context.trace_id(),
+                span.span_context.span_id()
+            )
+        );
+
+        let duration = telemetry.histogram::<f64>(metric::RPC_CLIENT_DURATION);
+        assert_eq!(duration.count, 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn client_span_cancelled_when_body_dropped() {
+        let telemetry = Telemetry::new();
+        let mut service = GrpcClientLayer::new()
+            .with_tracer_provider(&telemetry.tracer_provider)
+            .with_meter_provider(&telemetry.meter_provider)
+            .layer(TestService::new(|| {
+                Response::new(TestBody::new(Some((Code::Ok, ""))))
+            }));
+
+        let response = service
+            .call(
+                Request::post("https://users.example/users.Users/List")
+                    .body(())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert!(telemetry.spans.get_finished_spans().unwrap().is_empty());
+        drop(response);
+
+        let span = telemetry.span();
+        assert_eq!(span.status, Status::error("the response was dropped"));
+        assert_eq!(
+            attribute(&span.attributes, attribute::RPC_GRPC_STATUS_CODE),
+            Some(&1.into())
+        );
+    }
+
+    #[test]
+    fn interceptors_propagate_context() {
+        global::set_text_map_propagator(TraceContextPropagator::new());
+        let span_context = opentelemetry::trace::SpanContext::new(
+            TraceId::from_u128(1),
+            SpanId::from_u64(2),
+            opentelemetry::trace::TraceFlags::SAMPLED,
+            true,
+            Default::default(),
+        );
+        let request = {
+            let _guard = Context::new()
+                .with_remote_span_context(span_context.clone())
+                .attach();
+            InjectContextInterceptor
+                .call(tonic::Request::new(()))
+                .unwrap()
+        };
+
+        let request = ExtractContextInterceptor.call(request).unwrap();
+        let cx = request.extensions().get::<Context>().unwrap();
+        assert_eq!(cx.span().span_context(), &span_context);
+    }
+}
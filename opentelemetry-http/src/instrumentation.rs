@@ -0,0 +1,268 @@
+//! Instrumentation shared by the middleware and clients of this crate.
+use http::{Method, Uri, Version};
+use opentelemetry::{
+    global::{self, BoxedTracer, ObjectSafeTracerProvider},
+    metrics::{Histogram, Meter, MeterProvider},
+    trace::TracerProvider as _,
+    InstrumentationLibrary, KeyValue,
+};
+use opentelemetry_semantic_conventions::{attribute, metric};
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+
+const INSTRUMENTATION_NAME: &str = "opentelemetry-http";
+
+pub(crate) fn instrumentation_library() -> Arc<InstrumentationLibrary> {
+    Arc::new(
+        InstrumentationLibrary::builder(INSTRUMENTATION_NAME)
+            .with_version(env!("CARGO_PKG_VERSION"))
+            .build(),
+    )
+}
+
+pub(crate) fn meter<P: MeterProvider>(provider: &P) -> Meter {
+    provider.versioned_meter(
+        INSTRUMENTATION_NAME,
+        Some(env!("CARGO_PKG_VERSION")),
+        None::<&'static str>,
+        None,
+    )
+}
+
+/// The duration histograms recorded by the middleware of this crate.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum DurationMetric {
+    #[cfg(feature = "tower")]
+    HttpServer,
+    HttpClient,
+    #[cfg(feature = "grpc")]
+    RpcServer,
+    #[cfg(feature = "grpc")]
+    RpcClient,
+}
+
+/// The tracer and instruments shared by the services of a layer.
+#[derive(Clone)]
+pub(crate) struct Instruments {
+    pub(crate) tracer: Arc<BoxedTracer>,
+    pub(crate) duration: Histogram<f64>,
+}
+
+impl Instruments {
+    pub(crate) fn new(metric: DurationMetric) -> Self {
+        Instruments {
+            tracer: Arc::new(global::tracer_provider().library_tracer(instrumentation_library())),
+            duration: duration_histogram(&global::meter_provider(), metric),
+        }
+    }
+
+    pub(crate) fn set_tracer_provider<P: ObjectSafeTracerProvider>(&mut self, provider: &P) {
+        self.tracer = Arc::new(BoxedTracer::new(
+            provider.boxed_tracer(instrumentation_library()),
+        ));
+    }
+
+    pub(crate) fn set_meter_provider<P: MeterProvider>(
+        &mut self,
+        provider: &P,
+        metric: DurationMetric,
+    ) {
+        self.duration = duration_histogram(provider, metric);
+    }
+}
+
+fn duration_histogram<P: MeterProvider>(provider: &P, metric: DurationMetric) -> Histogram<f64> {
+    let (name, description, unit) = match metric {
+        #[cfg(feature = "tower")]
+        DurationMetric::HttpServer => (
+            metric::HTTP_SERVER_REQUEST_DURATION,
+            "Duration of HTTP server requests.",
+            "s",
+        ),
+        DurationMetric::HttpClient => (
+            metric::HTTP_CLIENT_REQUEST_DURATION,
+            "Duration of HTTP client requests.",
+            "s",
+        ),
+        #[cfg(feature = "grpc")]
+        DurationMetric::RpcServer => (
+            metric::RPC_SERVER_DURATION,
+            "Duration of inbound RPCs.",
+            "ms",
+        ),
+        #[cfg(feature = "grpc")]
+        DurationMetric::RpcClient => (
+            metric::RPC_CLIENT_DURATION,
+            "Duration of outbound RPCs.",
+            "ms",
+        ),
+    };
+    meter(provider)
+        .f64_histogram(name)
+        .with_description(description)
+        .with_unit(unit)
+        .init()
+}
+
+impl fmt::Debug for Instruments {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Instruments").finish_non_exhaustive()
+    }
+}
+
+/// Returns the span name and method attributes of a request.
+///
+/// Methods other than the ones defined by RFC 9110 and RFC 5789 are recorded
+/// as `_OTHER`, to keep the cardinality of the attribute bounded.
+pub(crate) fn method_attributes(method: &Method) -> (Cow<'static, str>, Vec<KeyValue>) {
+    let known = matches!(
+        *method,
+        Method::GET
+            | Method::HEAD
+            | Method::POST
+            | Method::PUT
+            | Method::DELETE
+            | Method::CONNECT
+            | Method::OPTIONS
+            | Method::TRACE
+            | Method::PATCH
+    );
+    if known {
+        let method = method.as_str().to_owned();
+        (
+            Cow::Owned(method.clone()),
+            vec![KeyValue::new(attribute::HTTP_REQUEST_METHOD, method)],
+        )
+    } else {
+        (
+            Cow::Borrowed("HTTP"),
+            vec![
+                KeyValue::new(attribute::HTTP_REQUEST_METHOD, "_OTHER"),
+                KeyValue::new(
+                    attribute::HTTP_REQUEST_METHOD_ORIGINAL,
+                    method.as_str().to_owned(),
+                ),
+            ],
+        )
+    }
+}
+
+pub(crate) fn protocol_version(version: Version) -> Option<&'static str> {
+    match version {
+        Version::HTTP_09 => Some("0.9"),
+        Version::HTTP_10 => Some("1.0"),
+        Version::HTTP_11 => Some("1.1"),
+        Version::HTTP_2 => Some("2"),
+        Version::HTTP_3 => Some("3"),
+        _ => None,
+    }
+}
+
+/// Returns the URL of a request without the credentials it may contain.
+pub(crate) fn redacted_url(uri: &Uri) -> String {
+    match uri.authority() {
+        Some(authority) if authority.as_str().contains('@') => {
+            let host = authority
+                .as_str()
+                .rsplit_once('@')
+                .map_or(authority.as_str(), |(_, host)| host);
+            format!(
+                "{}://REDACTED:REDACTED@{}{}",
+                uri.scheme_str().unwrap_or("http"),
+                host,
+                uri.path_and_query().map_or("", |path| path.as_str())
+            )
+        }
+        _ => uri.to_string(),
+    }
+}
+
+/// Returns the `server.address` and `server.port` attributes of a request to
+/// the given URI, defaulting the port from the scheme.
+pub(crate) fn server_attributes(uri: &Uri) -> Vec<KeyValue> {
+    let mut attributes = Vec::new();
+    if let Some(host) = uri.host() {
+        attributes.push(KeyValue::new(attribute::SERVER_ADDRESS, host.to_owned()));
+        let port = uri.port_u16().or(match uri.scheme_str() {
+            Some("https") => Some(443),
+            Some("http") => Some(80),
+            _ => None,
+        });
+        if let Some(port) = port {
+            attributes.push(KeyValue::new(attribute::SERVER_PORT, i64::from(port)));
+        }
+    }
+    attributes
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use opentelemetry::Value;
+    use opentelemetry_sdk::export::trace::SpanData;
+    use opentelemetry_sdk::metrics::{data, PeriodicReader, SdkMeterProvider};
+    use opentelemetry_sdk::propagation::TraceContextPropagator;
+    use opentelemetry_sdk::testing::metrics::InMemoryMetricsExporter;
+    use opentelemetry_sdk::testing::trace::InMemorySpanExporter;
+    use opentelemetry_sdk::{runtime, trace::TracerProvider};
+
+    pub(crate) struct Telemetry {
+        pub(crate) tracer_provider: TracerProvider,
+        pub(crate) spans: InMemorySpanExporter,
+        pub(crate) meter_provider: SdkMeterProvider,
+        metrics: InMemoryMetricsExporter,
+    }
+
+    impl Telemetry {
+        pub(crate) fn new() -> Self {
+            global::set_text_map_propagator(TraceContextPropagator::new());
+            let spans = InMemorySpanExporter::default();
+            let metrics = InMemoryMetricsExporter::default();
+            Telemetry {
+                tracer_provider: TracerProvider::builder()
+                    .with_simple_exporter(spans.clone())
+                    .build(),
+                spans,
+                meter_provider: SdkMeterProvider::builder()
+                    .with_reader(PeriodicReader::builder(metrics.clone(), runtime::Tokio).build())
+                    .build(),
+                metrics,
+            }
+        }
+
+        pub(crate) fn span(&self) -> SpanData {
+            let mut spans = self.spans.get_finished_spans().unwrap();
+            assert_eq!(spans.len(), 1);
+            spans.remove(0)
+        }
+
+        pub(crate) fn histogram<T: fmt::Debug + Copy + Send + Sync + 'static>(
+            &self,
+            name: &str,
+        ) -> data::HistogramDataPoint<T> {
+            self.meter_provider.force_flush().unwrap();
+            let resource_metrics = self.metrics.get_finished_metrics().unwrap();
+            let metric = resource_metrics
+                .iter()
+                .flat_map(|rm| &rm.scope_metrics)
+                .flat_map(|sm| &sm.metrics)
+                .find(|m| m.name == name)
+                .unwrap();
+            let histogram = metric
+                .data
+                .as_any()
+                .downcast_ref::<data::Histogram<T>>()
+                .unwrap();
+            assert_eq!(histogram.data_points.len(), 1);
+            histogram.data_points[0].clone()
+        }
+    }
+
+    pub(crate) fn attribute<'a>(attributes: &'a [KeyValue], key: &str) -> Option<&'a Value> {
+        attributes
+            .iter()
+            .find(|kv| kv.key.as_str() == key)
+            .map(|kv| &kv.value)
+    }
+}
@@ -0,0 +1,349 @@
+use crate::instrumentation::{
+    meter, method_attributes, protocol_version, redacted_url, server_attributes, DurationMetric,
+    Instruments,
+};
+use crate::{HeaderInjector, HttpClient, HttpError, Request, Response};
+use async_trait::async_trait;
+use bytes::Bytes;
+use opentelemetry::{
+    global::{self, ObjectSafeTracerProvider},
+    metrics::{Histogram, MeterProvider},
+    trace::{FutureExt, SpanKind, Status, TraceContextExt, Tracer},
+    Context, KeyValue,
+};
+use opentelemetry_semantic_conventions::{attribute, metric};
+use std::time::Instant;
+
+/// An [`HttpClient`] tracing the requests sent with another client.
+///
+/// Each request is recorded as a [`SpanKind::Client`] span, child of the
+/// current context, whose context is injected into the request headers with
+/// the global propagator. Spans carry the attributes of the [HTTP semantic
+/// conventions], including the request and response body sizes, and are
+/// failed for `4xx` and `5xx` responses and for errors. The request durations
+/// and body sizes are recorded in the `http.client.request.duration`,
+/// `http.client.request.body.size` and `http.client.response.body.size`
+/// histograms.
+///
+/// Requests sent while telemetry is suppressed, e.g. within
+/// [`suppress_telemetry`], are not recorded, so that exporters can send their
+/// requests with an instrumented client. The requests sent by the inner client
+/// are not recorded either, which makes nesting instrumented clients harmless.
+///
+/// # Examples
+///
+/// ```
+/// use opentelemetry_http::{HttpClient, InstrumentedHttpClient};
+///
+/// # fn example<C: HttpClient>(client: C) {
+/// let client = InstrumentedHttpClient::new(client);
+/// # }
+/// ```
+///
+/// [`suppress_telemetry`]: crate::suppress_telemetry
+/// [HTTP semantic conventions]: https://opentelemetry.io/docs/specs/semconv/http/
+#[derive(Clone, Debug)]
+pub struct InstrumentedHttpClient<C> {
+    inner: C,
+    instruments: Instruments,
+    request_body_size: Histogram<u64>,
+    response_body_size: Histogram<u64>,
+}
+
+impl<C> InstrumentedHttpClient<C> {
+    /// Wrap the given client, using the global tracer and meter providers.
+    pub fn new(inner: C) -> Self {
+        let (request_body_size, response_body_size) =
+            body_size_histograms(&global::meter_provider());
+        InstrumentedHttpClient {
+            inner,
+            instruments: Instruments::new(DurationMetric::HttpClient),
+            request_body_size,
+            response_body_size,
+        }
+    }
+
+    /// Record spans with the given tracer provider.
+    pub fn with_tracer_provider<P: ObjectSafeTracerProvider>(mut self, provider: &P) -> Self {
+        self.instruments.set_tracer_provider(provider);
+        self
+    }
+
+    /// Record the request durations and body sizes with the given meter
+    /// provider.
+    pub fn with_meter_provider<P: MeterProvider>(mut self, provider: &P) -> Self {
+        self.instruments
+            .set_meter_provider(provider, DurationMetric::HttpClient);
+        (self.request_body_size, self.response_body_size) = body_size_histograms(provider);
+        self
+    }
+
+    /// Returns the wrapped client.
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+/// Marks the context of the requests sent by the inner client of an
+/// [`InstrumentedHttpClient`].
+#[derive(Clone, Copy, Debug)]
+struct InstrumentedRequest;
+
+fn body_size_histograms<P: MeterProvider>(provider: &P) -> (Histogram<u64>, Histogram<u64>) {
+    let meter = meter(provider);
+    (
+        meter
+            .u64_histogram(metric::HTTP_CLIENT_REQUEST_BODY_SIZE)
+            .with_description("Size of HTTP client request bodies.")
+            .with_unit("By")
+            .init(),
+        meter
+            .u64_histogram(metric::HTTP_CLIENT_RESPONSE_BODY_SIZE)
+            .with_description("Size of HTTP client response bodies.")
+            .with_unit("By")
+            .init(),
+    )
+}
+
+#[async_trait]
+impl<C: HttpClient> HttpClient for InstrumentedHttpClient<C> {
+    async fn send(&self, mut request: Request<Vec<u8>>) -> Result<Response<Bytes>, HttpError> {
+        let parent_cx = Context::current();
+        if parent_cx.is_telemetry_suppressed() || parent_cx.get::<InstrumentedRequest>().is_some() {
+            return self.inner.send(request).await;
+        }
+
+        let (span_name, mut attributes) = method_attributes(request.method());
+        attributes.extend(server_attributes(request.uri()));
+        if let Some(version) = protocol_version(request.version()) {
+            attributes.push(KeyValue::new(attribute::NETWORK_PROTOCOL_VERSION, version));
+        }
+        let mut metric_attributes = attributes.clone();
+        let request_body_size = request.body().len() as u64;
+        attributes.push(KeyValue::new(
+            attribute::URL_FULL,
+            redacted_url(request.uri()),
+        ));
+        attributes.push(KeyValue::new(
+            attribute::HTTP_REQUEST_BODY_SIZE,
+            request_body_size as i64,
+        ));
+
+        let span = self.instruments.tracer.build_with_context(
+            self.instruments
+                .tracer
+                .span_builder(span_name)
+                .with_kind(SpanKind::Client)
+                .with_attributes(attributes),
+            &parent_cx,
+        );
+        let cx = parent_cx.with_span(span);
+        global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(&cx, &mut HeaderInjector(request.headers_mut()))
+        });
+
+        let start = Instant::now();
+        let result = self
+            .inner
+            .send(request)
+            .with_context(cx.with_value(InstrumentedRequest))
+            .await;
+
+        let span = cx.span();
+        let mut response_body_size = None;
+        match &result {
+            Ok(response) => {
+                let status = response.status();
+                let status_code = KeyValue::new(
+                    attribute::HTTP_RESPONSE_STATUS_CODE,
+                    i64::from(status.as_u16()),
+                );
+                span.set_attribute(status_code.clone());
+                metric_attributes.push(status_code);
+
+                let body_size = response.body().len() as u64;
+                span.set_attribute(KeyValue::new(
+                    attribute::HTTP_RESPONSE_BODY_SIZE,
+                    body_size as i64,
+                ));
+                response_body_size = Some(body_size);
+
+                if status.is_client_error() || status.is_server_error() {
+                    let error_type =
+                        KeyValue::new(attribute::ERROR_TYPE, status.as_str().to_owned());
+                    span.set_attribute(error_type.clone());
+                    span.set_status(Status::error(""));
+                    metric_attributes.push(error_type);
+                }
+            }
+            Err(err) => {
+                // The type of boxed errors is unknown.
+                let error_type = KeyValue::new(attribute::ERROR_TYPE, "_OTHER");
+                span.set_attribute(error_type.clone());
+                span.set_status(Status::error(err.to_string()));
+                metric_attributes.push(error_type);
+            }
+        }
+        span.end();
+
+        self.instruments
+            .duration
+            .record(start.elapsed().as_secs_f64(), &metric_attributes);
+        self.request_body_size
+            .record(request_body_size, &metric_attributes);
+        if let Some(body_size) = response_body_size {
+            self.response_body_size
+                .record(body_size, &metric_attributes);
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instrumentation::tests::{attribute, Telemetry};
+    use crate::suppress_telemetry;
+    use http::StatusCode;
+    use opentelemetry::Value;
+    use std::sync::{Arc, Mutex};
+
+    /// Responds with the given status or fails, recording the request headers.
+    #[derive(Debug, Default)]
+    struct TestClient {
+        status: Option<StatusCode>,
+        headers: Arc<Mutex<Vec<http::HeaderMap>>>,
+    }
+
+    #[async_trait]
+    impl HttpClient for TestClient {
+        async fn send(&self, request: Request<Vec<u8>>) -> Result<Response<Bytes>, HttpError> {
+            self.headers.lock().unwrap().push(request.headers().clone());
+            match self.status {
+                Some(status) => Ok(Response::builder()
+                    .status(status)
+                    .body(Bytes::from_static(b"unavailable"))?),
+                None => Err("connection refused".into()),
+            }
+        }
+    }
+
+    fn request() -> Request<Vec<u8>> {
+        Request::post("https://collector.example/v1/traces")
+            .body(b"hello".to_vec())
+            .unwrap()
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn records_requests() {
+        let telemetry = Telemetry::new();
+        let inner = TestClient {
+            status: Some(StatusCode::SERVICE_UNAVAILABLE),
+            ..Default::default()
+        };
+        let headers = inner.headers.clone();
+        let client = InstrumentedHttpClient::new(inner)
+            .with_tracer_provider(&telemetry.tracer_provider)
+            .with_meter_provider(&telemetry.meter_provider);
+
+        let response = client.send(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let span = telemetry.span();
+        assert_eq!(span.name, "POST");
+        assert_eq!(span.span_kind, SpanKind::Client);
+        assert_eq!(span.status, Status::error(""));
+        for (key, value) in [
+            (attribute::HTTP_REQUEST_METHOD, Value::from("POST")),
+            (
+                attribute::URL_FULL,
+                "https://collector.example/v1/traces".into(),
+            ),
+            (attribute::SERVER_ADDRESS, "collector.example".into()),
+            (attribute::SERVER_PORT, 443.into()),
+            (attribute::HTTP_REQUEST_BODY_SIZE, 5.into()),
+            (attribute::HTTP_RESPONSE_BODY_SIZE, 11.into()),
+            (attribute::HTTP_RESPONSE_STATUS_CODE, 503.into()),
+            (attribute::ERROR_TYPE, "503".into()),
+        ] {
+            assert_eq!(attribute(&span.attributes, key), Some(&value), "{key}");
+        }
+        assert_eq!(
+            headers.lock().unwrap()[0].get("traceparent").unwrap(),
+            &format!(
+                "00-{}-{}-01",
+                span.span_context.trace_id(),
+                span.span_context.span_id()
+            )
+        );
+
+        let duration = telemetry.histogram::<f64>(metric::HTTP_CLIENT_REQUEST_DURATION);
+        assert_eq!(duration.count, 1);
+        assert_eq!(
+            attribute(&duration.attributes, attribute::HTTP_RESPONSE_STATUS_CODE),
+            Some(&503.into())
+        );
+        let request_size = telemetry.histogram::<u64>(metric::HTTP_CLIENT_REQUEST_BODY_SIZE);
+        assert_eq!(request_size.sum, 5);
+        let response_size = telemetry.histogram::<u64>(metric::HTTP_CLIENT_RESPONSE_BODY_SIZE);
+        assert_eq!(response_size.sum, 11);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn records_errors() {
+        let telemetry = Telemetry::new();
+        let client = InstrumentedHttpClient::new(TestClient::default())
+            .with_tracer_provider(&telemetry.tracer_provider)
+            .with_meter_provider(&telemetry.meter_provider);
+
+        assert!(client.send(request()).await.is_err());
+
+        let span = telemetry.span();
+        assert_eq!(span.status, Status::error("connection refused"));
+        assert_eq!(
+            attribute(&span.attributes, attribute::ERROR_TYPE),
+            Some(&"_OTHER".into())
+        );
+        assert_eq!(
+            attribute(&span.attributes, attribute::HTTP_RESPONSE_STATUS_CODE),
+            None
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn suppressed_requests_are_not_recorded() {
+        let telemetry = Telemetry::new();
+        let inner = TestClient {
+            status: Some(StatusCode::OK),
+            ..Default::default()
+        };
+        let headers = inner.headers.clone();
+        let client = InstrumentedHttpClient::new(inner)
+            .with_tracer_provider(&telemetry.tracer_provider)
+            .with_meter_provider(&telemetry.meter_provider);
+
+        suppress_telemetry(client.send(request())).await.unwrap();
+
+        assert!(telemetry.spans.get_finished_spans().unwrap().is_empty());
+        assert!(headers.lock().unwrap()[0].get("traceparent").is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn nested_clients_record_requests_once() {
+        let telemetry = Telemetry::new();
+        let inner = TestClient {
+            status: Some(StatusCode::OK),
+            ..Default::default()
+        };
+        let client = InstrumentedHttpClient::new(
+            InstrumentedHttpClient::new(inner).with_tracer_provider(&telemetry.tracer_provider),
+        )
+        .with_tracer_provider(&telemetry.tracer_provider);
+
+        client.send(request()).await.unwrap();
+
+        // Fails unless exactly one span was recorded.
+        telemetry.span();
+    }
+}
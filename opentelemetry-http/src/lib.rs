@@ -1,11 +1,14 @@
 use async_trait::async_trait;
 use std::fmt::Debug;
+use std::future::Future;
 
 #[doc(no_inline)]
 pub use bytes::Bytes;
 #[doc(no_inline)]
 pub use http::{Request, Response};
 use opentelemetry::propagation::{Extractor, Injector};
+use opentelemetry::trace::{FutureExt, WithContext};
+use opentelemetry::Context;
 
 /// Helper for injecting headers into HTTP Requests. This is used for OpenTelemetry context
 /// propagation over HTTP.
@@ -45,6 +48,70 @@ impl<'a> Extractor for HeaderExtractor<'a> {
     }
 }
 
+/// Helper for injecting metadata into gRPC requests made with [`tonic`]. This is
+/// used for OpenTelemetry context propagation over gRPC.
+#[cfg(feature = "tonic")]
+pub struct MetadataInjector<'a>(pub &'a mut tonic::metadata::MetadataMap);
+
+#[cfg(feature = "tonic")]
+impl<'a> Injector for MetadataInjector<'a> {
+    /// Set a key and value in the MetadataMap.  Does nothing if the key or value are not valid inputs.
+    fn set(&mut self, key: &str, value: String) {
+        if let Ok(key) = tonic::metadata::MetadataKey::from_bytes(key.as_bytes()) {
+            if let Ok(val) = tonic::metadata::MetadataValue::try_from(&value) {
+                self.0.insert(key, val);
+            }
+        }
+    }
+}
+
+/// Helper for extracting metadata from gRPC requests received with [`tonic`]. This is
+/// used for OpenTelemetry context propagation over gRPC.
+#[cfg(feature = "tonic")]
+pub struct MetadataExtractor<'a>(pub &'a tonic::metadata::MetadataMap);
+
+#[cfg(feature = "tonic")]
+impl<'a> Extractor for MetadataExtractor<'a> {
+    /// Get a value for a key from the MetadataMap.  If the value is not valid ASCII, returns None.
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    /// Collect all the keys from the MetadataMap.
+    fn keys(&self) -> Vec<&str> {
+        self.0
+            .keys()
+            .map(|key| match key {
+                tonic::metadata::KeyRef::Ascii(v) => v.as_str(),
+                tonic::metadata::KeyRef::Binary(v) => v.as_str(),
+            })
+            .collect::<Vec<_>>()
+    }
+}
+
+#[cfg(feature = "grpc")]
+pub mod grpc;
+#[cfg(any(feature = "tower", feature = "instrumented-client"))]
+mod instrumentation;
+#[cfg(feature = "instrumented-client")]
+mod instrumented_client;
+#[cfg(feature = "tower")]
+pub mod tower;
+
+#[cfg(feature = "instrumented-client")]
+pub use instrumented_client::InstrumentedHttpClient;
+
+/// Runs the given future with telemetry suppressed.
+///
+/// Exporters must send their requests within this function, as recording them,
+/// e.g. with an `InstrumentedHttpClient`, would create telemetry about the
+/// exports, which would be exported in turn.
+///
+/// See [`Context::with_telemetry_suppressed`] for details.
+pub fn suppress_telemetry<F: Future>(future: F) -> WithContext<F> {
+    future.with_context(Context::current().with_telemetry_suppressed())
+}
+
 pub type HttpError = Box<dyn std::error::Error + Send + Sync + 'static>;
 
 /// A minimal interface necessary for sending requests over HTTP.
@@ -239,4 +306,33 @@ mod tests {
         assert!(got.contains(&"headername1"));
         assert!(got.contains(&"headername2"));
     }
+
+    #[cfg(feature = "tonic")]
+    #[test]
+    fn grpc_metadata_get() {
+        let mut carrier = tonic::metadata::MetadataMap::new();
+        MetadataInjector(&mut carrier).set("headerName", "value".to_string());
+        MetadataInjector(&mut carrier).set("invalid\n", "value".to_string());
+
+        assert_eq!(carrier.len(), 1);
+        assert_eq!(
+            MetadataExtractor(&carrier).get("HEADERNAME"),
+            Some("value"),
+            "case insensitive extraction"
+        )
+    }
+
+    #[cfg(feature = "tonic")]
+    #[test]
+    fn grpc_metadata_keys() {
+        let mut carrier = tonic::metadata::MetadataMap::new();
+        MetadataInjector(&mut carrier).set("headerName1", "value1".to_string());
+        MetadataInjector(&mut carrier).set("headerName2", "value2".to_string());
+
+        let extractor = MetadataExtractor(&carrier);
+        let got = extractor.keys();
+        assert_eq!(got.len(), 2);
+        assert!(got.contains(&"headername1"));
+        assert!(got.contains(&"headername2"));
+    }
 }
@@ -0,0 +1,344 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{quote, quote_spanned};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{
+    parenthesized, Expr, ExprLit, FnArg, Ident, ItemFn, Lit, LitStr, Pat, ReturnType, Token, Type,
+};
+
+/// The arguments of the `instrument` attribute.
+#[derive(Default)]
+pub(crate) struct InstrumentArgs {
+    name: Option<LitStr>,
+    kind: Option<Ident>,
+    tracer: Option<Expr>,
+    skip: Vec<Ident>,
+    skip_all: bool,
+    fields: Vec<Field>,
+}
+
+/// An additional attribute configured with `fields(key = value)`.
+struct Field {
+    key: LitStr,
+    value: Expr,
+}
+
+impl Parse for InstrumentArgs {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut args = InstrumentArgs::default();
+        let mut seen = Vec::new();
+
+        while !input.is_empty() {
+            let arg = input.call(Ident::parse_any)?;
+            let name = arg.to_string();
+            if seen.contains(&name) {
+                return Err(syn::Error::new(
+                    arg.span(),
+                    format!("duplicate argument `{name}`"),
+                ));
+            }
+
+            match name.as_str() {
+                "name" => {
+                    input.parse::<Token![=]>()?;
+                    args.name = Some(input.parse()?);
+                }
+                "kind" => {
+                    input.parse::<Token![=]>()?;
+                    args.kind = Some(input.parse()?);
+                }
+                "tracer" => {
+                    input.parse::<Token![=]>()?;
+                    args.tracer = Some(input.parse()?);
+                }
+                "skip" => {
+                    let content;
+                    parenthesized!(content in input);
+                    let skip = content.parse_terminated(Ident::parse_any, Token![,])?;
+                    args.skip = skip.into_iter().collect();
+                }
+                "skip_all" => args.skip_all = true,
+                "fields" => {
+                    let content;
+                    parenthesized!(content in input);
+                    let fields = content.parse_terminated(Field::parse, Token![,])?;
+                    args.fields = fields.into_iter().collect();
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        arg.span(),
+                        format!(
+                            "unknown argument `{name}`, expected one of `name`, `kind`, \
+                             `tracer`, `skip`, `skip_all` or `fields`"
+                        ),
+                    ))
+                }
+            }
+            seen.push(name);
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(args)
+    }
+}
+
+impl Parse for Field {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let key = if input.peek(LitStr) {
+            input.parse()?
+        } else {
+            let segments = Punctuated::<Ident, Token![.]>::parse_separated_nonempty_with(
+                input,
+                Ident::parse_any,
+            )?;
+            let key = segments
+                .iter()
+                .map(Ident::to_string)
+                .collect::<Vec<_>>()
+                .join(".");
+            LitStr::new(&key, segments.span())
+        };
+        input.parse::<Token![=]>()?;
+
+        Ok(Field {
+            key,
+            value: input.parse()?,
+        })
+    }
+}
+
+/// Rewrites `item` to run its body within a span configured by `args`.
+pub(crate) fn expand(args: InstrumentArgs, item: ItemFn) -> syn::Result<TokenStream> {
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = item;
+
+    if let Some(constness) = sig.constness {
+        return Err(syn::Error::new(
+            constness.span(),
+            "`instrument` cannot be applied to a `const fn`",
+        ));
+    }
+
+    let arguments: Vec<&Ident> = sig
+        .inputs
+        .iter()
+        .filter_map(|input| match input {
+            FnArg::Typed(arg) => match &*arg.pat {
+                Pat::Ident(pat) => Some(&pat.ident),
+                _ => None,
+            },
+            FnArg::Receiver(_) => None,
+        })
+        .collect();
+    for skipped in &args.skip {
+        if skipped != "self" && !arguments.contains(&skipped) {
+            return Err(syn::Error::new(
+                skipped.span(),
+                format!("function has no argument named `{skipped}`"),
+            ));
+        }
+    }
+
+    let name = match &args.name {
+        Some(name) => name.clone(),
+        None => LitStr::new(&sig.ident.to_string(), sig.ident.span()),
+    };
+    let kind = span_kind(args.kind.as_ref())?;
+    let tracer = match &args.tracer {
+        Some(Expr::Lit(ExprLit {
+            lit: Lit::Str(name),
+            ..
+        })) => quote!(::opentelemetry::global::tracer(#name)),
+        Some(tracer) => quote!(#tracer),
+        None => quote!(::opentelemetry::global::tracer(::core::env!(
+            "CARGO_PKG_NAME"
+        ))),
+    };
+
+    let mut attributes = Vec::new();
+    if !args.skip_all {
+        for arg in arguments.iter().filter(|arg| !args.skip.contains(*arg)) {
+            let key = arg.unraw().to_string();
+            attributes.push(quote_spanned! {arg.span()=>
+                ::opentelemetry::KeyValue::new(#key, ::std::format!("{:?}", #arg))
+            });
+        }
+    }
+    for Field { key, value } in &args.fields {
+        attributes.push(quote_spanned! {value.span()=>
+            ::opentelemetry::KeyValue::new(#key, #value)
+        });
+    }
+
+    let start_span = quote! {
+        let __otel_tracer = &#tracer;
+        let __otel_cx = {
+            #[allow(unused_imports)]
+            use ::opentelemetry::trace::Tracer as _;
+            let __otel_builder = ::opentelemetry::trace::SpanBuilder::from_name(#name)
+                .with_kind(#kind)
+                .with_attributes([#(#attributes),*]);
+            <::opentelemetry::Context as ::opentelemetry::trace::TraceContextExt>::current_with_span(
+                __otel_tracer.build(__otel_builder),
+            )
+        };
+    };
+
+    let returns_result = returns_result(&sig.output);
+    let result_type = match &sig.output {
+        ReturnType::Type(_, ty) if !contains_impl_trait(ty) => Some(ty),
+        _ => None,
+    };
+    let result_annotation = result_type.map(|ty| quote!(: #ty));
+
+    let record_error = returns_result.then(record_error);
+    let end_span = quote! {
+        ::opentelemetry::trace::TraceContextExt::span(&__otel_cx).end();
+    };
+    let body = if sig.asyncness.is_some() {
+        quote! {
+            #start_span
+            let __otel_result #result_annotation = ::opentelemetry::trace::FutureExt::with_context(
+                async move #block,
+                ::core::clone::Clone::clone(&__otel_cx),
+            )
+            .await;
+            #record_error
+            #end_span
+            __otel_result
+        }
+    } else {
+        let closure_output = result_type.map(|ty| quote!(-> #ty));
+        quote! {
+            #start_span
+            let __otel_guard = ::core::clone::Clone::clone(&__otel_cx).attach();
+            #[allow(clippy::redundant_closure_call)]
+            let __otel_result = (move || #closure_output #block)();
+            #record_error
+            #end_span
+            __otel_result
+        }
+    };
+
+    Ok(quote! {
+        #(#attrs)*
+        #vis #sig {
+            #body
+        }
+    })
+}
+
+/// The `SpanKind` configured with `kind = ...`.
+fn span_kind(kind: Option<&Ident>) -> syn::Result<TokenStream> {
+    let variant = match kind {
+        None => quote!(Internal),
+        Some(kind) => match kind.to_string().to_ascii_lowercase().as_str() {
+            "client" => quote!(Client),
+            "server" => quote!(Server),
+            "producer" => quote!(Producer),
+            "consumer" => quote!(Consumer),
+            "internal" => quote!(Internal),
+            _ => {
+                return Err(syn::Error::new(
+                    kind.span(),
+                    "unknown span kind, expected one of `client`, `server`, `producer`, \
+                     `consumer` or `internal`",
+                ))
+            }
+        },
+    };
+
+    Ok(quote!(::opentelemetry::trace::SpanKind::#variant))
+}
+
+/// Records an `Err` in `__otel_result` on the span of `__otel_cx`.
+///
+/// Errors implementing `std::error::Error` are recorded with
+/// `SpanRef::record_exception`, others with their `Display` representation. The
+/// implementation is chosen through auto-ref specialization: the method of
+/// `__OtelRecordError` takes precedence as it needs no additional reference.
+fn record_error() -> TokenStream {
+    quote! {
+        if let ::core::result::Result::Err(__otel_error) = &__otel_result {
+            struct __OtelError<'a, E: ?::core::marker::Sized>(&'a E);
+
+            trait __OtelRecordError {
+                fn __otel_record(&self, span: &::opentelemetry::trace::SpanRef<'_>);
+            }
+
+            impl<E: ::std::error::Error> __OtelRecordError for __OtelError<'_, E> {
+                fn __otel_record(&self, span: &::opentelemetry::trace::SpanRef<'_>) {
+                    span.record_exception(
+                        ::opentelemetry::Exception::new(self.0).with_escaped(true),
+                    );
+                }
+            }
+
+            trait __OtelRecordDisplay {
+                fn __otel_record(&self, span: &::opentelemetry::trace::SpanRef<'_>);
+            }
+
+            impl<E: ::std::fmt::Display + ?::core::marker::Sized> __OtelRecordDisplay
+                for &__OtelError<'_, E>
+            {
+                fn __otel_record(&self, span: &::opentelemetry::trace::SpanRef<'_>) {
+                    span.add_event(
+                        "exception",
+                        ::std::vec![
+                            ::opentelemetry::KeyValue::new(
+                                "exception.message",
+                                ::std::string::ToString::to_string(self.0),
+                            ),
+                            ::opentelemetry::KeyValue::new("exception.escaped", true),
+                        ],
+                    );
+                }
+            }
+
+            let __otel_span = ::opentelemetry::trace::TraceContextExt::span(&__otel_cx);
+            (&__OtelError(__otel_error)).__otel_record(&__otel_span);
+            __otel_span.set_status(::opentelemetry::trace::Status::error(
+                ::std::string::ToString::to_string(__otel_error),
+            ));
+        }
+    }
+}
+
+/// Returns whether the function returns a `Result`, including aliases like
+/// `io::Result` named `Result`.
+fn returns_result(output: &ReturnType) -> bool {
+    match output {
+        ReturnType::Type(_, ty) => match &**ty {
+            Type::Path(path) => path
+                .path
+                .segments
+                .last()
+                .map_or(false, |segment| segment.ident == "Result"),
+            _ => false,
+        },
+        ReturnType::Default => false,
+    }
+}
+
+/// Returns whether `ty` contains an `impl Trait`, which can't be named in the
+/// type annotations of the generated code.
+fn contains_impl_trait(ty: &Type) -> bool {
+    fn contains_impl(tokens: TokenStream) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Ident(ident) => ident == "impl",
+            TokenTree::Group(group) => contains_impl(group.stream()),
+            _ => false,
+        })
+    }
+
+    contains_impl(quote!(#ty))
+}
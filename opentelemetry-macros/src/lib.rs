@@ -0,0 +1,128 @@
+//! Attribute macros instrumenting functions with OpenTelemetry spans.
+//!
+//! Tracing a function by hand takes a fair amount of boilerplate: the span has
+//! to be built and started, attached to the context while the function runs
+//! (or to the future of an `async fn`), errors have to be recorded and the
+//! span status set. The [`macro@instrument`] attribute of this crate generates
+//! this code:
+//!
+//! ```
+//! use opentelemetry_macros as otel;
+//!
+//! #[derive(Debug)]
+//! struct Order {
+//!     id: u64,
+//! }
+//!
+//! #[otel::instrument(kind = server, skip(payment), fields(order.id = order.id as i64))]
+//! fn place_order(order: &Order, payment: &str) -> Result<(), std::io::Error> {
+//!     // spans started here are children of the `place_order` span
+//!     Ok(())
+//! }
+//!
+//! # place_order(&Order { id: 1 }, "card").unwrap();
+//! ```
+//!
+//! Spans are started with a tracer of the global tracer provider, named after
+//! the crate containing the instrumented function, unless another tracer is
+//! configured with the `tracer` argument.
+//!
+//! *Compiler support: [requires `rustc` 1.65+][msrv]*
+//!
+//! [msrv]: #supported-rust-versions
+//!
+//! # Supported Rust Versions
+//!
+//! OpenTelemetry is built against the latest stable release. The minimum
+//! supported version is 1.65. The current OpenTelemetry version is not
+//! guaranteed to build on Rust versions earlier than the minimum supported
+//! version.
+//!
+//! The current stable Rust compiler and the three most recent minor versions
+//! before it will always be supported. For example, if the current stable
+//! compiler version is 1.65, the minimum supported version will not be
+//! increased past 1.62, three minor versions prior. Increasing the minimum
+//! supported compiler version is not considered a semver breaking change as
+//! long as doing so complies with this policy.
+#![warn(
+    future_incompatible,
+    missing_debug_implementations,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    unreachable_pub,
+    unused
+)]
+#![cfg_attr(
+    docsrs,
+    feature(doc_cfg, doc_auto_cfg),
+    deny(rustdoc::broken_intra_doc_links)
+)]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/open-telemetry/opentelemetry-rust/main/assets/logo.svg"
+)]
+#![cfg_attr(test, deny(warnings))]
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, ItemFn};
+
+mod instrument;
+
+/// Instruments a function with a span covering each call.
+///
+/// The span is started when the function is called, or first polled for an
+/// `async fn`, as a child of the current span and is current while the
+/// function body runs. It ends when the function returns.
+///
+/// The arguments of the function are recorded as span attributes named after
+/// the arguments, using their [`Debug`] representation. `self` is not
+/// recorded.
+///
+/// Functions returning a `Result` record their `Err` values as an `exception`
+/// event, with [`SpanRef::record_exception`] if the error implements
+/// [`std::error::Error`] or its [`Display`] representation otherwise, and set
+/// the span status to error.
+///
+/// # Arguments
+///
+/// - `name = "name"`: the span name, defaults to the name of the function.
+/// - `kind = client`: the span kind, one of `client`, `server`, `producer`,
+///   `consumer` or `internal` (the default).
+/// - `skip(arg, ..)`: arguments not recorded as attributes, e.g. because they
+///   don't implement [`Debug`] or contain sensitive data.
+/// - `skip_all`: records none of the arguments.
+/// - `fields(key = value, ..)`: additional span attributes. Keys are
+///   identifiers, dotted identifiers like `http.request.method` or string
+///   literals, values are expressions convertible to
+///   [`Value`]s. They are evaluated before the function body and can refer
+///   to the arguments.
+/// - `tracer = expr`: the tracer starting the span, either a tracer or a
+///   reference to one. A string literal names a tracer of the global tracer
+///   provider instead.
+///
+/// # Examples
+///
+/// ```
+/// use opentelemetry_macros::instrument;
+///
+/// #[instrument(name = "GET /users/{id}", kind = client, fields(server.address = "example.com"))]
+/// async fn get_user(id: u64) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+///     Ok(format!("user {id}"))
+/// }
+///
+/// # futures_executor::block_on(get_user(1)).unwrap();
+/// ```
+///
+/// [`Debug`]: std::fmt::Debug
+/// [`Display`]: std::fmt::Display
+/// [`SpanRef::record_exception`]: https://docs.rs/opentelemetry/latest/opentelemetry/trace/struct.SpanRef.html#method.record_exception
+/// [`Value`]: https://docs.rs/opentelemetry/latest/opentelemetry/enum.Value.html
+#[proc_macro_attribute]
+pub fn instrument(args: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as instrument::InstrumentArgs);
+    let item = parse_macro_input!(item as ItemFn);
+
+    instrument::expand(args, item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
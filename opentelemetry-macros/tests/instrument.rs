@@ -0,0 +1,201 @@
+use once_cell::sync::Lazy;
+use opentelemetry::trace::{SpanKind, Status, TraceContextExt, TracerProvider as _};
+use opentelemetry::{Context, KeyValue, Value};
+use opentelemetry_macros::instrument;
+use opentelemetry_sdk::export::trace::SpanData;
+use opentelemetry_sdk::testing::trace::InMemorySpanExporter;
+use opentelemetry_sdk::trace::{Tracer, TracerProvider};
+use std::fmt;
+
+static EXPORTER: Lazy<InMemorySpanExporter> = Lazy::new(InMemorySpanExporter::default);
+
+static TRACER: Lazy<Tracer> = Lazy::new(|| {
+    TracerProvider::builder()
+        .with_simple_exporter(EXPORTER.clone())
+        .build()
+        .tracer("opentelemetry-macros-test")
+});
+
+/// Returns the finished spans named `name`.
+///
+/// The tests share the exporter, so every test uses different span names.
+fn finished_spans(name: &str) -> Vec<SpanData> {
+    let spans = EXPORTER.get_finished_spans().unwrap();
+    spans.into_iter().filter(|span| span.name == name).collect()
+}
+
+fn finished_span(name: &str) -> SpanData {
+    let mut spans = finished_spans(name);
+    assert_eq!(spans.len(), 1, "expected a single {name} span");
+    spans.remove(0)
+}
+
+fn attribute(span: &SpanData, key: &str) -> Option<Value> {
+    span.attributes
+        .iter()
+        .find(|kv| kv.key.as_str() == key)
+        .map(|kv| kv.value.clone())
+}
+
+#[derive(Debug)]
+struct Order {
+    id: u64,
+}
+
+struct Secret;
+
+#[instrument(tracer = &*TRACER, kind = server, skip(secret), fields(order.id = order.id as i64, "custom key" = "value"))]
+fn place_order(order: &Order, quantity: u32, secret: Secret) -> u32 {
+    let _ = secret;
+    assert!(Context::current().span().span_context().is_valid());
+    quantity * 2
+}
+
+#[test]
+fn sync_function() {
+    assert_eq!(place_order(&Order { id: 7 }, 3, Secret), 6);
+
+    let span = finished_span("place_order");
+    assert_eq!(span.span_kind, SpanKind::Server);
+    assert_eq!(span.status, Status::Unset);
+    assert_eq!(
+        attribute(&span, "order"),
+        Some(Value::from("Order { id: 7 }"))
+    );
+    assert_eq!(attribute(&span, "quantity"), Some(Value::from("3")));
+    assert_eq!(attribute(&span, "secret"), None);
+    assert_eq!(attribute(&span, "order.id"), Some(Value::I64(7)));
+    assert_eq!(attribute(&span, "custom key"), Some(Value::from("value")));
+}
+
+#[instrument(tracer = &*TRACER)]
+fn keep_context() -> Context {
+    Context::current()
+}
+
+#[instrument(tracer = &*TRACER)]
+async fn keep_context_async() -> Context {
+    Context::current()
+}
+
+#[tokio::test]
+async fn span_ends_on_return() {
+    let cx = keep_context();
+    let span = finished_span("keep_context");
+    assert_eq!(cx.span().span_context(), &span.span_context);
+
+    let cx = keep_context_async().await;
+    let span = finished_span("keep_context_async");
+    assert_eq!(cx.span().span_context(), &span.span_context);
+}
+
+#[derive(Debug)]
+struct InvalidInput;
+
+impl fmt::Display for InvalidInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid input")
+    }
+}
+
+impl std::error::Error for InvalidInput {}
+
+#[instrument(tracer = &*TRACER, name = "parse")]
+fn parse_number(input: &str) -> Result<u32, InvalidInput> {
+    let number = input.parse().map_err(|_| InvalidInput)?;
+    Ok(number)
+}
+
+#[instrument(tracer = &*TRACER, skip_all)]
+fn boxed_error(fail: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    if fail {
+        return Err("boxed failure".into());
+    }
+    Ok(())
+}
+
+#[test]
+fn errors_are_recorded() {
+    assert_eq!(parse_number("42").unwrap(), 42);
+    let span = finished_span("parse");
+    assert_eq!(span.status, Status::Unset);
+    assert!(span.events.is_empty());
+    assert_eq!(attribute(&span, "input"), Some(Value::from("\"42\"")));
+
+    assert!(parse_number("x").is_err());
+    let span = finished_spans("parse").remove(1);
+    assert_eq!(span.status, Status::error("invalid input"));
+    assert_eq!(span.events.len(), 1);
+    let event = &span.events[0];
+    assert_eq!(event.name, "exception");
+    assert!(event
+        .attributes
+        .contains(&KeyValue::new("exception.message", "invalid input")));
+    assert!(event
+        .attributes
+        .contains(&KeyValue::new("exception.type", "instrument::InvalidInput")));
+    assert!(event
+        .attributes
+        .contains(&KeyValue::new("exception.escaped", true)));
+
+    assert!(boxed_error(true).is_err());
+    let span = finished_span("boxed_error");
+    assert_eq!(attribute(&span, "fail"), None);
+    assert_eq!(span.status, Status::error("boxed failure"));
+    assert_eq!(span.events[0].name, "exception");
+}
+
+#[instrument(tracer = &*TRACER, kind = client)]
+async fn fetch(id: u64) -> Result<u64, InvalidInput> {
+    std::future::ready(()).await;
+    lookup(id)
+}
+
+#[instrument(tracer = &*TRACER)]
+fn lookup(id: u64) -> Result<u64, InvalidInput> {
+    match id {
+        0 => Err(InvalidInput),
+        id => Ok(id + 1),
+    }
+}
+
+#[tokio::test]
+async fn async_function() {
+    assert_eq!(fetch(1).await.unwrap(), 2);
+
+    let fetch_span = finished_span("fetch");
+    let lookup_span = finished_span("lookup");
+    assert_eq!(fetch_span.span_kind, SpanKind::Client);
+    assert_eq!(attribute(&fetch_span, "id"), Some(Value::from("1")));
+    assert_eq!(
+        lookup_span.parent_span_id,
+        fetch_span.span_context.span_id()
+    );
+    assert_eq!(
+        lookup_span.span_context.trace_id(),
+        fetch_span.span_context.trace_id()
+    );
+}
+
+struct Client {
+    prefix: String,
+}
+
+impl Client {
+    #[instrument(tracer = &*TRACER, name = "client.greet")]
+    async fn greet(&self, name: &str) -> String {
+        format!("{} {name}", self.prefix)
+    }
+}
+
+#[tokio::test]
+async fn methods() {
+    let client = Client {
+        prefix: "hello".into(),
+    };
+    assert_eq!(client.greet("world").await, "hello world");
+
+    let span = finished_span("client.greet");
+    assert_eq!(attribute(&span, "self"), None);
+    assert_eq!(attribute(&span, "name"), Some(Value::from("\"world\"")));
+}
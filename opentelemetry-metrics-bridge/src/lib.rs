@@ -0,0 +1,74 @@
+//! Bridge metrics recorded with other Rust metrics libraries into OpenTelemetry.
+//!
+//! This crate provides [`MetricProducer`] implementations that translate
+//! metrics collected by third-party libraries into OpenTelemetry
+//! [`ScopeMetrics`], so they are exported alongside the metrics recorded
+//! through the OpenTelemetry API by any reader the producer is registered on.
+//!
+//! The bridges are enabled with cargo features:
+//!
+//! * `metrics-rs`: [`MetricsRsRecorder`], a [`metrics::Recorder`] whose
+//!   measurements are produced by [`MetricsRsProducer`].
+//! * `prometheus`: [`PrometheusProducer`], which gathers a
+//!   [`prometheus::Registry`].
+//!
+//! # Temporality
+//!
+//! Both libraries keep cumulative state, so counters, histograms and
+//! summaries are always produced with [`Temporality::Cumulative`] and a start
+//! time equal to the creation of the producer.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "prometheus")]
+//! # {
+//! use opentelemetry_metrics_bridge::PrometheusProducer;
+//! use opentelemetry_sdk::metrics::{ManualReader, SdkMeterProvider};
+//!
+//! let registry = prometheus::Registry::new();
+//! let reader = ManualReader::builder()
+//!     .with_producer(PrometheusProducer::new(registry.clone()))
+//!     .build();
+//! let provider = SdkMeterProvider::builder().with_reader(reader).build();
+//! # drop(provider);
+//! # }
+//! ```
+//!
+//! [`MetricProducer`]: opentelemetry_sdk::metrics::reader::MetricProducer
+//! [`ScopeMetrics`]: opentelemetry_sdk::metrics::data::ScopeMetrics
+//! [`Temporality::Cumulative`]: opentelemetry_sdk::metrics::data::Temporality::Cumulative
+#![warn(
+    future_incompatible,
+    missing_debug_implementations,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    unreachable_pub,
+    unused
+)]
+#![cfg_attr(
+    docsrs,
+    feature(doc_cfg, doc_auto_cfg),
+    deny(rustdoc::broken_intra_doc_links)
+)]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/open-telemetry/opentelemetry-rust/main/assets/logo.svg"
+)]
+
+#[cfg(feature = "metrics-rs")]
+mod metrics_rs;
+#[cfg(feature = "prometheus")]
+mod prometheus_bridge;
+
+#[cfg(feature = "metrics-rs")]
+pub use metrics_rs::{MetricsRsProducer, MetricsRsRecorder};
+#[cfg(feature = "prometheus")]
+pub use prometheus_bridge::PrometheusProducer;
+
+#[cfg(any(feature = "metrics-rs", feature = "prometheus"))]
+fn scope(name: &'static str) -> opentelemetry_sdk::Scope {
+    opentelemetry_sdk::Scope::builder(name)
+        .with_version(env!("CARGO_PKG_VERSION"))
+        .build()
+}
@@ -0,0 +1,447 @@
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    fmt,
+    sync::{atomic::Ordering, Arc, Mutex, RwLock},
+    time::SystemTime,
+};
+
+use metrics::{Key, KeyName, Metadata, Recorder, SharedString, Unit};
+use metrics_util::registry::{AtomicStorage, Registry};
+use opentelemetry::{metrics::Result, KeyValue};
+use opentelemetry_sdk::metrics::{
+    data::{self, Aggregation, Metric, ScopeMetrics, Temporality},
+    reader::MetricProducer,
+};
+
+const SCOPE_NAME: &str = "opentelemetry-metrics-bridge/metrics";
+
+/// The boundaries used for histograms unless configured otherwise, identical
+/// to the default boundaries of explicit bucket histograms in the SDK.
+const DEFAULT_BOUNDARIES: [f64; 15] = [
+    0.0, 5.0, 10.0, 25.0, 50.0, 75.0, 100.0, 250.0, 500.0, 750.0, 1000.0, 2500.0, 5000.0, 7500.0,
+    10000.0,
+];
+
+/// A [`metrics::Recorder`] storing measurements so they can be produced as
+/// OpenTelemetry metrics by a [`MetricsRsProducer`].
+///
+/// Metrics are converted as follows:
+///
+/// | metrics   | OpenTelemetry |
+/// |-----------|---------------|
+/// | counter   | monotonic cumulative [`Sum<u64>`](data::Sum) |
+/// | gauge     | [`Gauge<f64>`](data::Gauge) |
+/// | histogram | cumulative [`Histogram<f64>`](data::Histogram) |
+///
+/// Labels become data point attributes, descriptions and units registered with
+/// the `describe_*` macros are used as the metric description and unit.
+///
+/// # Example
+///
+/// ```
+/// use opentelemetry_metrics_bridge::MetricsRsRecorder;
+/// use opentelemetry_sdk::metrics::{ManualReader, SdkMeterProvider};
+///
+/// let recorder = MetricsRsRecorder::new();
+/// let reader = ManualReader::builder()
+///     .with_producer(recorder.producer())
+///     .build();
+/// let provider = SdkMeterProvider::builder().with_reader(reader).build();
+///
+/// metrics::set_global_recorder(recorder).expect("no recorder installed yet");
+/// metrics::counter!("requests", "code" => "200").increment(1);
+/// # drop(provider);
+/// ```
+#[derive(Debug, Default)]
+pub struct MetricsRsRecorder {
+    inner: Arc<Inner>,
+}
+
+impl MetricsRsRecorder {
+    /// Create a recorder using the default histogram boundaries.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use `boundaries` as the bucket boundaries of all histograms.
+    ///
+    /// Boundaries are sorted and deduplicated, `NaN` values are ignored.
+    pub fn with_histogram_boundaries(boundaries: Vec<f64>) -> Self {
+        let mut boundaries: Vec<f64> = boundaries.into_iter().filter(|b| !b.is_nan()).collect();
+        boundaries.sort_by(f64::total_cmp);
+        boundaries.dedup();
+
+        MetricsRsRecorder {
+            inner: Arc::new(Inner {
+                boundaries,
+                ..Inner::default()
+            }),
+        }
+    }
+
+    /// A [`MetricProducer`] producing the metrics recorded by this recorder.
+    pub fn producer(&self) -> MetricsRsProducer {
+        MetricsRsProducer {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+
+    fn describe(&self, key: KeyName, unit: Option<Unit>, description: SharedString) {
+        if let Ok(mut descriptions) = self.inner.descriptions.write() {
+            descriptions.insert(
+                key.as_str().to_string(),
+                Description {
+                    unit: unit.map(ucum),
+                    description: description.into_owned().into(),
+                },
+            );
+        }
+    }
+}
+
+impl Recorder for MetricsRsRecorder {
+    fn describe_counter(&self, key: KeyName, unit: Option<Unit>, description: SharedString) {
+        self.describe(key, unit, description)
+    }
+
+    fn describe_gauge(&self, key: KeyName, unit: Option<Unit>, description: SharedString) {
+        self.describe(key, unit, description)
+    }
+
+    fn describe_histogram(&self, key: KeyName, unit: Option<Unit>, description: SharedString) {
+        self.describe(key, unit, description)
+    }
+
+    fn register_counter(&self, key: &Key, _metadata: &Metadata<'_>) -> metrics::Counter {
+        self.inner
+            .registry
+            .get_or_create_counter(key, |c| c.clone().into())
+    }
+
+    fn register_gauge(&self, key: &Key, _metadata: &Metadata<'_>) -> metrics::Gauge {
+        self.inner
+            .registry
+            .get_or_create_gauge(key, |g| g.clone().into())
+    }
+
+    fn register_histogram(&self, key: &Key, _metadata: &Metadata<'_>) -> metrics::Histogram {
+        self.inner
+            .registry
+            .get_or_create_histogram(key, |h| h.clone().into())
+    }
+}
+
+/// A [`MetricProducer`] for the measurements of a [`MetricsRsRecorder`].
+///
+/// Created with [`MetricsRsRecorder::producer`].
+#[derive(Debug, Clone)]
+pub struct MetricsRsProducer {
+    inner: Arc<Inner>,
+}
+
+impl MetricProducer for MetricsRsProducer {
+    fn produce(&self) -> Result<ScopeMetrics> {
+        let now = SystemTime::now();
+        let inner = &self.inner;
+
+        let mut sums: BTreeMap<String, Vec<data::DataPoint<u64>>> = BTreeMap::new();
+        inner.registry.visit_counters(|key, counter| {
+            sums.entry(key.name().to_string())
+                .or_default()
+                .push(data::DataPoint {
+                    attributes: attributes(key),
+                    start_time: Some(inner.start_time),
+                    time: Some(now),
+                    value: counter.load(Ordering::Relaxed),
+                    exemplars: vec![],
+                });
+        });
+
+        let mut gauges: BTreeMap<String, Vec<data::DataPoint<f64>>> = BTreeMap::new();
+        inner.registry.visit_gauges(|key, gauge| {
+            gauges
+                .entry(key.name().to_string())
+                .or_default()
+                .push(data::DataPoint {
+                    attributes: attributes(key),
+                    start_time: None,
+                    time: Some(now),
+                    value: f64::from_bits(gauge.load(Ordering::Relaxed)),
+                    exemplars: vec![],
+                });
+        });
+
+        let mut histograms: BTreeMap<String, Vec<data::HistogramDataPoint<f64>>> = BTreeMap::new();
+        if let Ok(mut states) = inner.histograms.lock() {
+            inner.registry.visit_histograms(|key, bucket| {
+                let state = states
+                    .entry(key.clone())
+                    .or_insert_with(|| HistogramState::new(inner.boundaries.len()));
+                bucket.clear_with(|values| {
+                    for &value in values {
+                        state.record(&inner.boundaries, value);
+                    }
+                });
+            });
+
+            for (key, state) in states.iter() {
+                histograms.entry(key.name().to_string()).or_default().push(
+                    data::HistogramDataPoint {
+                        attributes: attributes(key),
+                        start_time: inner.start_time,
+                        time: now,
+                        count: state.count,
+                        bounds: inner.boundaries.clone(),
+                        bucket_counts: state.bucket_counts.clone(),
+                        min: state.min,
+                        max: state.max,
+                        sum: state.sum,
+                        exemplars: vec![],
+                    },
+                );
+            }
+        }
+
+        let descriptions = inner.descriptions.read().ok();
+        let metric = |name: String, data: Box<dyn Aggregation>| {
+            let description = descriptions.as_ref().and_then(|d| d.get(&name));
+            Metric {
+                description: description
+                    .map(|d| d.description.clone())
+                    .unwrap_or_default(),
+                unit: description.and_then(|d| d.unit.clone()).unwrap_or_default(),
+                name: name.into(),
+                data,
+            }
+        };
+
+        let mut metrics = Vec::with_capacity(sums.len() + gauges.len() + histograms.len());
+        for (name, data_points) in sums {
+            let sum = data::Sum {
+                data_points,
+                temporality: Temporality::Cumulative,
+                is_monotonic: true,
+            };
+            metrics.push(metric(name, Box::new(sum)));
+        }
+        for (name, data_points) in gauges {
+            metrics.push(metric(name, Box::new(data::Gauge { data_points })));
+        }
+        for (name, data_points) in histograms {
+            let histogram = data::Histogram {
+                data_points,
+                temporality: Temporality::Cumulative,
+            };
+            metrics.push(metric(name, Box::new(histogram)));
+        }
+
+        Ok(ScopeMetrics {
+            scope: crate::scope(SCOPE_NAME),
+            metrics,
+        })
+    }
+}
+
+struct Inner {
+    registry: Registry<Key, AtomicStorage>,
+    descriptions: RwLock<HashMap<String, Description>>,
+    /// Cumulative histogram state, `metrics` histograms only buffer the values
+    /// recorded since they were last drained.
+    histograms: Mutex<HashMap<Key, HistogramState>>,
+    boundaries: Vec<f64>,
+    start_time: SystemTime,
+}
+
+impl fmt::Debug for Inner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Inner")
+            .field("boundaries", &self.boundaries)
+            .field("start_time", &self.start_time)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for Inner {
+    fn default() -> Self {
+        Inner {
+            registry: Registry::atomic(),
+            descriptions: RwLock::default(),
+            histograms: Mutex::default(),
+            boundaries: DEFAULT_BOUNDARIES.to_vec(),
+            start_time: SystemTime::now(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Description {
+    unit: Option<Cow<'static, str>>,
+    description: Cow<'static, str>,
+}
+
+#[derive(Debug)]
+struct HistogramState {
+    bucket_counts: Vec<u64>,
+    count: u64,
+    sum: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+impl HistogramState {
+    fn new(bounds: usize) -> Self {
+        HistogramState {
+            bucket_counts: vec![0; bounds + 1],
+            count: 0,
+            sum: 0.0,
+            min: None,
+            max: None,
+        }
+    }
+
+    fn record(&mut self, boundaries: &[f64], value: f64) {
+        // Buckets are upper-bound inclusive, as in the SDK.
+        let index = boundaries.partition_point(|&b| b < value);
+        self.bucket_counts[index] += 1;
+        self.count += 1;
+        self.sum += value;
+        self.min = Some(self.min.map_or(value, |min| min.min(value)));
+        self.max = Some(self.max.map_or(value, |max| max.max(value)));
+    }
+}
+
+fn attributes(key: &Key) -> Vec<KeyValue> {
+    key.labels()
+        .map(|l| KeyValue::new(l.key().to_string(), l.value().to_string()))
+        .collect()
+}
+
+/// Maps a `metrics` unit to its [UCUM](https://ucum.org) code.
+fn ucum(unit: Unit) -> Cow<'static, str> {
+    let code = match unit {
+        Unit::Count => "1",
+        Unit::Percent => "%",
+        Unit::Seconds => "s",
+        Unit::Milliseconds => "ms",
+        Unit::Microseconds => "us",
+        Unit::Nanoseconds => "ns",
+        Unit::Tebibytes => "TiBy",
+        Unit::Gigibytes => "GiBy",
+        Unit::Mebibytes => "MiBy",
+        Unit::Kibibytes => "KiBy",
+        Unit::Bytes => "By",
+        Unit::TerabitsPerSecond => "Tbit/s",
+        Unit::GigabitsPerSecond => "Gbit/s",
+        Unit::MegabitsPerSecond => "Mbit/s",
+        Unit::KilobitsPerSecond => "kbit/s",
+        Unit::BitsPerSecond => "bit/s",
+        Unit::CountPerSecond => "1/s",
+    };
+    Cow::Borrowed(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use metrics::{Label, Level};
+
+    use super::*;
+
+    fn find<'a>(scope_metrics: &'a ScopeMetrics, name: &str) -> &'a Metric {
+        scope_metrics
+            .metrics
+            .iter()
+            .find(|m| m.name == name)
+            .unwrap_or_else(|| panic!("metric {name} not produced"))
+    }
+
+    fn metadata() -> Metadata<'static> {
+        Metadata::new("test", Level::INFO, None)
+    }
+
+    #[test]
+    fn counters_and_gauges() {
+        let recorder = MetricsRsRecorder::new();
+        let producer = recorder.producer();
+
+        recorder.describe_counter(
+            "requests".into(),
+            Some(Unit::Count),
+            "Handled requests".into(),
+        );
+        let key = Key::from_parts("requests", vec![Label::new("code", "200")]);
+        recorder.register_counter(&key, &metadata()).increment(2);
+        recorder.register_counter(&key, &metadata()).increment(3);
+        recorder
+            .register_gauge(&Key::from_name("temperature"), &metadata())
+            .set(-1.5);
+
+        let scope_metrics = producer.produce().unwrap();
+        assert_eq!(scope_metrics.scope.name, SCOPE_NAME);
+
+        let requests = find(&scope_metrics, "requests");
+        assert_eq!(requests.description, "Handled requests");
+        assert_eq!(requests.unit, "1");
+        let sum = requests
+            .data
+            .as_any()
+            .downcast_ref::<data::Sum<u64>>()
+            .unwrap();
+        assert!(sum.is_monotonic);
+        assert_eq!(sum.temporality, Temporality::Cumulative);
+        assert_eq!(sum.data_points[0].value, 5);
+        assert_eq!(
+            sum.data_points[0].attributes,
+            vec![KeyValue::new("code", "200")]
+        );
+
+        let gauge = find(&scope_metrics, "temperature")
+            .data
+            .as_any()
+            .downcast_ref::<data::Gauge<f64>>()
+            .unwrap();
+        assert_eq!(gauge.data_points[0].value, -1.5);
+    }
+
+    #[test]
+    fn histograms_are_cumulative() {
+        let recorder = MetricsRsRecorder::with_histogram_boundaries(vec![10.0, 1.0, f64::NAN]);
+        let producer = recorder.producer();
+        recorder.describe_histogram(
+            "latency".into(),
+            Some(Unit::Seconds),
+            "Request latency".into(),
+        );
+        let histogram = recorder.register_histogram(&Key::from_name("latency"), &metadata());
+        histogram.record(0.5);
+        histogram.record(1.0);
+
+        let scope_metrics = producer.produce().unwrap();
+        let latency = find(&scope_metrics, "latency");
+        assert_eq!(latency.unit, "s");
+        let dp = &latency
+            .data
+            .as_any()
+            .downcast_ref::<data::Histogram<f64>>()
+            .unwrap()
+            .data_points[0];
+        assert_eq!(dp.bounds, vec![1.0, 10.0]);
+        assert_eq!(dp.bucket_counts, vec![2, 0, 0]);
+        assert_eq!(dp.count, 2);
+
+        histogram.record(20.0);
+        let scope_metrics = producer.produce().unwrap();
+        let histogram = find(&scope_metrics, "latency")
+            .data
+            .as_any()
+            .downcast_ref::<data::Histogram<f64>>()
+            .unwrap();
+        assert_eq!(histogram.temporality, Temporality::Cumulative);
+        let dp = &histogram.data_points[0];
+        assert_eq!(dp.bucket_counts, vec![2, 0, 1]);
+        assert_eq!(dp.count, 3);
+        assert_eq!(dp.sum, 21.5);
+        assert_eq!(dp.min, Some(0.5));
+        assert_eq!(dp.max, Some(20.0));
+    }
+}
@@ -0,0 +1,331 @@
+use std::time::SystemTime;
+
+use opentelemetry::{metrics::Result, KeyValue};
+use opentelemetry_sdk::metrics::{
+    data::{self, Aggregation, Metric, QuantileValue, ScopeMetrics, Temporality},
+    reader::MetricProducer,
+};
+use prometheus::proto::{self, MetricFamily, MetricType};
+
+const SCOPE_NAME: &str = "opentelemetry-metrics-bridge/prometheus";
+
+/// A [`MetricProducer`] that gathers the metrics of a [`prometheus::Registry`].
+///
+/// Prometheus metric families are converted as follows:
+///
+/// | Prometheus | OpenTelemetry |
+/// |------------|---------------|
+/// | counter    | monotonic cumulative [`Sum<f64>`](data::Sum) |
+/// | gauge      | [`Gauge<f64>`](data::Gauge) |
+/// | untyped    | [`Gauge<f64>`](data::Gauge) |
+/// | histogram  | cumulative [`Histogram<f64>`](data::Histogram) |
+/// | summary    | cumulative [`Summary<f64>`](data::Summary) |
+///
+/// Metric names are kept as-is and labels become data point attributes.
+#[derive(Debug)]
+pub struct PrometheusProducer {
+    registry: prometheus::Registry,
+    start_time: SystemTime,
+}
+
+impl PrometheusProducer {
+    /// Create a producer gathering metrics from `registry`.
+    pub fn new(registry: prometheus::Registry) -> Self {
+        PrometheusProducer {
+            registry,
+            start_time: SystemTime::now(),
+        }
+    }
+}
+
+impl MetricProducer for PrometheusProducer {
+    fn produce(&self) -> Result<ScopeMetrics> {
+        let now = SystemTime::now();
+        let metrics = self
+            .registry
+            .gather()
+            .iter()
+            .filter_map(|family| {
+                let data = convert_family(family, self.start_time, now)?;
+                Some(Metric {
+                    name: family.get_name().to_string().into(),
+                    description: family.get_help().to_string().into(),
+                    unit: "".into(),
+                    data,
+                })
+            })
+            .collect();
+
+        Ok(ScopeMetrics {
+            scope: crate::scope(SCOPE_NAME),
+            metrics,
+        })
+    }
+}
+
+fn convert_family(
+    family: &MetricFamily,
+    start_time: SystemTime,
+    now: SystemTime,
+) -> Option<Box<dyn Aggregation>> {
+    let metrics = family.get_metric();
+    if metrics.is_empty() {
+        return None;
+    }
+
+    let data: Box<dyn Aggregation> = match family.get_field_type() {
+        MetricType::COUNTER => Box::new(data::Sum {
+            data_points: metrics
+                .iter()
+                .map(|m| data::DataPoint {
+                    attributes: attributes(m),
+                    start_time: Some(start_time),
+                    time: Some(time(m, now)),
+                    value: m.get_counter().get_value(),
+                    exemplars: vec![],
+                })
+                .collect(),
+            temporality: Temporality::Cumulative,
+            is_monotonic: true,
+        }),
+        MetricType::GAUGE | MetricType::UNTYPED => Box::new(data::Gauge {
+            data_points: metrics
+                .iter()
+                .map(|m| data::DataPoint {
+                    attributes: attributes(m),
+                    start_time: None,
+                    time: Some(time(m, now)),
+                    value: gauge_value(family.get_field_type(), m),
+                    exemplars: vec![],
+                })
+                .collect(),
+        }),
+        MetricType::HISTOGRAM => Box::new(data::Histogram {
+            data_points: metrics
+                .iter()
+                .map(|m| histogram_data_point(m, start_time, now))
+                .collect(),
+            temporality: Temporality::Cumulative,
+        }),
+        MetricType::SUMMARY => Box::new(data::Summary {
+            data_points: metrics
+                .iter()
+                .map(|m| {
+                    let summary = m.get_summary();
+                    data::SummaryDataPoint {
+                        attributes: attributes(m),
+                        start_time,
+                        time: time(m, now),
+                        count: summary.get_sample_count(),
+                        min: None,
+                        max: None,
+                        sum: summary.get_sample_sum(),
+                        quantile_values: summary
+                            .get_quantile()
+                            .iter()
+                            .map(|q| QuantileValue {
+                                quantile: q.get_quantile(),
+                                value: q.get_value(),
+                            })
+                            .collect(),
+                    }
+                })
+                .collect(),
+            temporality: Temporality::Cumulative,
+        }),
+    };
+
+    Some(data)
+}
+
+/// Converts prometheus cumulative buckets into explicit bucket counts.
+///
+/// The `+Inf` bucket is implied by the OpenTelemetry data model, its count is
+/// derived from the total sample count.
+fn histogram_data_point(
+    m: &proto::Metric,
+    start_time: SystemTime,
+    now: SystemTime,
+) -> data::HistogramDataPoint<f64> {
+    let histogram = m.get_histogram();
+    let count = histogram.get_sample_count();
+
+    let mut bounds = Vec::with_capacity(histogram.get_bucket().len());
+    let mut bucket_counts = Vec::with_capacity(histogram.get_bucket().len() + 1);
+    let mut previous = 0;
+    for bucket in histogram.get_bucket() {
+        if bucket.get_upper_bound().is_infinite() {
+            continue;
+        }
+        bounds.push(bucket.get_upper_bound());
+        bucket_counts.push(bucket.get_cumulative_count().saturating_sub(previous));
+        previous = bucket.get_cumulative_count();
+    }
+    bucket_counts.push(count.saturating_sub(previous));
+
+    data::HistogramDataPoint {
+        attributes: attributes(m),
+        start_time,
+        time: time(m, now),
+        count,
+        bounds,
+        bucket_counts,
+        min: None,
+        max: None,
+        sum: histogram.get_sample_sum(),
+        exemplars: vec![],
+    }
+}
+
+// Untyped metrics are deprecated by the client but can still be gathered from
+// custom collectors.
+#[allow(deprecated)]
+fn gauge_value(metric_type: MetricType, m: &proto::Metric) -> f64 {
+    if metric_type == MetricType::UNTYPED {
+        m.get_untyped().get_value()
+    } else {
+        m.get_gauge().get_value()
+    }
+}
+
+fn attributes(m: &proto::Metric) -> Vec<KeyValue> {
+    m.get_label()
+        .iter()
+        .map(|l| KeyValue::new(l.get_name().to_string(), l.get_value().to_string()))
+        .collect()
+}
+
+fn time(m: &proto::Metric, now: SystemTime) -> SystemTime {
+    match m.get_timestamp_ms() {
+        0 => now,
+        ms => SystemTime::UNIX_EPOCH + std::time::Duration::from_millis(ms as u64),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use opentelemetry_sdk::metrics::data;
+    use prometheus::{Counter, Gauge, Histogram, HistogramOpts, IntCounterVec, Opts, Registry};
+
+    use super::*;
+
+    fn find<'a>(scope_metrics: &'a ScopeMetrics, name: &str) -> &'a Metric {
+        scope_metrics
+            .metrics
+            .iter()
+            .find(|m| m.name == name)
+            .unwrap_or_else(|| panic!("metric {name} not produced"))
+    }
+
+    #[test]
+    fn counters_and_gauges() {
+        let registry = Registry::new();
+        let counter =
+            IntCounterVec::new(Opts::new("requests_total", "Requests"), &["code"]).unwrap();
+        registry.register(Box::new(counter.clone())).unwrap();
+        counter.with_label_values(&["200"]).inc_by(3);
+        let gauge = Gauge::new("temperature", "Temperature").unwrap();
+        registry.register(Box::new(gauge.clone())).unwrap();
+        gauge.set(-1.5);
+        let unused = Counter::new("unused_total", "Never observed").unwrap();
+        registry.register(Box::new(unused)).unwrap();
+
+        let producer = PrometheusProducer::new(registry);
+        let scope_metrics = producer.produce().unwrap();
+        assert_eq!(scope_metrics.scope.name, SCOPE_NAME);
+
+        let requests = find(&scope_metrics, "requests_total");
+        assert_eq!(requests.description, "Requests");
+        let sum = requests
+            .data
+            .as_any()
+            .downcast_ref::<data::Sum<f64>>()
+            .unwrap();
+        assert!(sum.is_monotonic);
+        assert_eq!(sum.temporality, Temporality::Cumulative);
+        assert_eq!(sum.data_points[0].value, 3.0);
+        assert_eq!(
+            sum.data_points[0].attributes,
+            vec![KeyValue::new("code", "200")]
+        );
+        assert_eq!(sum.data_points[0].start_time, Some(producer.start_time));
+
+        let temperature = find(&scope_metrics, "temperature");
+        let gauge = temperature
+            .data
+            .as_any()
+            .downcast_ref::<data::Gauge<f64>>()
+            .unwrap();
+        assert_eq!(gauge.data_points[0].value, -1.5);
+
+        let unused = find(&scope_metrics, "unused_total");
+        let sum = unused
+            .data
+            .as_any()
+            .downcast_ref::<data::Sum<f64>>()
+            .unwrap();
+        assert_eq!(sum.data_points[0].value, 0.0);
+    }
+
+    #[test]
+    fn histograms() {
+        let registry = Registry::new();
+        let histogram = Histogram::with_opts(
+            HistogramOpts::new("latency_seconds", "Latency").buckets(vec![0.1, 1.0]),
+        )
+        .unwrap();
+        registry.register(Box::new(histogram.clone())).unwrap();
+        for v in [0.05, 0.5, 0.7, 5.0] {
+            histogram.observe(v);
+        }
+
+        let scope_metrics = PrometheusProducer::new(registry).produce().unwrap();
+        let histogram = find(&scope_metrics, "latency_seconds")
+            .data
+            .as_any()
+            .downcast_ref::<data::Histogram<f64>>()
+            .unwrap();
+        assert_eq!(histogram.temporality, Temporality::Cumulative);
+
+        let dp = &histogram.data_points[0];
+        assert_eq!(dp.count, 4);
+        assert_eq!(dp.sum, 6.25);
+        assert_eq!(dp.bounds, vec![0.1, 1.0]);
+        assert_eq!(dp.bucket_counts, vec![1, 2, 1]);
+    }
+
+    #[test]
+    // Repeated fields are `RepeatedField`s when the `protobuf` feature is enabled.
+    #[allow(clippy::useless_conversion)]
+    fn summaries() {
+        // The prometheus client has no summary type, only gathered families
+        // from custom collectors contain summaries.
+        let mut family = MetricFamily::default();
+        family.set_name("size_bytes".into());
+        family.set_field_type(MetricType::SUMMARY);
+        let mut quantile = proto::Quantile::default();
+        quantile.set_quantile(0.5);
+        quantile.set_value(42.0);
+        let mut summary = proto::Summary::default();
+        summary.set_sample_count(2);
+        summary.set_sample_sum(84.0);
+        summary.set_quantile(vec![quantile].into());
+        let mut metric = proto::Metric::default();
+        metric.set_summary(summary);
+        family.set_metric(vec![metric].into());
+
+        let now = SystemTime::now();
+        let data = convert_family(&family, now, now).unwrap();
+        let summary = data.as_any().downcast_ref::<data::Summary<f64>>().unwrap();
+        let dp = &summary.data_points[0];
+        assert_eq!(dp.count, 2);
+        assert_eq!(dp.sum, 84.0);
+        assert_eq!(
+            dp.quantile_values,
+            vec![QuantileValue {
+                quantile: 0.5,
+                value: 42.0
+            }]
+        );
+    }
+}
@@ -0,0 +1,49 @@
+//! Propagator for the `ot-tracer-*` headers of [OpenTracing] clients.
+//!
+//! Services still instrumented with OpenTracing, e.g. through the LightStep
+//! tracers, propagate span contexts in the `ot-tracer-traceid`,
+//! `ot-tracer-spanid` and `ot-tracer-sampled` headers, and baggage entries in
+//! headers prefixed with `ot-baggage-`. The [`Propagator`] of this crate reads
+//! and writes these headers so traces continue across OpenTracing and
+//! OpenTelemetry instrumented services.
+//!
+//! *Compiler support: [requires `rustc` 1.65+][msrv]*
+//!
+//! [OpenTracing]: https://opentracing.io/
+//! [msrv]: #supported-rust-versions
+//!
+//! # Supported Rust Versions
+//!
+//! OpenTelemetry is built against the latest stable release. The minimum
+//! supported version is 1.65. The current OpenTelemetry version is not
+//! guaranteed to build on Rust versions earlier than the minimum supported
+//! version.
+//!
+//! The current stable Rust compiler and the three most recent minor versions
+//! before it will always be supported. For example, if the current stable
+//! compiler version is 1.65, the minimum supported version will not be
+//! increased past 1.62, three minor versions prior. Increasing the minimum
+//! supported compiler version is not considered a semver breaking change as
+//! long as doing so complies with this policy.
+#![warn(
+    future_incompatible,
+    missing_debug_implementations,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    unreachable_pub,
+    unused
+)]
+#![cfg_attr(
+    docsrs,
+    feature(doc_cfg, doc_auto_cfg),
+    deny(rustdoc::broken_intra_doc_links)
+)]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/open-telemetry/opentelemetry-rust/main/assets/logo.svg"
+)]
+#![cfg_attr(test, deny(warnings))]
+
+pub mod propagator;
+
+pub use propagator::Propagator;
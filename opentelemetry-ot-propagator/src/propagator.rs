@@ -0,0 +1,379 @@
+//! Propagator for the OpenTracing `ot-tracer-*` headers.
+use opentelemetry::propagation::PropagationError;
+use opentelemetry::{
+    baggage::{BaggageExt, KeyValueMetadata},
+    global::{self, Error},
+    propagation::{text_map_propagator::FieldIter, Extractor, Injector, TextMapPropagator},
+    trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState},
+    Context,
+};
+
+const OT_TRACE_ID_HEADER: &str = "ot-tracer-traceid";
+const OT_SPAN_ID_HEADER: &str = "ot-tracer-spanid";
+const OT_SAMPLED_HEADER: &str = "ot-tracer-sampled";
+const OT_BAGGAGE_PREFIX: &str = "ot-baggage-";
+
+/// `Propagator` implements the OpenTracing `ot-tracer-*` propagation format
+/// used by OpenTracing and LightStep clients.
+///
+/// Span contexts are propagated in the `ot-tracer-traceid`, `ot-tracer-spanid`
+/// and `ot-tracer-sampled` headers, and baggage entries in headers prefixed
+/// with `ot-baggage-`. These clients use 64-bit trace ids, so only the lower
+/// 64 bits of trace ids are injected, while both 64-bit and 128-bit trace ids
+/// are extracted. Baggage is only extracted along with a valid span context.
+///
+/// [`fields`] only reports the fixed headers, as the baggage header names
+/// depend on the baggage keys.
+///
+/// ## Examples
+///
+/// ```
+/// use opentelemetry::{global, propagation::TextMapCompositePropagator};
+/// use opentelemetry::trace::TraceContextExt;
+/// use opentelemetry_ot_propagator::Propagator as OtPropagator;
+/// use opentelemetry_sdk::propagation::TraceContextPropagator;
+/// use std::collections::HashMap;
+///
+/// // Accept requests from both OpenTelemetry and OpenTracing clients.
+/// global::set_text_map_propagator(TextMapCompositePropagator::new(vec![
+///     Box::new(TraceContextPropagator::new()),
+///     Box::new(OtPropagator::new()),
+/// ]));
+///
+/// let mut headers = HashMap::new();
+/// headers.insert("ot-tracer-traceid".to_string(), "e457b5a2e4d86bd1".to_string());
+/// headers.insert("ot-tracer-spanid".to_string(), "a2fb4a1d1a96d312".to_string());
+/// headers.insert("ot-tracer-sampled".to_string(), "true".to_string());
+///
+/// let cx = global::get_text_map_propagator(|propagator| propagator.extract(&headers));
+/// assert!(cx.span().span_context().is_sampled());
+/// ```
+///
+/// [`fields`]: TextMapPropagator::fields
+#[derive(Clone, Debug)]
+pub struct Propagator {
+    fields: [String; 3],
+}
+
+impl Default for Propagator {
+    fn default() -> Self {
+        Propagator::new()
+    }
+}
+
+impl Propagator {
+    /// Create an OpenTracing propagator
+    pub fn new() -> Self {
+        Propagator {
+            fields: [
+                OT_TRACE_ID_HEADER.to_owned(),
+                OT_SPAN_ID_HEADER.to_owned(),
+                OT_SAMPLED_HEADER.to_owned(),
+            ],
+        }
+    }
+
+    /// Extract span context from the headers
+    fn extract_span_context(&self, extractor: &dyn Extractor) -> Option<SpanContext> {
+        let trace_id = extractor.get(OT_TRACE_ID_HEADER)?;
+
+        if let (Ok(trace_id), Ok(span_id), Ok(flags)) = (
+            self.extract_trace_id(trace_id.trim()),
+            self.extract_span_id(extractor.get(OT_SPAN_ID_HEADER).unwrap_or("").trim()),
+            self.extract_trace_flags(extractor.get(OT_SAMPLED_HEADER).unwrap_or("").trim()),
+        ) {
+            let span_context =
+                SpanContext::new(trace_id, span_id, flags, true, TraceState::default());
+            if span_context.is_valid() {
+                return Some(span_context);
+            }
+        }
+
+        global::handle_error(Error::Propagation(PropagationError::extract(
+            "invalid ot-tracer headers",
+            "OtPropagator",
+        )));
+        None
+    }
+
+    /// Extract a 64-bit or 128-bit trace id.
+    fn extract_trace_id(&self, trace_id: &str) -> Result<TraceId, ()> {
+        match trace_id.len() {
+            16 | 32 => TraceId::from_hex(trace_id).map_err(|_| ()),
+            _ => Err(()),
+        }
+    }
+
+    /// Extract span id from the header.
+    fn extract_span_id(&self, span_id: &str) -> Result<SpanId, ()> {
+        if span_id.len() != 16 {
+            return Err(());
+        }
+        SpanId::from_hex(span_id).map_err(|_| ())
+    }
+
+    /// Extract flags from the sampled header, defaulting to not sampled if
+    /// absent.
+    fn extract_trace_flags(&self, sampled: &str) -> Result<TraceFlags, ()> {
+        if sampled.is_empty() || sampled == "0" || sampled.eq_ignore_ascii_case("false") {
+            Ok(TraceFlags::default())
+        } else if sampled == "1" || sampled.eq_ignore_ascii_case("true") {
+            Ok(TraceFlags::SAMPLED)
+        } else {
+            Err(())
+        }
+    }
+
+    /// Extract the baggage entries from the prefixed headers.
+    fn extract_baggage(&self, extractor: &dyn Extractor) -> Vec<KeyValueMetadata> {
+        extractor
+            .keys()
+            .into_iter()
+            .filter_map(|header| {
+                let key = header
+                    .strip_prefix(OT_BAGGAGE_PREFIX)
+                    .filter(|key| !key.is_empty())?;
+                let value = extractor.get(header)?;
+                Some(KeyValueMetadata::new(
+                    key.to_string(),
+                    value.to_string(),
+                    "",
+                ))
+            })
+            .collect()
+    }
+}
+
+impl TextMapPropagator for Propagator {
+    fn inject_context(&self, cx: &Context, injector: &mut dyn Injector) {
+        let span = cx.span();
+        let span_context = span.span_context();
+        if !span_context.is_valid() {
+            return;
+        }
+
+        // OpenTracing clients only support 64-bit trace ids, keep the lower bits.
+        let trace_id = span_context.trace_id().to_string();
+        injector.set(OT_TRACE_ID_HEADER, trace_id[16..].to_string());
+        injector.set(OT_SPAN_ID_HEADER, span_context.span_id().to_string());
+        injector.set(OT_SAMPLED_HEADER, span_context.is_sampled().to_string());
+
+        for (key, (value, _metadata)) in cx.baggage() {
+            injector.set(
+                &format!("{OT_BAGGAGE_PREFIX}{}", key.as_str()),
+                value.as_str().into_owned(),
+            );
+        }
+    }
+
+    fn extract_with_context(&self, cx: &Context, extractor: &dyn Extractor) -> Context {
+        match self.extract_span_context(extractor) {
+            Some(span_context) => cx
+                .with_baggage(self.extract_baggage(extractor))
+                .with_remote_span_context(span_context),
+            None => cx.clone(),
+        }
+    }
+
+    fn fields(&self) -> FieldIter<'_> {
+        FieldIter::new(self.fields.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::propagation::TextMapCompositePropagator;
+    use opentelemetry::{Key, KeyValue, StringValue};
+    use opentelemetry_sdk::propagation::TraceContextPropagator;
+    use std::collections::HashMap;
+
+    const TRACE_ID_STR: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+    const TRACE_ID: u128 = 0x4bf9_2f35_77b3_4da6_a3ce_929d_0e0e_4736;
+    const SHORT_TRACE_ID_STR: &str = "a3ce929d0e0e4736";
+    const SHORT_TRACE_ID: u128 = 0xa3ce_929d_0e0e_4736;
+    const SPAN_ID_STR: &str = "00f067aa0ba902b7";
+    const SPAN_ID: u64 = 0x00f0_67aa_0ba9_02b7;
+
+    fn headers(trace_id: &str, span_id: &str, sampled: Option<&str>) -> HashMap<String, String> {
+        let mut headers = HashMap::new();
+        headers.insert(OT_TRACE_ID_HEADER.to_string(), trace_id.to_string());
+        headers.insert(OT_SPAN_ID_HEADER.to_string(), span_id.to_string());
+        if let Some(sampled) = sampled {
+            headers.insert(OT_SAMPLED_HEADER.to_string(), sampled.to_string());
+        }
+        headers
+    }
+
+    fn span_context(trace_id: u128, flags: TraceFlags, is_remote: bool) -> SpanContext {
+        SpanContext::new(
+            TraceId::from_u128(trace_id),
+            SpanId::from_u64(SPAN_ID),
+            flags,
+            is_remote,
+            TraceState::default(),
+        )
+    }
+
+    #[test]
+    fn test_extract_span_context() {
+        let data = vec![
+            (TRACE_ID_STR, Some("true"), TRACE_ID, TraceFlags::SAMPLED),
+            (TRACE_ID_STR, Some("false"), TRACE_ID, TraceFlags::default()),
+            (TRACE_ID_STR, Some("1"), TRACE_ID, TraceFlags::SAMPLED),
+            (TRACE_ID_STR, Some("0"), TRACE_ID, TraceFlags::default()),
+            (TRACE_ID_STR, None, TRACE_ID, TraceFlags::default()),
+            (
+                SHORT_TRACE_ID_STR,
+                Some("true"),
+                SHORT_TRACE_ID,
+                TraceFlags::SAMPLED,
+            ),
+        ];
+
+        let propagator = Propagator::new();
+        for (trace_id, sampled, expected_trace_id, expected_flags) in data {
+            let cx = propagator.extract(&headers(trace_id, SPAN_ID_STR, sampled));
+            assert_eq!(
+                cx.span().span_context(),
+                &span_context(expected_trace_id, expected_flags, true),
+                "{trace_id} {sampled:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_extract_invalid_headers() {
+        let data = vec![
+            headers("", SPAN_ID_STR, Some("true")),
+            headers("4bf92f3577b34da6a3ce929d0e0e473", SPAN_ID_STR, Some("true")),
+            headers(
+                "4bf92f3577b34da6a3ce929d0e0e473g",
+                SPAN_ID_STR,
+                Some("true"),
+            ),
+            headers(
+                "00000000000000000000000000000000",
+                SPAN_ID_STR,
+                Some("true"),
+            ),
+            headers(TRACE_ID_STR, "00f067aa0ba902b", Some("true")),
+            headers(TRACE_ID_STR, "0000000000000000", Some("true")),
+            headers(TRACE_ID_STR, SPAN_ID_STR, Some("yes")),
+        ];
+
+        let propagator = Propagator::new();
+        for mut headers in data {
+            headers.insert("ot-baggage-tenant".to_string(), "acme".to_string());
+            let cx = propagator.extract(&headers);
+            assert!(!cx.has_active_span(), "{headers:?}");
+            assert!(cx.baggage().is_empty(), "{headers:?}");
+        }
+        assert!(!propagator
+            .extract(&HashMap::<String, String>::new())
+            .has_active_span());
+    }
+
+    #[test]
+    fn test_extract_baggage() {
+        let mut headers = headers(TRACE_ID_STR, SPAN_ID_STR, Some("true"));
+        headers.insert("ot-baggage-tenant".to_string(), "acme".to_string());
+        headers.insert("ot-baggage-user".to_string(), "jane".to_string());
+        headers.insert("ot-baggage-".to_string(), "empty key".to_string());
+        headers.insert("x-other".to_string(), "ignored".to_string());
+
+        let cx = Propagator::new().extract(&headers);
+        let baggage = cx.baggage();
+        assert_eq!(baggage.len(), 2);
+        assert_eq!(baggage.get("tenant"), Some(&"acme".into()));
+        assert_eq!(baggage.get("user"), Some(&"jane".into()));
+    }
+
+    #[test]
+    fn test_inject() {
+        let data = vec![
+            (TraceFlags::SAMPLED, "true"),
+            (TraceFlags::default(), "false"),
+        ];
+
+        let propagator = Propagator::new();
+        for (flags, sampled) in data {
+            let cx = Context::new()
+                .with_remote_span_context(span_context(TRACE_ID, flags, false))
+                .with_baggage(vec![KeyValue::new("tenant", "acme")]);
+            let mut injector = HashMap::new();
+            propagator.inject_context(&cx, &mut injector);
+
+            let mut expected = headers(SHORT_TRACE_ID_STR, SPAN_ID_STR, Some(sampled));
+            expected.insert("ot-baggage-tenant".to_string(), "acme".to_string());
+            assert_eq!(injector, expected);
+        }
+    }
+
+    #[test]
+    fn test_inject_invalid_span_context() {
+        let cx = Context::new()
+            .with_remote_span_context(SpanContext::empty_context())
+            .with_baggage(vec![KeyValue::new("tenant", "acme")]);
+        let mut injector = HashMap::new();
+        Propagator::new().inject_context(&cx, &mut injector);
+        assert!(injector.is_empty());
+    }
+
+    #[test]
+    fn test_inject_extract_roundtrip() {
+        let propagator = Propagator::new();
+        let cx = Context::new()
+            .with_remote_span_context(span_context(SHORT_TRACE_ID, TraceFlags::SAMPLED, false))
+            .with_baggage(vec![KeyValue::new("tenant", "acme")]);
+        let mut injector = HashMap::new();
+        propagator.inject_context(&cx, &mut injector);
+
+        let extracted = propagator.extract(&injector);
+        assert_eq!(
+            extracted.span().span_context(),
+            &span_context(SHORT_TRACE_ID, TraceFlags::SAMPLED, true)
+        );
+        assert_eq!(
+            extracted.baggage().get(Key::from_static_str("tenant")),
+            Some(&opentelemetry::Value::String(StringValue::from("acme")))
+        );
+    }
+
+    #[test]
+    fn test_composite_propagator() {
+        let composite = TextMapCompositePropagator::new(vec![
+            Box::new(TraceContextPropagator::new()),
+            Box::new(Propagator::new()),
+        ]);
+        let cx = composite.extract(&headers(TRACE_ID_STR, SPAN_ID_STR, Some("true")));
+        assert_eq!(
+            cx.span().span_context(),
+            &span_context(TRACE_ID, TraceFlags::SAMPLED, true)
+        );
+
+        let mut injector = HashMap::new();
+        composite.inject_context(&cx, &mut injector);
+        assert_eq!(
+            injector.get("traceparent").map(String::as_str),
+            Some("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01")
+        );
+        assert_eq!(
+            injector.get(OT_TRACE_ID_HEADER).map(String::as_str),
+            Some(SHORT_TRACE_ID_STR)
+        );
+
+        let fields = composite.fields().collect::<Vec<_>>();
+        assert!(fields.contains(&"traceparent"));
+        assert!(fields.contains(&OT_SAMPLED_HEADER));
+    }
+
+    #[test]
+    fn test_fields() {
+        let propagator = Propagator::new();
+        assert_eq!(
+            propagator.fields().collect::<Vec<_>>(),
+            vec![OT_TRACE_ID_HEADER, OT_SPAN_ID_HEADER, OT_SAMPLED_HEADER]
+        );
+    }
+}
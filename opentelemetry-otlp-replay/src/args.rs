@@ -0,0 +1,258 @@
+//! Command line parsing.
+use crate::filter::Filter;
+use crate::reader::Signal;
+use crate::send::SendConfig;
+use crate::Result;
+use opentelemetry_otlp::Protocol;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub(crate) const USAGE: &str = "\
+Print, filter, summarize and resend recorded OTLP telemetry.
+
+Usage: otlp-replay <COMMAND> [OPTIONS] <FILE>...
+
+Commands:
+  print   Print the recorded telemetry
+  stats   Print summary statistics of the recorded telemetry
+  send    Resend the recorded telemetry to an OTLP endpoint
+
+Files hold one OTLP JSON encoded export request per line or length-prefixed
+protobuf encoded export requests, the encoding is detected from the content.
+
+Options:
+  --signal <SIGNAL>       Only read `traces`, `logs` or `metrics`, required for
+                          protobuf files as they don't name their signal
+  --service <NAME>        Only include resources with this `service.name`
+  --trace-id <HEX>        Only include spans and logs of this trace
+  --since <TIME>          Only include items at or after TIME, as RFC 3339 or
+                          nanoseconds since the unix epoch
+  --until <TIME>          Only include items before TIME
+  -h, --help              Print this help
+
+Print options:
+  --json                  Print OTLP JSON lines instead of a readable layout
+
+Send options:
+  --endpoint <URL>        The collector endpoint, the base URL for HTTP
+                          protocols; defaults to the OTEL_EXPORTER_OTLP_*
+                          environment variables
+  --protocol <PROTOCOL>   `grpc` (default), `http/protobuf` or `http/json`
+  --header <KEY=VALUE>    Add a header or gRPC metadata entry, repeatable
+  --timeout <SECONDS>     Timeout of each export request
+";
+
+/// A parsed command line.
+#[derive(Debug)]
+pub(crate) struct Args {
+    pub(crate) command: Command,
+    pub(crate) files: Vec<PathBuf>,
+    pub(crate) signal: Option<Signal>,
+    pub(crate) filter: Filter,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Command {
+    Help,
+    Print { json: bool },
+    Stats,
+    Send(SendConfig),
+}
+
+impl Args {
+    pub(crate) fn parse(args: impl IntoIterator<Item = String>) -> Result<Args> {
+        let mut args = args.into_iter();
+        let mut command = match args.next().as_deref() {
+            None | Some("-h" | "--help" | "help") => return Ok(Args::help()),
+            Some("print") => Command::Print { json: false },
+            Some("stats") => Command::Stats,
+            Some("send") => Command::Send(SendConfig::default()),
+            Some(other) => return Err(format!("unknown command `{other}`").into()),
+        };
+        let mut files = Vec::new();
+        let mut signal = None;
+        let mut filter = Filter::default();
+
+        while let Some(arg) = args.next() {
+            if !arg.starts_with('-') {
+                files.push(PathBuf::from(arg));
+                continue;
+            }
+            let (name, inline_value) = match arg.split_once('=') {
+                Some((name, value)) => (name.to_owned(), Some(value.to_owned())),
+                None => (arg, None),
+            };
+            let mut value = || {
+                inline_value
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("missing value for `{name}`"))
+            };
+
+            match (name.as_str(), &mut command) {
+                ("-h" | "--help", _) => return Ok(Args::help()),
+                ("--signal", _) => signal = Some(value()?.parse()?),
+                ("--service", _) => filter.service = Some(value()?),
+                ("--trace-id", _) => filter.trace_id = Some(parse_trace_id(&value()?)?),
+                ("--since", _) => filter.since = Some(parse_time(&value()?)?),
+                ("--until", _) => filter.until = Some(parse_time(&value()?)?),
+                ("--json", Command::Print { json }) => *json = true,
+                ("--endpoint", Command::Send(config)) => config.endpoint = Some(value()?),
+                ("--protocol", Command::Send(config)) => {
+                    config.protocol = parse_protocol(&value()?)?
+                }
+                ("--header", Command::Send(config)) => {
+                    let header = value()?;
+                    let (key, value) = header
+                        .split_once('=')
+                        .ok_or_else(|| format!("header `{header}` is not a KEY=VALUE pair"))?;
+                    config
+                        .headers
+                        .push((key.trim().into(), value.trim().into()));
+                }
+                ("--timeout", Command::Send(config)) => {
+                    let timeout = value()?;
+                    let seconds = timeout
+                        .parse()
+                        .map_err(|_| format!("invalid timeout `{timeout}`"))?;
+                    config.timeout = Some(Duration::from_secs(seconds));
+                }
+                _ => return Err(format!("unexpected option `{name}`").into()),
+            }
+        }
+
+        if files.is_empty() {
+            return Err("no files given".into());
+        }
+        Ok(Args {
+            command,
+            files,
+            signal,
+            filter,
+        })
+    }
+
+    fn help() -> Args {
+        Args {
+            command: Command::Help,
+            files: Vec::new(),
+            signal: None,
+            filter: Filter::default(),
+        }
+    }
+}
+
+fn parse_trace_id(value: &str) -> Result<[u8; 16]> {
+    let invalid = || format!("invalid trace id `{value}`, expected 32 hex digits");
+    if value.len() != 32 {
+        return Err(invalid().into());
+    }
+    u128::from_str_radix(value, 16)
+        .map(u128::to_be_bytes)
+        .map_err(|_| invalid().into())
+}
+
+/// Parses RFC 3339 times or nanoseconds since the unix epoch.
+fn parse_time(value: &str) -> Result<u64> {
+    if let Ok(nanos) = value.parse() {
+        return Ok(nanos);
+    }
+    chrono::DateTime::parse_from_rfc3339(value)
+        .ok()
+        .and_then(|time| time.timestamp_nanos_opt())
+        .and_then(|nanos| u64::try_from(nanos).ok())
+        .ok_or_else(|| {
+            format!("invalid time `{value}`, expected RFC 3339 or unix nanoseconds").into()
+        })
+}
+
+fn parse_protocol(value: &str) -> Result<Protocol> {
+    match value {
+        "grpc" => Ok(Protocol::Grpc),
+        "http/protobuf" => Ok(Protocol::HttpBinary),
+        "http/json" => Ok(Protocol::HttpJson),
+        _ => Err(format!("unknown protocol `{value}`").into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args> {
+        Args::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn print_with_filters() {
+        let args = parse(&[
+            "print",
+            "--service",
+            "checkout",
+            "--trace-id=0102030405060708090a0b0c0d0e0f10",
+            "--since",
+            "2024-08-01T12:00:00Z",
+            "--until",
+            "1722517200000000001",
+            "--json",
+            "traces.jsonl",
+            "more.jsonl",
+        ])
+        .unwrap();
+
+        assert_eq!(args.command, Command::Print { json: true });
+        assert_eq!(
+            args.files,
+            vec![PathBuf::from("traces.jsonl"), PathBuf::from("more.jsonl")]
+        );
+        assert_eq!(args.filter.service.as_deref(), Some("checkout"));
+        assert_eq!(
+            args.filter.trace_id,
+            Some(0x0102030405060708090a0b0c0d0e0f10u128.to_be_bytes())
+        );
+        assert_eq!(args.filter.since, Some(1_722_513_600_000_000_000));
+        assert_eq!(args.filter.until, Some(1_722_517_200_000_000_001));
+    }
+
+    #[test]
+    fn send_options() {
+        let args = parse(&[
+            "send",
+            "--signal",
+            "logs",
+            "--endpoint",
+            "http://localhost:4318",
+            "--protocol",
+            "http/json",
+            "--header",
+            "authorization=Bearer token",
+            "--timeout",
+            "3",
+            "logs.binpb",
+        ])
+        .unwrap();
+
+        assert_eq!(args.signal, Some(Signal::Logs));
+        let Command::Send(config) = args.command else {
+            panic!("expected the send command");
+        };
+        assert_eq!(config.endpoint.as_deref(), Some("http://localhost:4318"));
+        assert_eq!(config.protocol, Protocol::HttpJson);
+        assert_eq!(
+            config.headers,
+            vec![("authorization".to_string(), "Bearer token".to_string())]
+        );
+        assert_eq!(config.timeout, Some(Duration::from_secs(3)));
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        assert!(parse(&["print"]).is_err());
+        assert!(parse(&["replay", "traces.jsonl"]).is_err());
+        assert!(parse(&["stats", "--json", "traces.jsonl"]).is_err());
+        assert!(parse(&["print", "--trace-id", "0102", "traces.jsonl"]).is_err());
+        assert!(parse(&["print", "--since", "yesterday", "traces.jsonl"]).is_err());
+        assert!(parse(&["send", "--protocol", "udp", "traces.jsonl"]).is_err());
+        assert_eq!(parse(&[]).unwrap().command, Command::Help);
+    }
+}
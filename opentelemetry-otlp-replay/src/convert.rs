@@ -0,0 +1,763 @@
+//! Conversions of recorded OTLP messages back into SDK data, the reverse of
+//! `opentelemetry_proto::transform`, so they can be resent with the OTLP
+//! exporters.
+use opentelemetry::logs::{AnyValue as LogsAnyValue, LogRecord as _, Severity};
+use opentelemetry::trace::{
+    Event, Link, SpanContext, SpanId, SpanKind, Status, TraceFlags, TraceId, TraceState,
+};
+use opentelemetry::{Array, InstrumentationLibrary, Key, KeyValue, StringValue, Value};
+use opentelemetry_proto::tonic::{
+    common::v1::{
+        any_value, AnyValue, ArrayValue, InstrumentationScope, KeyValue as ProtoKeyValue,
+    },
+    logs::v1::LogRecord as ProtoLogRecord,
+    metrics::v1::{
+        exemplar, metric::Data, number_data_point, Exemplar as ProtoExemplar,
+        ExponentialHistogram as ProtoExponentialHistogram, Gauge as ProtoGauge,
+        Histogram as ProtoHistogram, NumberDataPoint, ResourceMetrics as ProtoResourceMetrics,
+        Sum as ProtoSum, Summary as ProtoSummary,
+    },
+    resource::v1::Resource as ProtoResource,
+    trace::v1::{span, status::StatusCode, Span},
+};
+use opentelemetry_sdk::export::trace::SpanData;
+use opentelemetry_sdk::logs::{LogRecord, TraceContext};
+use opentelemetry_sdk::metrics::data::{
+    Aggregation, DataPoint, Exemplar, ExponentialBucket, ExponentialHistogram,
+    ExponentialHistogramDataPoint, Gauge, Histogram, HistogramDataPoint, Metric, QuantileValue,
+    ResourceMetrics, ScopeMetrics, Sum, Summary, SummaryDataPoint, Temporality,
+};
+use opentelemetry_sdk::trace::{SpanEvents, SpanLinks};
+use opentelemetry_sdk::Resource;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+pub(crate) fn time(nanos: u64) -> SystemTime {
+    SystemTime::UNIX_EPOCH + Duration::from_nanos(nanos)
+}
+
+pub(crate) fn resource(resource: Option<ProtoResource>, schema_url: String) -> Resource {
+    let attributes = key_values(resource.map(|r| r.attributes).unwrap_or_default());
+    if schema_url.is_empty() {
+        Resource::new(attributes)
+    } else {
+        Resource::from_schema_url(attributes, schema_url)
+    }
+}
+
+pub(crate) fn scope(
+    scope: Option<InstrumentationScope>,
+    schema_url: String,
+) -> InstrumentationLibrary {
+    let scope = scope.unwrap_or_default();
+    let mut builder =
+        InstrumentationLibrary::builder(scope.name).with_attributes(key_values(scope.attributes));
+    if !scope.version.is_empty() {
+        builder = builder.with_version(scope.version);
+    }
+    if !schema_url.is_empty() {
+        builder = builder.with_schema_url(schema_url);
+    }
+    builder.build()
+}
+
+pub(crate) fn key_values(attributes: Vec<ProtoKeyValue>) -> Vec<KeyValue> {
+    attributes
+        .into_iter()
+        .filter_map(|kv| Some(KeyValue::new(kv.key, value(kv.value?.value?))))
+        .collect()
+}
+
+/// Converts a value, arrays of mixed types, maps and bytes can't be attribute
+/// values and are converted to their display string.
+fn value(value: any_value::Value) -> Value {
+    match value {
+        any_value::Value::StringValue(s) => Value::String(s.into()),
+        any_value::Value::BoolValue(b) => Value::Bool(b),
+        any_value::Value::IntValue(i) => Value::I64(i),
+        any_value::Value::DoubleValue(d) => Value::F64(d),
+        any_value::Value::ArrayValue(array) => match array_value(&array) {
+            Some(array) => Value::Array(array),
+            None => Value::String(
+                DisplayValue(&any_value::Value::ArrayValue(array))
+                    .to_string()
+                    .into(),
+            ),
+        },
+        other => Value::String(DisplayValue(&other).to_string().into()),
+    }
+}
+
+fn array_value(array: &ArrayValue) -> Option<Array> {
+    let values = || array.values.iter().map(|v| v.value.as_ref());
+    match values().next() {
+        None => Some(Array::String(Vec::new())),
+        Some(Some(any_value::Value::StringValue(_))) => values()
+            .map(|v| match v {
+                Some(any_value::Value::StringValue(s)) => Some(StringValue::from(s.clone())),
+                _ => None,
+            })
+            .collect::<Option<_>>()
+            .map(Array::String),
+        Some(Some(any_value::Value::BoolValue(_))) => values()
+            .map(|v| match v {
+                Some(any_value::Value::BoolValue(b)) => Some(*b),
+                _ => None,
+            })
+            .collect::<Option<_>>()
+            .map(Array::Bool),
+        Some(Some(any_value::Value::IntValue(_))) => values()
+            .map(|v| match v {
+                Some(any_value::Value::IntValue(i)) => Some(*i),
+                _ => None,
+            })
+            .collect::<Option<_>>()
+            .map(Array::I64),
+        Some(Some(any_value::Value::DoubleValue(_))) => values()
+            .map(|v| match v {
+                Some(any_value::Value::DoubleValue(d)) => Some(*d),
+                _ => None,
+            })
+            .collect::<Option<_>>()
+            .map(Array::F64),
+        _ => None,
+    }
+}
+
+fn log_value(value: any_value::Value) -> LogsAnyValue {
+    match value {
+        any_value::Value::StringValue(s) => LogsAnyValue::String(s.into()),
+        any_value::Value::BoolValue(b) => LogsAnyValue::Boolean(b),
+        any_value::Value::IntValue(i) => LogsAnyValue::Int(i),
+        any_value::Value::DoubleValue(d) => LogsAnyValue::Double(d),
+        any_value::Value::ArrayValue(array) => LogsAnyValue::ListAny(Box::new(
+            array
+                .values
+                .into_iter()
+                .filter_map(|v| v.value.map(log_value))
+                .collect(),
+        )),
+        any_value::Value::KvlistValue(list) => LogsAnyValue::Map(Box::new(
+            list.values
+                .into_iter()
+                .filter_map(|kv| Some((Key::from(kv.key), log_value(kv.value?.value?))))
+                .collect::<HashMap<_, _>>(),
+        )),
+        any_value::Value::BytesValue(bytes) => LogsAnyValue::Bytes(Box::new(bytes)),
+    }
+}
+
+/// Displays a value the way it would be written in code, strings are quoted.
+pub(crate) struct DisplayValue<'a>(pub(crate) &'a any_value::Value);
+
+impl fmt::Display for DisplayValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            any_value::Value::StringValue(s) => write!(f, "{s:?}"),
+            any_value::Value::BoolValue(b) => write!(f, "{b}"),
+            any_value::Value::IntValue(i) => write!(f, "{i}"),
+            any_value::Value::DoubleValue(d) => write!(f, "{d}"),
+            any_value::Value::ArrayValue(array) => {
+                f.write_str("[")?;
+                for (i, value) in array.values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write_any_value(f, value)?;
+                }
+                f.write_str("]")
+            }
+            any_value::Value::KvlistValue(list) => {
+                f.write_str("{")?;
+                for (i, kv) in list.values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}: ", kv.key)?;
+                    match &kv.value {
+                        Some(value) => write_any_value(f, value)?,
+                        None => f.write_str("null")?,
+                    }
+                }
+                f.write_str("}")
+            }
+            any_value::Value::BytesValue(bytes) => {
+                f.write_str("0x")?;
+                bytes.iter().try_for_each(|b| write!(f, "{b:02x}"))
+            }
+        }
+    }
+}
+
+fn write_any_value(f: &mut fmt::Formatter<'_>, value: &AnyValue) -> fmt::Result {
+    match &value.value {
+        Some(value) => write!(f, "{}", DisplayValue(value)),
+        None => f.write_str("null"),
+    }
+}
+
+fn trace_id(id: &[u8]) -> TraceId {
+    <[u8; 16]>::try_from(id).map_or(TraceId::INVALID, TraceId::from_bytes)
+}
+
+fn span_id(id: &[u8]) -> SpanId {
+    <[u8; 8]>::try_from(id).map_or(SpanId::INVALID, SpanId::from_bytes)
+}
+
+/// Bits of the span and link flags telling whether the context was propagated
+/// from a remote parent.
+const CONTEXT_HAS_IS_REMOTE_MASK: u32 = 0x100;
+const CONTEXT_IS_REMOTE_MASK: u32 = 0x200;
+
+fn span_context(
+    trace_id_bytes: &[u8],
+    span_id_bytes: &[u8],
+    flags: u32,
+    trace_state: &str,
+) -> SpanContext {
+    let is_remote = flags & CONTEXT_HAS_IS_REMOTE_MASK != 0 && flags & CONTEXT_IS_REMOTE_MASK != 0;
+    SpanContext::new(
+        trace_id(trace_id_bytes),
+        span_id(span_id_bytes),
+        TraceFlags::new(flags as u8),
+        is_remote,
+        trace_state
+            .parse()
+            .unwrap_or_else(|_| TraceState::default()),
+    )
+}
+
+pub(crate) fn span_data(span: Span, scope: &InstrumentationLibrary) -> SpanData {
+    let mut events = SpanEvents::default();
+    events.events = span
+        .events
+        .into_iter()
+        .map(|event| {
+            Event::new(
+                event.name,
+                time(event.time_unix_nano),
+                key_values(event.attributes),
+                event.dropped_attributes_count,
+            )
+        })
+        .collect();
+    events.dropped_count = span.dropped_events_count;
+
+    let mut links = SpanLinks::default();
+    links.links = span
+        .links
+        .into_iter()
+        .map(|link| {
+            Link::new(
+                span_context(&link.trace_id, &link.span_id, link.flags, &link.trace_state),
+                key_values(link.attributes),
+                link.dropped_attributes_count,
+            )
+        })
+        .collect();
+    links.dropped_count = span.dropped_links_count;
+
+    let status = span.status.unwrap_or_default();
+    SpanData {
+        span_context: span_context(&span.trace_id, &span.span_id, span.flags, &span.trace_state),
+        parent_span_id: span_id(&span.parent_span_id),
+        span_kind: match span::SpanKind::try_from(span.kind) {
+            Ok(span::SpanKind::Server) => SpanKind::Server,
+            Ok(span::SpanKind::Client) => SpanKind::Client,
+            Ok(span::SpanKind::Producer) => SpanKind::Producer,
+            Ok(span::SpanKind::Consumer) => SpanKind::Consumer,
+            _ => SpanKind::Internal,
+        },
+        name: span.name.into(),
+        start_time: time(span.start_time_unix_nano),
+        end_time: time(span.end_time_unix_nano),
+        attributes: key_values(span.attributes),
+        dropped_attributes_count: span.dropped_attributes_count,
+        events,
+        links,
+        status: match StatusCode::try_from(status.code) {
+            Ok(StatusCode::Ok) => Status::Ok,
+            Ok(StatusCode::Error) => Status::error(status.message),
+            _ => Status::Unset,
+        },
+        instrumentation_lib: scope.clone(),
+    }
+}
+
+const SEVERITIES: [Severity; 24] = [
+    Severity::Trace,
+    Severity::Trace2,
+    Severity::Trace3,
+    Severity::Trace4,
+    Severity::Debug,
+    Severity::Debug2,
+    Severity::Debug3,
+    Severity::Debug4,
+    Severity::Info,
+    Severity::Info2,
+    Severity::Info3,
+    Severity::Info4,
+    Severity::Warn,
+    Severity::Warn2,
+    Severity::Warn3,
+    Severity::Warn4,
+    Severity::Error,
+    Severity::Error2,
+    Severity::Error3,
+    Severity::Error4,
+    Severity::Fatal,
+    Severity::Fatal2,
+    Severity::Fatal3,
+    Severity::Fatal4,
+];
+
+/// Converts log records, whose severity texts are `&'static str` in the SDK.
+///
+/// Texts are leaked once per distinct value, which is bounded by the few
+/// severities logging libraries use.
+#[derive(Debug, Default)]
+pub(crate) struct LogConverter {
+    severity_texts: HashSet<&'static str>,
+}
+
+impl LogConverter {
+    pub(crate) fn log_record(&mut self, record: ProtoLogRecord) -> LogRecord {
+        let severity_number = usize::try_from(record.severity_number)
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .and_then(|i| SEVERITIES.get(i).copied());
+
+        let mut log_record = LogRecord::default();
+        log_record.timestamp = Some(record.time_unix_nano)
+            .filter(|nanos| *nanos != 0)
+            .map(time);
+        log_record.observed_timestamp = Some(record.observed_time_unix_nano)
+            .filter(|nanos| *nanos != 0)
+            .map(time);
+        log_record.severity_number = severity_number;
+        log_record.severity_text = Some(record.severity_text)
+            .filter(|text| !text.is_empty())
+            .map(|text| self.intern(text, severity_number));
+        log_record.body = record.body.and_then(|body| body.value).map(log_value);
+        if record.trace_id.len() == 16 && record.span_id.len() == 8 {
+            log_record.trace_context = Some(TraceContext::from(&span_context(
+                &record.trace_id,
+                &record.span_id,
+                record.flags,
+                "",
+            )));
+        }
+        log_record.add_attributes(
+            record
+                .attributes
+                .into_iter()
+                .filter_map(|kv| Some((Key::from(kv.key), log_value(kv.value?.value?)))),
+        );
+        log_record
+    }
+
+    fn intern(&mut self, text: String, severity: Option<Severity>) -> &'static str {
+        if let Some(name) = severity.map(|s| s.name()).filter(|name| *name == text) {
+            return name;
+        }
+        match self.severity_texts.get(text.as_str()) {
+            Some(text) => text,
+            None => {
+                let text: &'static str = Box::leak(text.into_boxed_str());
+                self.severity_texts.insert(text);
+                text
+            }
+        }
+    }
+}
+
+pub(crate) fn resource_metrics(resource_metrics: ProtoResourceMetrics) -> ResourceMetrics {
+    ResourceMetrics {
+        resource: resource(resource_metrics.resource, resource_metrics.schema_url),
+        scope_metrics: resource_metrics
+            .scope_metrics
+            .into_iter()
+            .map(|scope_metrics| ScopeMetrics {
+                scope: scope(scope_metrics.scope, scope_metrics.schema_url),
+                metrics: scope_metrics
+                    .metrics
+                    .into_iter()
+                    .filter_map(|metric| {
+                        Some(Metric {
+                            name: metric.name.into(),
+                            description: metric.description.into(),
+                            unit: metric.unit.into(),
+                            data: aggregation(metric.data?),
+                        })
+                    })
+                    .collect(),
+            })
+            .collect(),
+    }
+}
+
+fn aggregation(data: Data) -> Box<dyn Aggregation> {
+    match data {
+        Data::Gauge(gauge) => gauge_aggregation(gauge),
+        Data::Sum(sum) => sum_aggregation(sum),
+        Data::Histogram(histogram) => Box::new(histogram_aggregation(histogram)),
+        Data::ExponentialHistogram(histogram) => {
+            Box::new(exponential_histogram_aggregation(histogram))
+        }
+        Data::Summary(summary) => Box::new(summary_aggregation(summary)),
+    }
+}
+
+fn temporality(temporality: i32) -> Temporality {
+    // AGGREGATION_TEMPORALITY_DELTA
+    if temporality == 1 {
+        Temporality::Delta
+    } else {
+        Temporality::Cumulative
+    }
+}
+
+/// Whether number data points are read as integers, which is only the case if
+/// none of them holds a double.
+fn is_int(data_points: &[NumberDataPoint]) -> bool {
+    !data_points
+        .iter()
+        .any(|dp| matches!(dp.value, Some(number_data_point::Value::AsDouble(_))))
+}
+
+fn gauge_aggregation(gauge: ProtoGauge) -> Box<dyn Aggregation> {
+    if is_int(&gauge.data_points) {
+        Box::new(Gauge {
+            data_points: data_points(gauge.data_points, as_i64),
+        })
+    } else {
+        Box::new(Gauge {
+            data_points: data_points(gauge.data_points, as_f64),
+        })
+    }
+}
+
+fn sum_aggregation(sum: ProtoSum) -> Box<dyn Aggregation> {
+    let temporality = temporality(sum.aggregation_temporality);
+    if is_int(&sum.data_points) {
+        Box::new(Sum {
+            data_points: data_points(sum.data_points, as_i64),
+            temporality,
+            is_monotonic: sum.is_monotonic,
+        })
+    } else {
+        Box::new(Sum {
+            data_points: data_points(sum.data_points, as_f64),
+            temporality,
+            is_monotonic: sum.is_monotonic,
+        })
+    }
+}
+
+fn as_i64(value: Option<number_data_point::Value>) -> i64 {
+    match value {
+        Some(number_data_point::Value::AsInt(i)) => i,
+        Some(number_data_point::Value::AsDouble(d)) => d as i64,
+        None => 0,
+    }
+}
+
+fn as_f64(value: Option<number_data_point::Value>) -> f64 {
+    match value {
+        Some(number_data_point::Value::AsInt(i)) => i as f64,
+        Some(number_data_point::Value::AsDouble(d)) => d,
+        None => 0.0,
+    }
+}
+
+fn data_points<T: Copy>(
+    data_points: Vec<NumberDataPoint>,
+    convert: fn(Option<number_data_point::Value>) -> T,
+) -> Vec<DataPoint<T>> {
+    data_points
+        .into_iter()
+        .map(|dp| DataPoint {
+            attributes: key_values(dp.attributes),
+            start_time: Some(dp.start_time_unix_nano)
+                .filter(|nanos| *nanos != 0)
+                .map(time),
+            time: Some(time(dp.time_unix_nano)),
+            value: convert(dp.value),
+            exemplars: exemplars(dp.exemplars, |value| {
+                convert(value.map(|value| match value {
+                    exemplar::Value::AsInt(i) => number_data_point::Value::AsInt(i),
+                    exemplar::Value::AsDouble(d) => number_data_point::Value::AsDouble(d),
+                }))
+            }),
+        })
+        .collect()
+}
+
+fn exemplars<T>(
+    exemplars: Vec<ProtoExemplar>,
+    convert: impl Fn(Option<exemplar::Value>) -> T,
+) -> Vec<Exemplar<T>> {
+    exemplars
+        .into_iter()
+        .map(|exemplar| Exemplar {
+            filtered_attributes: key_values(exemplar.filtered_attributes),
+            time: time(exemplar.time_unix_nano),
+            value: convert(exemplar.value),
+            span_id: exemplar.span_id.try_into().unwrap_or_default(),
+            trace_id: exemplar.trace_id.try_into().unwrap_or_default(),
+        })
+        .collect()
+}
+
+fn exemplar_f64(value: Option<exemplar::Value>) -> f64 {
+    match value {
+        Some(exemplar::Value::AsInt(i)) => i as f64,
+        Some(exemplar::Value::AsDouble(d)) => d,
+        None => 0.0,
+    }
+}
+
+fn histogram_aggregation(histogram: ProtoHistogram) -> Histogram<f64> {
+    Histogram {
+        data_points: histogram
+            .data_points
+            .into_iter()
+            .map(|dp| HistogramDataPoint {
+                attributes: key_values(dp.attributes),
+                start_time: time(dp.start_time_unix_nano),
+                time: time(dp.time_unix_nano),
+                count: dp.count,
+                bounds: dp.explicit_bounds,
+                bucket_counts: dp.bucket_counts,
+                min: dp.min,
+                max: dp.max,
+                sum: dp.sum.unwrap_or_default(),
+                exemplars: exemplars(dp.exemplars, exemplar_f64),
+            })
+            .collect(),
+        temporality: temporality(histogram.aggregation_temporality),
+    }
+}
+
+fn exponential_histogram_aggregation(
+    histogram: ProtoExponentialHistogram,
+) -> ExponentialHistogram<f64> {
+    ExponentialHistogram {
+        data_points: histogram
+            .data_points
+            .into_iter()
+            .map(|dp| {
+                let bucket = |buckets: Option<_>| {
+                    let buckets: opentelemetry_proto::tonic::metrics::v1::exponential_histogram_data_point::Buckets =
+                        buckets.unwrap_or_default();
+                    ExponentialBucket {
+                        offset: buckets.offset,
+                        counts: buckets.bucket_counts,
+                    }
+                };
+                ExponentialHistogramDataPoint {
+                    attributes: key_values(dp.attributes),
+                    start_time: time(dp.start_time_unix_nano),
+                    time: time(dp.time_unix_nano),
+                    count: dp.count as usize,
+                    min: dp.min,
+                    max: dp.max,
+                    sum: dp.sum.unwrap_or_default(),
+                    scale: dp.scale.clamp(i8::MIN.into(), i8::MAX.into()) as i8,
+                    zero_count: dp.zero_count,
+                    positive_bucket: bucket(dp.positive),
+                    negative_bucket: bucket(dp.negative),
+                    zero_threshold: dp.zero_threshold,
+                    exemplars: exemplars(dp.exemplars, exemplar_f64),
+                }
+            })
+            .collect(),
+        temporality: temporality(histogram.aggregation_temporality),
+    }
+}
+
+fn summary_aggregation(summary: ProtoSummary) -> Summary<f64> {
+    Summary {
+        data_points: summary
+            .data_points
+            .into_iter()
+            .map(|dp| SummaryDataPoint {
+                attributes: key_values(dp.attributes),
+                start_time: time(dp.start_time_unix_nano),
+                time: time(dp.time_unix_nano),
+                count: dp.count,
+                min: None,
+                max: None,
+                sum: dp.sum,
+                quantile_values: dp
+                    .quantile_values
+                    .into_iter()
+                    .map(|qv| QuantileValue {
+                        quantile: qv.quantile,
+                        value: qv.value,
+                    })
+                    .collect(),
+            })
+            .collect(),
+        temporality: Temporality::Cumulative,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry_proto::tonic::collector::metrics::v1::ExportMetricsServiceRequest;
+    use opentelemetry_proto::tonic::logs::v1::LogRecord as ProtoLogRecord;
+    use opentelemetry_proto::tonic::trace::v1::Span as ProtoSpan;
+    use opentelemetry_sdk::metrics::data::Gauge;
+
+    fn string(value: &str) -> AnyValue {
+        AnyValue {
+            value: Some(any_value::Value::StringValue(value.into())),
+        }
+    }
+
+    #[test]
+    fn span_round_trip() {
+        let span = SpanData {
+            span_context: SpanContext::new(
+                TraceId::from_bytes([1; 16]),
+                SpanId::from_bytes([2; 8]),
+                TraceFlags::SAMPLED,
+                false,
+                "vendor=value".parse().unwrap(),
+            ),
+            parent_span_id: SpanId::from_bytes([3; 8]),
+            span_kind: SpanKind::Client,
+            name: "GET /cart".into(),
+            start_time: time(1_000),
+            end_time: time(2_000),
+            attributes: vec![
+                KeyValue::new("http.request.method", "GET"),
+                KeyValue::new("http.response.status_code", 200),
+                KeyValue::new("tags", Value::Array(Array::String(vec!["a".into()]))),
+            ],
+            dropped_attributes_count: 1,
+            events: SpanEvents::default(),
+            links: SpanLinks::default(),
+            status: Status::error("unavailable"),
+            instrumentation_lib: InstrumentationLibrary::builder("replay")
+                .with_version("1.0")
+                .build(),
+        };
+
+        let converted = span_data(ProtoSpan::from(span.clone()), &span.instrumentation_lib);
+
+        assert_eq!(converted, span);
+    }
+
+    #[test]
+    fn mixed_arrays_become_strings() {
+        let array = any_value::Value::ArrayValue(ArrayValue {
+            values: vec![
+                string("a"),
+                AnyValue {
+                    value: Some(any_value::Value::IntValue(1)),
+                },
+            ],
+        });
+
+        assert_eq!(value(array), Value::String("[\"a\", 1]".into()));
+    }
+
+    #[test]
+    fn log_record() {
+        let mut converter = LogConverter::default();
+        let record = ProtoLogRecord {
+            time_unix_nano: 1_000,
+            severity_number: 17,
+            severity_text: "ERROR".into(),
+            body: Some(string("failed")),
+            trace_id: vec![1; 16],
+            span_id: vec![2; 8],
+            flags: 1,
+            ..Default::default()
+        };
+
+        let converted = converter.log_record(record.clone());
+        assert_eq!(converted.timestamp, Some(time(1_000)));
+        assert_eq!(converted.observed_timestamp, None);
+        assert_eq!(converted.severity_number, Some(Severity::Error));
+        assert_eq!(converted.severity_text, Some("ERROR"));
+        assert_eq!(converted.body, Some(LogsAnyValue::String("failed".into())));
+        let trace_context = converted.trace_context.unwrap();
+        assert_eq!(trace_context.trace_id, TraceId::from_bytes([1; 16]));
+        assert_eq!(trace_context.span_id, SpanId::from_bytes([2; 8]));
+
+        let custom = converter.log_record(ProtoLogRecord {
+            severity_text: "oops".into(),
+            ..record.clone()
+        });
+        let again = converter.log_record(ProtoLogRecord {
+            severity_text: "oops".into(),
+            ..record
+        });
+        assert!(std::ptr::eq(
+            custom.severity_text.unwrap(),
+            again.severity_text.unwrap()
+        ));
+    }
+
+    #[test]
+    fn metrics_round_trip() {
+        let metrics = ResourceMetrics {
+            resource: Resource::new([KeyValue::new("service.name", "checkout")]),
+            scope_metrics: vec![ScopeMetrics {
+                scope: InstrumentationLibrary::builder("replay").build(),
+                metrics: vec![
+                    Metric {
+                        name: "queue.size".into(),
+                        description: "".into(),
+                        unit: "{item}".into(),
+                        data: Box::new(Gauge {
+                            data_points: vec![DataPoint {
+                                attributes: vec![KeyValue::new("queue", "orders")],
+                                start_time: None,
+                                time: Some(time(1_000)),
+                                value: 3i64,
+                                exemplars: vec![],
+                            }],
+                        }),
+                    },
+                    Metric {
+                        name: "request.duration".into(),
+                        description: "".into(),
+                        unit: "s".into(),
+                        data: Box::new(Histogram {
+                            data_points: vec![HistogramDataPoint {
+                                attributes: vec![],
+                                start_time: time(500),
+                                time: time(1_000),
+                                count: 2,
+                                bounds: vec![0.5],
+                                bucket_counts: vec![1, 1],
+                                min: Some(0.25),
+                                max: Some(0.75),
+                                sum: 1.0,
+                                exemplars: vec![],
+                            }],
+                            temporality: Temporality::Delta,
+                        }),
+                    },
+                ],
+            }],
+        };
+        let request = ExportMetricsServiceRequest::from(&metrics);
+
+        let converted = resource_metrics(request.resource_metrics[0].clone());
+
+        assert_eq!(ExportMetricsServiceRequest::from(&converted), request);
+        let gauge = converted.scope_metrics[0].metrics[0]
+            .data
+            .as_any()
+            .downcast_ref::<Gauge<i64>>()
+            .unwrap();
+        assert_eq!(gauge.data_points[0].value, 3);
+    }
+}
@@ -0,0 +1,262 @@
+//! Filtering recorded export requests.
+use crate::reader::Request;
+use opentelemetry_proto::tonic::{
+    common::v1::{any_value, KeyValue},
+    metrics::v1::metric::Data,
+    resource::v1::Resource,
+};
+
+/// Which resources and items of export requests to keep, all of them by default.
+#[derive(Debug, Default)]
+pub(crate) struct Filter {
+    /// The `service.name` resources must have.
+    pub(crate) service: Option<String>,
+    /// The trace spans and logs must belong to. Metrics don't belong to traces
+    /// and are dropped when set.
+    pub(crate) trace_id: Option<[u8; 16]>,
+    /// The inclusive start of the time range items must be in, as nanoseconds
+    /// since the unix epoch.
+    pub(crate) since: Option<u64>,
+    /// The exclusive end of the time range items must be in.
+    pub(crate) until: Option<u64>,
+}
+
+impl Filter {
+    /// Removes what doesn't match this filter from `request`, along with
+    /// resources and scopes left empty.
+    pub(crate) fn apply(&self, request: &mut Request) {
+        match request {
+            Request::Traces(request) => {
+                request.resource_spans.retain_mut(|resource_spans| {
+                    if !self.matches_resource(resource_spans.resource.as_ref()) {
+                        return false;
+                    }
+                    resource_spans.scope_spans.retain_mut(|scope_spans| {
+                        scope_spans.spans.retain(|span| {
+                            self.matches_trace(&span.trace_id)
+                                && self.in_range(span.start_time_unix_nano)
+                        });
+                        !scope_spans.spans.is_empty()
+                    });
+                    !resource_spans.scope_spans.is_empty()
+                });
+            }
+            Request::Logs(request) => {
+                request.resource_logs.retain_mut(|resource_logs| {
+                    if !self.matches_resource(resource_logs.resource.as_ref()) {
+                        return false;
+                    }
+                    resource_logs.scope_logs.retain_mut(|scope_logs| {
+                        scope_logs.log_records.retain(|log_record| {
+                            let time = match log_record.time_unix_nano {
+                                0 => log_record.observed_time_unix_nano,
+                                time => time,
+                            };
+                            self.matches_trace(&log_record.trace_id) && self.in_range(time)
+                        });
+                        !scope_logs.log_records.is_empty()
+                    });
+                    !resource_logs.scope_logs.is_empty()
+                });
+            }
+            Request::Metrics(request) => {
+                if self.trace_id.is_some() {
+                    request.resource_metrics.clear();
+                }
+                request.resource_metrics.retain_mut(|resource_metrics| {
+                    if !self.matches_resource(resource_metrics.resource.as_ref()) {
+                        return false;
+                    }
+                    resource_metrics.scope_metrics.retain_mut(|scope_metrics| {
+                        scope_metrics
+                            .metrics
+                            .retain_mut(|metric| match &mut metric.data {
+                                Some(Data::Gauge(gauge)) => {
+                                    gauge
+                                        .data_points
+                                        .retain(|dp| self.in_range(dp.time_unix_nano));
+                                    !gauge.data_points.is_empty()
+                                }
+                                Some(Data::Sum(sum)) => {
+                                    sum.data_points
+                                        .retain(|dp| self.in_range(dp.time_unix_nano));
+                                    !sum.data_points.is_empty()
+                                }
+                                Some(Data::Histogram(histogram)) => {
+                                    histogram
+                                        .data_points
+                                        .retain(|dp| self.in_range(dp.time_unix_nano));
+                                    !histogram.data_points.is_empty()
+                                }
+                                Some(Data::ExponentialHistogram(histogram)) => {
+                                    histogram
+                                        .data_points
+                                        .retain(|dp| self.in_range(dp.time_unix_nano));
+                                    !histogram.data_points.is_empty()
+                                }
+                                Some(Data::Summary(summary)) => {
+                                    summary
+                                        .data_points
+                                        .retain(|dp| self.in_range(dp.time_unix_nano));
+                                    !summary.data_points.is_empty()
+                                }
+                                None => false,
+                            });
+                        !scope_metrics.metrics.is_empty()
+                    });
+                    !resource_metrics.scope_metrics.is_empty()
+                });
+            }
+        }
+    }
+
+    fn matches_resource(&self, resource: Option<&Resource>) -> bool {
+        match &self.service {
+            Some(service) => resource
+                .and_then(|resource| service_name(&resource.attributes))
+                .is_some_and(|name| name == service),
+            None => true,
+        }
+    }
+
+    fn matches_trace(&self, trace_id: &[u8]) -> bool {
+        self.trace_id
+            .map_or(true, |expected| trace_id == expected.as_slice())
+    }
+
+    fn in_range(&self, time: u64) -> bool {
+        self.since.map_or(true, |since| time >= since)
+            && self.until.map_or(true, |until| time < until)
+    }
+}
+
+/// The `service.name` among resource attributes.
+pub(crate) fn service_name(attributes: &[KeyValue]) -> Option<&str> {
+    attributes
+        .iter()
+        .find(|kv| kv.key == "service.name")
+        .and_then(|kv| match kv.value.as_ref()?.value.as_ref()? {
+            any_value::Value::StringValue(name) => Some(name.as_str()),
+            _ => None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::tests::{logs, span, traces};
+    use opentelemetry_proto::tonic::{
+        collector::metrics::v1::ExportMetricsServiceRequest,
+        logs::v1::LogRecord,
+        metrics::v1::{Gauge, Metric, NumberDataPoint, ResourceMetrics, ScopeMetrics},
+    };
+
+    fn filtered(filter: &Filter, mut request: Request) -> Request {
+        filter.apply(&mut request);
+        request
+    }
+
+    #[test]
+    fn filter_by_service() {
+        let filter = Filter {
+            service: Some("checkout".into()),
+            ..Default::default()
+        };
+        let checkout = traces("checkout", vec![span("GET /cart", 1, 10)]);
+
+        assert_eq!(filtered(&filter, checkout.clone()), checkout);
+        assert!(filtered(&filter, traces("payment", vec![span("charge", 1, 10)])).is_empty());
+    }
+
+    #[test]
+    fn filter_by_trace_id_and_time() {
+        let filter = Filter {
+            trace_id: Some([1; 16]),
+            since: Some(10),
+            until: Some(20),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            filtered(
+                &filter,
+                traces(
+                    "checkout",
+                    vec![
+                        span("before", 1, 9),
+                        span("first", 1, 10),
+                        span("other trace", 2, 15),
+                        span("last", 1, 19),
+                        span("after", 1, 20),
+                    ]
+                )
+            ),
+            traces("checkout", vec![span("first", 1, 10), span("last", 1, 19)])
+        );
+
+        let log = |trace_id: u8, time: u64, observed: u64| LogRecord {
+            trace_id: vec![trace_id; 16],
+            time_unix_nano: time,
+            observed_time_unix_nano: observed,
+            ..Default::default()
+        };
+        assert_eq!(
+            filtered(
+                &filter,
+                logs(
+                    "checkout",
+                    vec![
+                        log(1, 15, 30),
+                        log(1, 0, 15),
+                        log(1, 30, 15),
+                        log(2, 15, 15)
+                    ]
+                )
+            ),
+            logs("checkout", vec![log(1, 15, 30), log(1, 0, 15)])
+        );
+    }
+
+    #[test]
+    fn filter_metrics() {
+        let request = |times: &[u64]| {
+            Request::Metrics(ExportMetricsServiceRequest {
+                resource_metrics: vec![ResourceMetrics {
+                    resource: crate::reader::tests::resource("checkout"),
+                    scope_metrics: vec![ScopeMetrics {
+                        metrics: vec![Metric {
+                            name: "queue.size".into(),
+                            data: Some(Data::Gauge(Gauge {
+                                data_points: times
+                                    .iter()
+                                    .map(|time| NumberDataPoint {
+                                        time_unix_nano: *time,
+                                        ..Default::default()
+                                    })
+                                    .collect(),
+                            })),
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    }],
+                    schema_url: String::new(),
+                }],
+            })
+        };
+        let by_time = Filter {
+            since: Some(10),
+            ..Default::default()
+        };
+        let by_trace = Filter {
+            trace_id: Some([1; 16]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            filtered(&by_time, request(&[5, 10, 15])),
+            request(&[10, 15])
+        );
+        assert!(filtered(&by_time, request(&[5])).is_empty());
+        assert!(filtered(&by_trace, request(&[10])).is_empty());
+    }
+}
@@ -0,0 +1,88 @@
+//! Prints, filters, summarizes and resends telemetry recorded as OTLP files,
+//! such as those written by the `opentelemetry-otlp` file exporter.
+//!
+//! Run `otlp-replay --help` for usage.
+use args::{Args, Command, USAGE};
+use send::Sender;
+use stats::Stats;
+use std::fs::File;
+use std::io::{self, BufReader, Write};
+use std::process::ExitCode;
+
+mod args;
+mod convert;
+mod filter;
+mod print;
+mod reader;
+mod send;
+mod stats;
+
+pub(crate) type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("otlp-replay: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<()> {
+    let args = Args::parse(std::env::args().skip(1))?;
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+
+    match args.command {
+        Command::Help => out.write_all(USAGE.as_bytes())?,
+        Command::Print { json } => {
+            for_each_request(&args, |request| {
+                if json {
+                    writeln!(out, "{}", request.to_json()?)?;
+                } else {
+                    print::print(&mut out, &request)?;
+                }
+                Ok(())
+            })?;
+        }
+        Command::Stats => {
+            let mut stats = Stats::default();
+            for_each_request(&args, |request| {
+                stats.add(&request);
+                Ok(())
+            })?;
+            stats.print(&mut out)?;
+        }
+        Command::Send(ref config) => {
+            let runtime = tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()?;
+            // Exporters connect in the background of the runtime they are
+            // built in.
+            let _guard = runtime.enter();
+            let mut sender = Sender::new(config.clone());
+            let result = for_each_request(&args, |request| runtime.block_on(sender.send(request)));
+            let shutdown = sender.shutdown();
+            result?;
+            shutdown?;
+            writeln!(out, "sent {} export requests", sender.sent)?;
+        }
+    }
+    Ok(())
+}
+
+/// Calls `f` with the filtered, non-empty requests of all files in order.
+fn for_each_request(args: &Args, mut f: impl FnMut(reader::Request) -> Result<()>) -> Result<()> {
+    for path in &args.files {
+        let file = File::open(path).map_err(|err| format!("{}: {err}", path.display()))?;
+        for request in reader::read(BufReader::new(file), args.signal) {
+            let mut request = request.map_err(|err| format!("{}: {err}", path.display()))?;
+            args.filter.apply(&mut request);
+            if !request.is_empty() {
+                f(request)?;
+            }
+        }
+    }
+    Ok(())
+}
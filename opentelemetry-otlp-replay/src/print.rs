@@ -0,0 +1,246 @@
+//! Printing recorded export requests in a readable layout.
+use crate::convert::DisplayValue;
+use crate::reader::Request;
+use opentelemetry_proto::tonic::{
+    common::v1::{InstrumentationScope, KeyValue},
+    metrics::v1::metric::Data,
+    resource::v1::Resource,
+};
+use std::io::{self, Write};
+
+/// Prints `request` as resources, followed by their scopes and items, one
+/// per line and indented by nesting.
+pub(crate) fn print(out: &mut impl Write, request: &Request) -> io::Result<()> {
+    match request {
+        Request::Traces(request) => {
+            for resource_spans in &request.resource_spans {
+                print_resource(out, resource_spans.resource.as_ref())?;
+                for scope_spans in &resource_spans.scope_spans {
+                    print_scope(out, scope_spans.scope.as_ref())?;
+                    for span in &scope_spans.spans {
+                        writeln!(
+                            out,
+                            "    span {} trace={} span={} parent={} start={} duration={}",
+                            span.name,
+                            hex(&span.trace_id),
+                            hex(&span.span_id),
+                            hex(&span.parent_span_id),
+                            timestamp(span.start_time_unix_nano),
+                            duration(span.start_time_unix_nano, span.end_time_unix_nano),
+                        )?;
+                        if let Some(status) = span.status.as_ref().filter(|s| s.code == 2) {
+                            writeln!(out, "      error: {}", status.message)?;
+                        }
+                        print_attributes(out, "      ", &span.attributes)?;
+                        for event in &span.events {
+                            writeln!(
+                                out,
+                                "      event {} at {}",
+                                event.name,
+                                timestamp(event.time_unix_nano)
+                            )?;
+                            print_attributes(out, "        ", &event.attributes)?;
+                        }
+                    }
+                }
+            }
+        }
+        Request::Logs(request) => {
+            for resource_logs in &request.resource_logs {
+                print_resource(out, resource_logs.resource.as_ref())?;
+                for scope_logs in &resource_logs.scope_logs {
+                    print_scope(out, scope_logs.scope.as_ref())?;
+                    for log_record in &scope_logs.log_records {
+                        let time = match log_record.time_unix_nano {
+                            0 => log_record.observed_time_unix_nano,
+                            time => time,
+                        };
+                        write!(out, "    log {}", timestamp(time))?;
+                        if !log_record.severity_text.is_empty() {
+                            write!(out, " {}", log_record.severity_text)?;
+                        } else if log_record.severity_number != 0 {
+                            write!(out, " severity={}", log_record.severity_number)?;
+                        }
+                        if !log_record.trace_id.is_empty() {
+                            write!(
+                                out,
+                                " trace={} span={}",
+                                hex(&log_record.trace_id),
+                                hex(&log_record.span_id)
+                            )?;
+                        }
+                        if let Some(body) = log_record.body.as_ref().and_then(|b| b.value.as_ref())
+                        {
+                            write!(out, " {}", DisplayValue(body))?;
+                        }
+                        writeln!(out)?;
+                        print_attributes(out, "      ", &log_record.attributes)?;
+                    }
+                }
+            }
+        }
+        Request::Metrics(request) => {
+            for resource_metrics in &request.resource_metrics {
+                print_resource(out, resource_metrics.resource.as_ref())?;
+                for scope_metrics in &resource_metrics.scope_metrics {
+                    print_scope(out, scope_metrics.scope.as_ref())?;
+                    for metric in &scope_metrics.metrics {
+                        let (kind, data_points) = match &metric.data {
+                            Some(Data::Gauge(gauge)) => ("gauge", gauge.data_points.len()),
+                            Some(Data::Sum(sum)) => ("sum", sum.data_points.len()),
+                            Some(Data::Histogram(histogram)) => {
+                                ("histogram", histogram.data_points.len())
+                            }
+                            Some(Data::ExponentialHistogram(histogram)) => {
+                                ("exponential histogram", histogram.data_points.len())
+                            }
+                            Some(Data::Summary(summary)) => ("summary", summary.data_points.len()),
+                            None => ("empty", 0),
+                        };
+                        write!(out, "    {kind} {}", metric.name)?;
+                        if !metric.unit.is_empty() {
+                            write!(out, " [{}]", metric.unit)?;
+                        }
+                        writeln!(out, " {data_points} data points")?;
+                        print_data_points(out, metric.data.as_ref())?;
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_data_points(out: &mut impl Write, data: Option<&Data>) -> io::Result<()> {
+    use opentelemetry_proto::tonic::metrics::v1::number_data_point::Value;
+    let number = |value: Option<&Value>| match value {
+        Some(Value::AsInt(i)) => i.to_string(),
+        Some(Value::AsDouble(d)) => d.to_string(),
+        None => "-".to_string(),
+    };
+    match data {
+        Some(Data::Gauge(gauge)) => gauge.data_points.iter().try_for_each(|dp| {
+            writeln!(
+                out,
+                "      {} = {}",
+                timestamp(dp.time_unix_nano),
+                number(dp.value.as_ref())
+            )?;
+            print_attributes(out, "        ", &dp.attributes)
+        }),
+        Some(Data::Sum(sum)) => sum.data_points.iter().try_for_each(|dp| {
+            writeln!(
+                out,
+                "      {} = {}",
+                timestamp(dp.time_unix_nano),
+                number(dp.value.as_ref())
+            )?;
+            print_attributes(out, "        ", &dp.attributes)
+        }),
+        Some(Data::Histogram(histogram)) => histogram.data_points.iter().try_for_each(|dp| {
+            writeln!(
+                out,
+                "      {} count={} sum={}",
+                timestamp(dp.time_unix_nano),
+                dp.count,
+                dp.sum.unwrap_or_default()
+            )?;
+            print_attributes(out, "        ", &dp.attributes)
+        }),
+        Some(Data::ExponentialHistogram(histogram)) => {
+            histogram.data_points.iter().try_for_each(|dp| {
+                writeln!(
+                    out,
+                    "      {} count={} sum={} scale={}",
+                    timestamp(dp.time_unix_nano),
+                    dp.count,
+                    dp.sum.unwrap_or_default(),
+                    dp.scale
+                )?;
+                print_attributes(out, "        ", &dp.attributes)
+            })
+        }
+        Some(Data::Summary(summary)) => summary.data_points.iter().try_for_each(|dp| {
+            writeln!(
+                out,
+                "      {} count={} sum={}",
+                timestamp(dp.time_unix_nano),
+                dp.count,
+                dp.sum
+            )?;
+            print_attributes(out, "        ", &dp.attributes)
+        }),
+        None => Ok(()),
+    }
+}
+
+fn print_resource(out: &mut impl Write, resource: Option<&Resource>) -> io::Result<()> {
+    writeln!(out, "resource")?;
+    print_attributes(out, "  ", resource.map_or(&[], |r| &r.attributes))
+}
+
+fn print_scope(out: &mut impl Write, scope: Option<&InstrumentationScope>) -> io::Result<()> {
+    match scope {
+        Some(scope) if !scope.version.is_empty() => {
+            writeln!(out, "  scope {} {}", scope.name, scope.version)
+        }
+        Some(scope) => writeln!(out, "  scope {}", scope.name),
+        None => writeln!(out, "  scope"),
+    }
+}
+
+fn print_attributes(out: &mut impl Write, indent: &str, attributes: &[KeyValue]) -> io::Result<()> {
+    for kv in attributes {
+        match kv.value.as_ref().and_then(|v| v.value.as_ref()) {
+            Some(value) => writeln!(out, "{indent}{}: {}", kv.key, DisplayValue(value))?,
+            None => writeln!(out, "{indent}{}: null", kv.key)?,
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Formats nanoseconds since the unix epoch as an RFC 3339 UTC time.
+pub(crate) fn timestamp(nanos: u64) -> String {
+    let secs = (nanos / 1_000_000_000) as i64;
+    let nsecs = (nanos % 1_000_000_000) as u32;
+    chrono::DateTime::from_timestamp(secs, nsecs)
+        .map(|time| time.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true))
+        .unwrap_or_else(|| nanos.to_string())
+}
+
+fn duration(start: u64, end: u64) -> String {
+    format!(
+        "{:?}",
+        std::time::Duration::from_nanos(end.saturating_sub(start))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::tests::{span, traces};
+
+    #[test]
+    fn print_spans() {
+        let mut out = Vec::new();
+        print(
+            &mut out,
+            &traces(
+                "checkout",
+                vec![span("GET /cart", 1, 1_722_513_600_000_000_000)],
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "resource\n  service.name: \"checkout\"\n  scope\n    span GET /cart \
+             trace=01010101010101010101010101010101 span=0101010101010101 parent= \
+             start=2024-08-01T12:00:00Z duration=1µs\n"
+        );
+    }
+}
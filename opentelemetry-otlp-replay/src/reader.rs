@@ -0,0 +1,296 @@
+//! Reading recorded export requests.
+use crate::Result;
+use opentelemetry_proto::tonic::collector::{
+    logs::v1::ExportLogsServiceRequest, metrics::v1::ExportMetricsServiceRequest,
+    trace::v1::ExportTraceServiceRequest,
+};
+use prost::Message;
+use std::fmt;
+use std::io::{BufRead, ErrorKind, Read};
+use std::str::FromStr;
+
+/// A telemetry signal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Signal {
+    Traces,
+    Logs,
+    Metrics,
+}
+
+impl FromStr for Signal {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "traces" => Ok(Signal::Traces),
+            "logs" => Ok(Signal::Logs),
+            "metrics" => Ok(Signal::Metrics),
+            _ => Err(format!(
+                "unknown signal `{s}`, expected `traces`, `logs` or `metrics`"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Signal::Traces => "traces",
+            Signal::Logs => "logs",
+            Signal::Metrics => "metrics",
+        })
+    }
+}
+
+/// A recorded export request of any signal.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Request {
+    Traces(ExportTraceServiceRequest),
+    Logs(ExportLogsServiceRequest),
+    Metrics(ExportMetricsServiceRequest),
+}
+
+impl Request {
+    pub(crate) fn signal(&self) -> Signal {
+        match self {
+            Request::Traces(_) => Signal::Traces,
+            Request::Logs(_) => Signal::Logs,
+            Request::Metrics(_) => Signal::Metrics,
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        match self {
+            Request::Traces(request) => request.resource_spans.is_empty(),
+            Request::Logs(request) => request.resource_logs.is_empty(),
+            Request::Metrics(request) => request.resource_metrics.is_empty(),
+        }
+    }
+
+    /// Encodes the request as a single line of OTLP JSON.
+    pub(crate) fn to_json(&self) -> serde_json::Result<String> {
+        match self {
+            Request::Traces(request) => serde_json::to_string(request),
+            Request::Logs(request) => serde_json::to_string(request),
+            Request::Metrics(request) => serde_json::to_string(request),
+        }
+    }
+}
+
+/// Reads the export requests of a file, written as OTLP JSON lines or as
+/// protobuf messages each prefixed by its length as a 4 byte big-endian
+/// integer.
+///
+/// JSON lines are read as the signal they hold, skipping those of other
+/// signals than `signal`. Protobuf messages don't name their signal, so
+/// `signal` is required to read them.
+pub(crate) fn read<'a>(
+    mut input: impl BufRead + 'a,
+    signal: Option<Signal>,
+) -> Box<dyn Iterator<Item = Result<Request>> + 'a> {
+    let is_json = match input.fill_buf() {
+        Ok(buf) => buf.first().map_or(true, |b| *b == b'{'),
+        Err(err) => return Box::new(std::iter::once(Err(err.into()))),
+    };
+    if is_json {
+        return Box::new(JsonLines {
+            input,
+            signal,
+            line: 0,
+        });
+    }
+    match signal {
+        Some(signal) => Box::new(Protobuf { input, signal }),
+        None => Box::new(std::iter::once(Err(
+            "reading protobuf requires `--signal`".into()
+        ))),
+    }
+}
+
+struct JsonLines<R> {
+    input: R,
+    signal: Option<Signal>,
+    line: usize,
+}
+
+impl<R: BufRead> Iterator for JsonLines<R> {
+    type Item = Result<Request>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            self.line += 1;
+            match self.input.read_line(&mut line) {
+                Ok(0) => return None,
+                Ok(_) if line.trim().is_empty() => continue,
+                Ok(_) => {}
+                Err(err) => return Some(Err(err.into())),
+            }
+            match decode_json(&line, self.signal) {
+                Ok(Some(request)) => return Some(Ok(request)),
+                Ok(None) => continue,
+                Err(err) => return Some(Err(format!("line {}: {err}", self.line).into())),
+            }
+        }
+    }
+}
+
+fn decode_json(line: &str, signal: Option<Signal>) -> Result<Option<Request>> {
+    let value: serde_json::Value = serde_json::from_str(line)?;
+    let line_signal = if value.get("resourceSpans").is_some() {
+        Signal::Traces
+    } else if value.get("resourceLogs").is_some() {
+        Signal::Logs
+    } else if value.get("resourceMetrics").is_some() {
+        Signal::Metrics
+    } else {
+        return Err("not an OTLP export request".into());
+    };
+    if signal.is_some_and(|signal| signal != line_signal) {
+        return Ok(None);
+    }
+    Ok(Some(match line_signal {
+        Signal::Traces => Request::Traces(serde_json::from_value(value)?),
+        Signal::Logs => Request::Logs(serde_json::from_value(value)?),
+        Signal::Metrics => Request::Metrics(serde_json::from_value(value)?),
+    }))
+}
+
+struct Protobuf<R> {
+    input: R,
+    signal: Signal,
+}
+
+impl<R: Read> Iterator for Protobuf<R> {
+    type Item = Result<Request>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut len = [0; 4];
+        match self.input.read_exact(&mut len) {
+            Ok(()) => {}
+            Err(err) if err.kind() == ErrorKind::UnexpectedEof => return None,
+            Err(err) => return Some(Err(err.into())),
+        }
+        let mut message = vec![0; u32::from_be_bytes(len) as usize];
+        if let Err(err) = self.input.read_exact(&mut message) {
+            return Some(Err(err.into()));
+        }
+        let message = message.as_slice();
+        Some(
+            match self.signal {
+                Signal::Traces => ExportTraceServiceRequest::decode(message).map(Request::Traces),
+                Signal::Logs => ExportLogsServiceRequest::decode(message).map(Request::Logs),
+                Signal::Metrics => {
+                    ExportMetricsServiceRequest::decode(message).map(Request::Metrics)
+                }
+            }
+            .map_err(Into::into),
+        )
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use opentelemetry_proto::tonic::{
+        common::v1::{any_value, AnyValue, KeyValue},
+        logs::v1::{LogRecord, ResourceLogs, ScopeLogs},
+        resource::v1::Resource,
+        trace::v1::{ResourceSpans, ScopeSpans, Span},
+    };
+
+    pub(crate) fn resource(service: &str) -> Option<Resource> {
+        Some(Resource {
+            attributes: vec![KeyValue {
+                key: "service.name".into(),
+                value: Some(AnyValue {
+                    value: Some(any_value::Value::StringValue(service.into())),
+                }),
+            }],
+            dropped_attributes_count: 0,
+        })
+    }
+
+    pub(crate) fn traces(service: &str, spans: Vec<Span>) -> Request {
+        Request::Traces(ExportTraceServiceRequest {
+            resource_spans: vec![ResourceSpans {
+                resource: resource(service),
+                scope_spans: vec![ScopeSpans {
+                    spans,
+                    ..Default::default()
+                }],
+                schema_url: String::new(),
+            }],
+        })
+    }
+
+    pub(crate) fn logs(service: &str, log_records: Vec<LogRecord>) -> Request {
+        Request::Logs(ExportLogsServiceRequest {
+            resource_logs: vec![ResourceLogs {
+                resource: resource(service),
+                scope_logs: vec![ScopeLogs {
+                    log_records,
+                    ..Default::default()
+                }],
+                schema_url: String::new(),
+            }],
+        })
+    }
+
+    pub(crate) fn span(name: &str, trace_id: u8, start: u64) -> Span {
+        Span {
+            trace_id: vec![trace_id; 16],
+            span_id: vec![1; 8],
+            name: name.into(),
+            start_time_unix_nano: start,
+            end_time_unix_nano: start + 1_000,
+            ..Default::default()
+        }
+    }
+
+    fn read_all(input: &[u8], signal: Option<Signal>) -> Result<Vec<Request>> {
+        read(input, signal).collect()
+    }
+
+    #[test]
+    fn json_lines() {
+        let traces = traces("checkout", vec![span("GET /cart", 1, 10)]);
+        let logs = logs("checkout", vec![LogRecord::default()]);
+        let input = format!(
+            "{}\n\n{}\n",
+            traces.to_json().unwrap(),
+            logs.to_json().unwrap()
+        );
+
+        assert_eq!(
+            read_all(input.as_bytes(), None).unwrap(),
+            vec![traces.clone(), logs.clone()]
+        );
+        assert_eq!(
+            read_all(input.as_bytes(), Some(Signal::Logs)).unwrap(),
+            vec![logs]
+        );
+        assert!(read_all(b"{\"unknown\": []}\n", None).is_err());
+    }
+
+    #[test]
+    fn length_prefixed_protobuf() {
+        let requests = vec![
+            traces("checkout", vec![span("GET /cart", 1, 10)]),
+            traces("payment", vec![span("charge", 2, 20)]),
+        ];
+        let mut input = Vec::new();
+        for request in &requests {
+            let Request::Traces(request) = request else {
+                unreachable!()
+            };
+            input.extend((request.encoded_len() as u32).to_be_bytes());
+            request.encode(&mut input).unwrap();
+        }
+
+        assert_eq!(read_all(&input, Some(Signal::Traces)).unwrap(), requests);
+        assert!(read_all(&input, None).is_err());
+        assert!(read_all(&input[..input.len() - 1], Some(Signal::Traces)).is_err());
+    }
+}
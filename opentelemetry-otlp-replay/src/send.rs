@@ -0,0 +1,207 @@
+//! Resending recorded export requests with the OTLP exporters.
+use crate::convert::{self, LogConverter};
+use crate::reader::Request;
+use crate::Result;
+use opentelemetry_otlp::{
+    HttpExporterBuilder, LogExporter, MetricsExporter, Protocol, SpanExporter,
+    TonicExporterBuilder, WithExportConfig,
+};
+use opentelemetry_sdk::export::logs::{LogBatch, LogExporter as _};
+use opentelemetry_sdk::export::trace::SpanExporter as _;
+use opentelemetry_sdk::metrics::exporter::PushMetricsExporter;
+use opentelemetry_sdk::metrics::reader::{DefaultAggregationSelector, DefaultTemporalitySelector};
+use std::collections::HashMap;
+use std::time::Duration;
+use tonic::metadata::{MetadataKey, MetadataMap, MetadataValue};
+
+/// Where and how to send telemetry.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct SendConfig {
+    /// The collector endpoint, the base URL to which `/v1/<signal>` is
+    /// appended for HTTP protocols. The exporters read their environment
+    /// variables when unset.
+    pub(crate) endpoint: Option<String>,
+    pub(crate) protocol: Protocol,
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) timeout: Option<Duration>,
+}
+
+impl Default for SendConfig {
+    fn default() -> Self {
+        SendConfig {
+            endpoint: None,
+            protocol: Protocol::Grpc,
+            headers: Vec::new(),
+            timeout: None,
+        }
+    }
+}
+
+/// Sends export requests, building the exporter of each signal on first use.
+#[derive(Debug)]
+pub(crate) struct Sender {
+    config: SendConfig,
+    span_exporter: Option<SpanExporter>,
+    log_exporter: Option<LogExporter>,
+    metrics_exporter: Option<MetricsExporter>,
+    log_converter: LogConverter,
+    /// The number of export requests sent.
+    pub(crate) sent: usize,
+}
+
+impl Sender {
+    pub(crate) fn new(config: SendConfig) -> Self {
+        Sender {
+            config,
+            span_exporter: None,
+            log_exporter: None,
+            metrics_exporter: None,
+            log_converter: LogConverter::default(),
+            sent: 0,
+        }
+    }
+
+    /// Sends `request` as one export per resource, as the SDK exports
+    /// batches of a single resource.
+    pub(crate) async fn send(&mut self, request: Request) -> Result<()> {
+        match request {
+            Request::Traces(request) => {
+                if self.span_exporter.is_none() {
+                    let exporter = match self.config.protocol {
+                        Protocol::Grpc => self.tonic()?.build_span_exporter()?,
+                        _ => self.http("traces").build_span_exporter()?,
+                    };
+                    self.span_exporter = Some(exporter);
+                }
+                let exporter = self.span_exporter.as_mut().expect("built above");
+                for resource_spans in request.resource_spans {
+                    exporter.set_resource(&convert::resource(
+                        resource_spans.resource,
+                        resource_spans.schema_url,
+                    ));
+                    let mut batch = Vec::new();
+                    for scope_spans in resource_spans.scope_spans {
+                        let scope = convert::scope(scope_spans.scope, scope_spans.schema_url);
+                        batch.extend(
+                            scope_spans
+                                .spans
+                                .into_iter()
+                                .map(|span| convert::span_data(span, &scope)),
+                        );
+                    }
+                    exporter.export(batch).await?;
+                    self.sent += 1;
+                }
+            }
+            Request::Logs(request) => {
+                if self.log_exporter.is_none() {
+                    let exporter = match self.config.protocol {
+                        Protocol::Grpc => self.tonic()?.build_log_exporter()?,
+                        _ => self.http("logs").build_log_exporter()?,
+                    };
+                    self.log_exporter = Some(exporter);
+                }
+                let exporter = self.log_exporter.as_mut().expect("built above");
+                for resource_logs in request.resource_logs {
+                    exporter.set_resource(&convert::resource(
+                        resource_logs.resource,
+                        resource_logs.schema_url,
+                    ));
+                    let mut records = Vec::new();
+                    for scope_logs in resource_logs.scope_logs {
+                        let scope = convert::scope(scope_logs.scope, scope_logs.schema_url);
+                        for log_record in scope_logs.log_records {
+                            records
+                                .push((self.log_converter.log_record(log_record), scope.clone()));
+                        }
+                    }
+                    let batch = records
+                        .iter()
+                        .map(|(record, scope)| (record, scope))
+                        .collect::<Vec<_>>();
+                    exporter.export(LogBatch::new(&batch)).await?;
+                    self.sent += 1;
+                }
+            }
+            Request::Metrics(request) => {
+                if self.metrics_exporter.is_none() {
+                    let aggregation = Box::new(DefaultAggregationSelector::new());
+                    let temporality = Box::new(DefaultTemporalitySelector::new());
+                    let exporter = match self.config.protocol {
+                        Protocol::Grpc => self
+                            .tonic()?
+                            .build_metrics_exporter(aggregation, temporality)?,
+                        _ => self
+                            .http("metrics")
+                            .build_metrics_exporter(aggregation, temporality)?,
+                    };
+                    self.metrics_exporter = Some(exporter);
+                }
+                let exporter = self.metrics_exporter.as_ref().expect("built above");
+                for resource_metrics in request.resource_metrics {
+                    exporter
+                        .export(&mut convert::resource_metrics(resource_metrics))
+                        .await?;
+                    self.sent += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Shuts the exporters down.
+    pub(crate) fn shutdown(&mut self) -> Result<()> {
+        if let Some(mut exporter) = self.span_exporter.take() {
+            exporter.shutdown();
+        }
+        if let Some(mut exporter) = self.log_exporter.take() {
+            exporter.shutdown();
+        }
+        if let Some(exporter) = self.metrics_exporter.take() {
+            exporter.shutdown()?;
+        }
+        Ok(())
+    }
+
+    fn tonic(&self) -> Result<TonicExporterBuilder> {
+        let mut metadata = MetadataMap::new();
+        for (key, value) in &self.config.headers {
+            let key = MetadataKey::from_bytes(key.to_lowercase().as_bytes())
+                .map_err(|_| format!("invalid metadata key `{key}`"))?;
+            let value = MetadataValue::try_from(value.as_str())
+                .map_err(|_| format!("invalid metadata value `{value}`"))?;
+            metadata.insert(key, value);
+        }
+        let mut builder = opentelemetry_otlp::new_exporter()
+            .tonic()
+            .with_metadata(metadata);
+        if let Some(endpoint) = &self.config.endpoint {
+            builder = builder.with_endpoint(endpoint);
+        }
+        if let Some(timeout) = self.config.timeout {
+            builder = builder.with_timeout(timeout);
+        }
+        Ok(builder)
+    }
+
+    fn http(&self, signal: &str) -> HttpExporterBuilder {
+        let mut builder = opentelemetry_otlp::new_exporter()
+            .http()
+            .with_protocol(self.config.protocol)
+            .with_headers(
+                self.config
+                    .headers
+                    .iter()
+                    .cloned()
+                    .collect::<HashMap<_, _>>(),
+            );
+        if let Some(endpoint) = &self.config.endpoint {
+            builder =
+                builder.with_endpoint(format!("{}/v1/{signal}", endpoint.trim_end_matches('/')));
+        }
+        if let Some(timeout) = self.config.timeout {
+            builder = builder.with_timeout(timeout);
+        }
+        builder
+    }
+}
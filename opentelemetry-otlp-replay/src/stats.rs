@@ -0,0 +1,202 @@
+//! Summary statistics of recorded export requests.
+use crate::filter::service_name;
+use crate::print::timestamp;
+use crate::reader::{Request, Signal};
+use opentelemetry_proto::tonic::{metrics::v1::metric::Data, resource::v1::Resource};
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+/// Counts of the items of a service.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct ServiceStats {
+    pub(crate) spans: usize,
+    pub(crate) error_spans: usize,
+    pub(crate) logs: usize,
+    pub(crate) metrics: usize,
+    pub(crate) data_points: usize,
+}
+
+/// Statistics accumulated over export requests.
+#[derive(Debug, Default)]
+pub(crate) struct Stats {
+    pub(crate) trace_requests: usize,
+    pub(crate) log_requests: usize,
+    pub(crate) metric_requests: usize,
+    /// By `service.name`, the empty name for resources without one.
+    pub(crate) services: BTreeMap<String, ServiceStats>,
+    /// The earliest and latest time of any item, as nanoseconds since the
+    /// unix epoch.
+    pub(crate) time_range: Option<(u64, u64)>,
+}
+
+impl Stats {
+    pub(crate) fn add(&mut self, request: &Request) {
+        match request.signal() {
+            Signal::Traces => self.trace_requests += 1,
+            Signal::Logs => self.log_requests += 1,
+            Signal::Metrics => self.metric_requests += 1,
+        }
+        match request {
+            Request::Traces(request) => {
+                for resource_spans in &request.resource_spans {
+                    let service = self.service(resource_spans.resource.as_ref());
+                    for span in resource_spans.scope_spans.iter().flat_map(|s| &s.spans) {
+                        service.spans += 1;
+                        if span.status.as_ref().is_some_and(|status| status.code == 2) {
+                            service.error_spans += 1;
+                        }
+                    }
+                    for span in resource_spans.scope_spans.iter().flat_map(|s| &s.spans) {
+                        self.observe(span.start_time_unix_nano);
+                        self.observe(span.end_time_unix_nano);
+                    }
+                }
+            }
+            Request::Logs(request) => {
+                for resource_logs in &request.resource_logs {
+                    let records = || resource_logs.scope_logs.iter().flat_map(|s| &s.log_records);
+                    self.service(resource_logs.resource.as_ref()).logs += records().count();
+                    for log_record in records() {
+                        match log_record.time_unix_nano {
+                            0 => self.observe(log_record.observed_time_unix_nano),
+                            time => self.observe(time),
+                        }
+                    }
+                }
+            }
+            Request::Metrics(request) => {
+                for resource_metrics in &request.resource_metrics {
+                    let metrics = || {
+                        resource_metrics
+                            .scope_metrics
+                            .iter()
+                            .flat_map(|s| &s.metrics)
+                    };
+                    let mut times = Vec::new();
+                    let service = self.service(resource_metrics.resource.as_ref());
+                    for metric in metrics() {
+                        service.metrics += 1;
+                        match &metric.data {
+                            Some(Data::Gauge(gauge)) => {
+                                times.extend(gauge.data_points.iter().map(|dp| dp.time_unix_nano))
+                            }
+                            Some(Data::Sum(sum)) => {
+                                times.extend(sum.data_points.iter().map(|dp| dp.time_unix_nano))
+                            }
+                            Some(Data::Histogram(histogram)) => times
+                                .extend(histogram.data_points.iter().map(|dp| dp.time_unix_nano)),
+                            Some(Data::ExponentialHistogram(histogram)) => times
+                                .extend(histogram.data_points.iter().map(|dp| dp.time_unix_nano)),
+                            Some(Data::Summary(summary)) => {
+                                times.extend(summary.data_points.iter().map(|dp| dp.time_unix_nano))
+                            }
+                            None => {}
+                        }
+                    }
+                    service.data_points += times.len();
+                    times.into_iter().for_each(|time| self.observe(time));
+                }
+            }
+        }
+    }
+
+    fn service(&mut self, resource: Option<&Resource>) -> &mut ServiceStats {
+        let name = resource
+            .and_then(|resource| service_name(&resource.attributes))
+            .unwrap_or_default();
+        self.services.entry(name.to_owned()).or_default()
+    }
+
+    fn observe(&mut self, time: u64) {
+        if time == 0 {
+            return;
+        }
+        self.time_range = Some(match self.time_range {
+            Some((first, last)) => (first.min(time), last.max(time)),
+            None => (time, time),
+        });
+    }
+
+    pub(crate) fn print(&self, out: &mut impl Write) -> io::Result<()> {
+        writeln!(
+            out,
+            "requests: {} traces, {} logs, {} metrics",
+            self.trace_requests, self.log_requests, self.metric_requests
+        )?;
+        if let Some((first, last)) = self.time_range {
+            writeln!(
+                out,
+                "time range: {} to {}",
+                timestamp(first),
+                timestamp(last)
+            )?;
+        }
+        for (name, service) in &self.services {
+            let name = if name.is_empty() { "<unknown>" } else { name };
+            writeln!(out, "service {name}")?;
+            if service.spans > 0 {
+                writeln!(
+                    out,
+                    "  spans: {} ({} errors)",
+                    service.spans, service.error_spans
+                )?;
+            }
+            if service.logs > 0 {
+                writeln!(out, "  logs: {}", service.logs)?;
+            }
+            if service.metrics > 0 {
+                writeln!(
+                    out,
+                    "  metrics: {} ({} data points)",
+                    service.metrics, service.data_points
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::tests::{logs, span, traces};
+    use opentelemetry_proto::tonic::{logs::v1::LogRecord, trace::v1::Status};
+
+    #[test]
+    fn count_per_service() {
+        let mut failed = span("charge", 2, 30);
+        failed.status = Some(Status {
+            message: "declined".into(),
+            code: 2,
+        });
+        let mut stats = Stats::default();
+        stats.add(&traces("checkout", vec![span("GET /cart", 1, 10)]));
+        stats.add(&traces("payment", vec![span("charge", 2, 20), failed]));
+        stats.add(&logs(
+            "checkout",
+            vec![LogRecord {
+                observed_time_unix_nano: 5,
+                ..Default::default()
+            }],
+        ));
+
+        assert_eq!((stats.trace_requests, stats.log_requests), (2, 1));
+        assert_eq!(
+            stats.services["checkout"],
+            ServiceStats {
+                spans: 1,
+                logs: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            stats.services["payment"],
+            ServiceStats {
+                spans: 2,
+                error_spans: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(stats.time_range, Some((5, 1_030)));
+    }
+}
@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use opentelemetry::logs::LogResult;
+use opentelemetry_proto::tonic::collector::logs::v1::ExportLogsServiceRequest;
+use opentelemetry_proto::transform::logs::tonic::group_logs_by_resource_and_scope;
+use opentelemetry_sdk::export::logs::{LogBatch, LogExporter};
+
+use super::FileExporter;
+
+#[async_trait]
+impl LogExporter for FileExporter {
+    async fn export(&mut self, batch: LogBatch<'_>) -> LogResult<()> {
+        let request = ExportLogsServiceRequest {
+            resource_logs: group_logs_by_resource_and_scope(batch, &self.resource),
+        };
+        Ok(self.write(&request)?)
+    }
+
+    fn shutdown(&mut self) {
+        let _ = FileExporter::shutdown(self);
+    }
+
+    fn set_resource(&mut self, resource: &opentelemetry_sdk::Resource) {
+        self.resource = resource.into();
+    }
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use opentelemetry::metrics::Result;
+use opentelemetry_proto::tonic::collector::metrics::v1::ExportMetricsServiceRequest;
+use opentelemetry_sdk::metrics::data::ResourceMetrics;
+
+use crate::metric::MetricsClient;
+
+use super::FileExporter;
+
+#[async_trait]
+impl MetricsClient for FileExporter {
+    async fn export(&self, metrics: &mut ResourceMetrics) -> Result<()> {
+        let request = ExportMetricsServiceRequest::from(&*metrics);
+        Ok(self.write(&request)?)
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(FileExporter::shutdown(self)?)
+    }
+}
@@ -0,0 +1,531 @@
+//! OTLP file exporter.
+//!
+//! Implements the [OTLP File Exporter], which writes export requests to a
+//! local file instead of sending them to a collector. Files written as JSON
+//! lines can be ingested by the collector's `otlpjsonfile` receiver.
+//!
+//! [OTLP File Exporter]: https://opentelemetry.io/docs/specs/otel/protocol/file-exporter/
+#[cfg(any(feature = "trace", feature = "logs"))]
+use opentelemetry_proto::transform::common::tonic::ResourceAttributesWithSchema;
+use prost::Message;
+use serde::Serialize;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime};
+
+#[cfg(feature = "metrics")]
+mod metrics;
+
+#[cfg(feature = "logs")]
+mod logs;
+
+#[cfg(feature = "trace")]
+mod trace;
+
+/// The encoding of export requests written by the file exporter.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FileFormat {
+    /// One [OTLP JSON] encoded export request per line, as read by the
+    /// collector's `otlpjsonfile` receiver.
+    ///
+    /// [OTLP JSON]: https://opentelemetry.io/docs/specs/otlp/#json-protobuf-encoding
+    #[default]
+    JsonLines,
+    /// Protobuf encoded export requests, each preceded by its length in bytes
+    /// as a 4 byte big-endian unsigned integer.
+    Protobuf,
+}
+
+/// When written data is synced to disk with [`File::sync_data`].
+///
+/// Data is always synced before a file is rotated and when the exporter is
+/// flushed or shut down.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SyncPolicy {
+    /// Leave syncing to the operating system.
+    #[default]
+    Never,
+    /// Sync after every export.
+    EveryExport,
+    /// Sync after an export if the last sync happened at least the given
+    /// duration ago.
+    Interval(Duration),
+}
+
+/// Configuration for the OTLP file exporter.
+///
+/// The exporter appends to the file at the configured path. With a maximum
+/// file size or a rotation interval set, the file is rotated by renaming it to
+/// `<path>.<unix timestamp in milliseconds>` and starting a new one. Rotated
+/// files beyond [`with_max_files`] are removed, oldest first.
+///
+/// [`with_max_files`]: FileExporterBuilder::with_max_files
+///
+/// ## Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::time::Duration;
+///
+/// // Create a span exporter rotating its file every hour and at 64 MiB,
+/// // keeping the last 24 rotated files.
+/// # #[cfg(feature="trace")]
+/// let span_exporter = opentelemetry_otlp::new_exporter()
+///     .file("/var/log/otel/traces.jsonl")
+///     .with_max_file_size(64 * 1024 * 1024)
+///     .with_rotation_interval(Duration::from_secs(60 * 60))
+///     .with_max_files(24)
+///     .build_span_exporter()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct FileExporterBuilder {
+    path: PathBuf,
+    format: FileFormat,
+    rotation: Rotation,
+    sync_policy: SyncPolicy,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Rotation {
+    max_file_size: Option<u64>,
+    interval: Option<Duration>,
+    max_files: Option<usize>,
+}
+
+impl FileExporterBuilder {
+    pub(crate) fn new(path: impl Into<PathBuf>) -> Self {
+        FileExporterBuilder {
+            path: path.into(),
+            format: FileFormat::default(),
+            rotation: Rotation::default(),
+            sync_policy: SyncPolicy::default(),
+        }
+    }
+
+    /// Set the encoding of written export requests, [`FileFormat::JsonLines`] by default.
+    pub fn with_format(mut self, format: FileFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Rotate the file before it grows beyond `bytes`.
+    ///
+    /// Export requests are never split across files, so a file holding a
+    /// single request larger than `bytes` can exceed this size.
+    pub fn with_max_file_size(mut self, bytes: u64) -> Self {
+        self.rotation.max_file_size = Some(bytes);
+        self
+    }
+
+    /// Rotate the file once it has been written to for `interval`.
+    pub fn with_rotation_interval(mut self, interval: Duration) -> Self {
+        self.rotation.interval = Some(interval);
+        self
+    }
+
+    /// Keep at most `count` rotated files, all of them are kept by default.
+    pub fn with_max_files(mut self, count: usize) -> Self {
+        self.rotation.max_files = Some(count);
+        self
+    }
+
+    /// Set when written data is synced to disk, [`SyncPolicy::Never`] by default.
+    pub fn with_sync_policy(mut self, policy: SyncPolicy) -> Self {
+        self.sync_policy = policy;
+        self
+    }
+
+    /// Build an OTLP span exporter writing to the configured file.
+    #[cfg(feature = "trace")]
+    pub fn build_span_exporter(
+        self,
+    ) -> Result<crate::SpanExporter, opentelemetry::trace::TraceError> {
+        Ok(crate::SpanExporter::new(self.build_exporter()?))
+    }
+
+    /// Build an OTLP log exporter writing to the configured file.
+    #[cfg(feature = "logs")]
+    pub fn build_log_exporter(self) -> opentelemetry::logs::LogResult<crate::LogExporter> {
+        Ok(crate::LogExporter::new(self.build_exporter()?))
+    }
+
+    /// Build an OTLP metrics exporter writing to the configured file.
+    #[cfg(feature = "metrics")]
+    pub fn build_metrics_exporter(
+        self,
+        aggregation_selector: Box<dyn opentelemetry_sdk::metrics::reader::AggregationSelector>,
+        temporality_selector: Box<dyn opentelemetry_sdk::metrics::reader::TemporalitySelector>,
+    ) -> opentelemetry::metrics::Result<crate::MetricsExporter> {
+        Ok(crate::MetricsExporter::new(
+            self.build_exporter()?,
+            temporality_selector,
+            aggregation_selector,
+        ))
+    }
+
+    fn build_exporter(self) -> Result<FileExporter, crate::Error> {
+        let file = RotatingFile::open(self.path, self.rotation, self.sync_policy)?;
+        Ok(FileExporter {
+            file: Mutex::new(Some(file)),
+            format: self.format,
+            #[cfg(any(feature = "trace", feature = "logs"))]
+            resource: ResourceAttributesWithSchema::default(),
+        })
+    }
+}
+
+#[derive(Debug)]
+struct FileExporter {
+    /// The file written to, `None` once the exporter is shut down.
+    file: Mutex<Option<RotatingFile>>,
+    format: FileFormat,
+    #[cfg(any(feature = "trace", feature = "logs"))]
+    resource: ResourceAttributesWithSchema,
+}
+
+impl FileExporter {
+    fn write<T: Message + Serialize>(&self, request: &T) -> Result<(), crate::Error> {
+        let record = match self.format {
+            FileFormat::JsonLines => {
+                let mut record = serde_json::to_vec(request).map_err(io::Error::from)?;
+                record.push(b'\n');
+                record
+            }
+            FileFormat::Protobuf => {
+                let len = u32::try_from(request.encoded_len()).map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "export request is too large to be length prefixed",
+                    )
+                })?;
+                let mut record = Vec::with_capacity(4 + len as usize);
+                record.extend_from_slice(&len.to_be_bytes());
+                request.encode_raw(&mut record);
+                record
+            }
+        };
+        self.with_file(|file| file.write(&record))
+    }
+
+    #[cfg(feature = "trace")]
+    fn flush(&self) -> Result<(), crate::Error> {
+        self.with_file(RotatingFile::sync)
+    }
+
+    fn shutdown(&self) -> Result<(), crate::Error> {
+        let file = self.file.lock().map_err(|_| poisoned())?.take();
+        match file {
+            Some(mut file) => file.sync().map_err(Into::into),
+            None => Ok(()),
+        }
+    }
+
+    fn with_file(
+        &self,
+        f: impl FnOnce(&mut RotatingFile) -> io::Result<()>,
+    ) -> Result<(), crate::Error> {
+        let mut file = self.file.lock().map_err(|_| poisoned())?;
+        match file.as_mut() {
+            Some(file) => f(file).map_err(Into::into),
+            None => {
+                Err(io::Error::new(io::ErrorKind::Other, "exporter is already shut down").into())
+            }
+        }
+    }
+}
+
+fn poisoned() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Other,
+        "the lock of the file exporter has been poisoned",
+    )
+}
+
+/// A file which is rotated by size or age, keeping a bounded number of rotated files.
+#[derive(Debug)]
+struct RotatingFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    opened_at: Instant,
+    synced_at: Instant,
+    rotation: Rotation,
+    sync_policy: SyncPolicy,
+}
+
+impl RotatingFile {
+    fn open(path: PathBuf, rotation: Rotation, sync_policy: SyncPolicy) -> io::Result<Self> {
+        let file = open_append(&path)?;
+        let size = file.metadata()?.len();
+        let now = Instant::now();
+        Ok(RotatingFile {
+            path,
+            file,
+            size,
+            opened_at: now,
+            synced_at: now,
+            rotation,
+            sync_policy,
+        })
+    }
+
+    /// Write a whole record, rotating first if it would exceed the configured limits.
+    fn write(&mut self, record: &[u8]) -> io::Result<()> {
+        if self.should_rotate(record.len() as u64) {
+            self.rotate()?;
+        }
+        self.file.write_all(record)?;
+        self.size += record.len() as u64;
+
+        match self.sync_policy {
+            SyncPolicy::Never => Ok(()),
+            SyncPolicy::EveryExport => self.sync(),
+            SyncPolicy::Interval(interval) if self.synced_at.elapsed() >= interval => self.sync(),
+            SyncPolicy::Interval(_) => Ok(()),
+        }
+    }
+
+    fn sync(&mut self) -> io::Result<()> {
+        self.file.sync_data()?;
+        self.synced_at = Instant::now();
+        Ok(())
+    }
+
+    fn should_rotate(&self, len: u64) -> bool {
+        if self.size == 0 {
+            return false;
+        }
+        self.rotation
+            .max_file_size
+            .is_some_and(|max| self.size + len > max)
+            || self
+                .rotation
+                .interval
+                .is_some_and(|interval| self.opened_at.elapsed() >= interval)
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.sync()?;
+        fs::rename(&self.path, self.rotated_path())?;
+        self.file = open_append(&self.path)?;
+        self.size = 0;
+        self.opened_at = Instant::now();
+
+        if let Some(max_files) = self.rotation.max_files {
+            let mut rotated = self.rotated_files()?;
+            rotated.sort_unstable();
+            let expired = rotated.len().saturating_sub(max_files);
+            for (_, path) in rotated.into_iter().take(expired) {
+                fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The path of the next rotated file, suffixed with the current time in milliseconds.
+    fn rotated_path(&self) -> PathBuf {
+        let mut timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        loop {
+            let mut path = self.path.clone().into_os_string();
+            path.push(format!(".{timestamp}"));
+            let path = PathBuf::from(path);
+            if !path.exists() {
+                return path;
+            }
+            timestamp += 1;
+        }
+    }
+
+    /// All rotated files of this file with their timestamps.
+    fn rotated_files(&self) -> io::Result<Vec<(u128, PathBuf)>> {
+        let Some(name) = self.path.file_name().and_then(|name| name.to_str()) else {
+            return Ok(Vec::new());
+        };
+        let prefix = format!("{name}.");
+        let dir = match self.path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+
+        let mut rotated = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let timestamp = entry
+                .file_name()
+                .to_str()
+                .and_then(|file_name| file_name.strip_prefix(&prefix))
+                .filter(|suffix| suffix.bytes().all(|b| b.is_ascii_digit()))
+                .and_then(|suffix| suffix.parse().ok());
+            if let Some(timestamp) = timestamp {
+                rotated.push((timestamp, entry.path()));
+            }
+        }
+        Ok(rotated)
+    }
+}
+
+fn open_append(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+#[cfg(all(test, feature = "trace"))]
+mod tests {
+    use super::*;
+    use opentelemetry::trace::{
+        SpanContext, SpanId, SpanKind, Status, TraceFlags, TraceId, TraceState,
+    };
+    use opentelemetry_proto::tonic::collector::trace::v1::ExportTraceServiceRequest;
+    use opentelemetry_sdk::export::trace::{SpanData, SpanExporter};
+    use opentelemetry_sdk::trace::{SpanEvents, SpanLinks};
+    use std::io::BufRead;
+
+    fn span(name: &'static str) -> SpanData {
+        SpanData {
+            span_context: SpanContext::new(
+                TraceId::from_bytes(1u128.to_be_bytes()),
+                SpanId::from_bytes(2u64.to_be_bytes()),
+                TraceFlags::SAMPLED,
+                false,
+                TraceState::default(),
+            ),
+            parent_span_id: SpanId::INVALID,
+            span_kind: SpanKind::Internal,
+            name: name.into(),
+            start_time: SystemTime::UNIX_EPOCH,
+            end_time: SystemTime::UNIX_EPOCH + Duration::from_secs(1),
+            attributes: vec![],
+            dropped_attributes_count: 0,
+            events: SpanEvents::default(),
+            links: SpanLinks::default(),
+            status: Status::Unset,
+            instrumentation_lib: Default::default(),
+        }
+    }
+
+    fn span_names(request: ExportTraceServiceRequest) -> Vec<String> {
+        request
+            .resource_spans
+            .into_iter()
+            .flat_map(|resource_spans| resource_spans.scope_spans)
+            .flat_map(|scope_spans| scope_spans.spans)
+            .map(|span| span.name)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn json_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("traces.jsonl");
+        let mut exporter = FileExporterBuilder::new(&path)
+            .build_span_exporter()
+            .unwrap();
+
+        exporter.export(vec![span("first")]).await.unwrap();
+        exporter
+            .export(vec![span("second"), span("third")])
+            .await
+            .unwrap();
+
+        let names = io::BufReader::new(File::open(&path).unwrap())
+            .lines()
+            .map(|line| span_names(serde_json::from_str(&line.unwrap()).unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec![vec!["first"], vec!["second", "third"]]);
+    }
+
+    #[tokio::test]
+    async fn length_prefixed_protobuf() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("traces.binpb");
+        let mut exporter = FileExporterBuilder::new(&path)
+            .with_format(FileFormat::Protobuf)
+            .with_sync_policy(SyncPolicy::EveryExport)
+            .build_span_exporter()
+            .unwrap();
+
+        exporter.export(vec![span("first")]).await.unwrap();
+        exporter.export(vec![span("second")]).await.unwrap();
+
+        let content = fs::read(&path).unwrap();
+        let mut remaining = content.as_slice();
+        let mut names = Vec::new();
+        while !remaining.is_empty() {
+            let (len, rest) = remaining.split_at(4);
+            let len = u32::from_be_bytes(len.try_into().unwrap()) as usize;
+            let (message, rest) = rest.split_at(len);
+            names.extend(span_names(
+                ExportTraceServiceRequest::decode(message).unwrap(),
+            ));
+            remaining = rest;
+        }
+        assert_eq!(names, vec!["first", "second"]);
+    }
+
+    #[tokio::test]
+    async fn rotate_by_size_and_keep_max_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("traces.jsonl");
+        let mut exporter = FileExporterBuilder::new(&path)
+            .with_max_file_size(1)
+            .with_max_files(2)
+            .build_span_exporter()
+            .unwrap();
+
+        for name in ["first", "second", "third", "fourth"] {
+            exporter.export(vec![span(name)]).await.unwrap();
+        }
+
+        let mut files = fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        files.sort();
+        assert_eq!(files.len(), 3);
+        assert_eq!(files[0], "traces.jsonl");
+
+        let names = files
+            .iter()
+            .map(|file| {
+                let content = fs::read_to_string(dir.path().join(file)).unwrap();
+                span_names(serde_json::from_str(content.trim_end()).unwrap())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec![vec!["fourth"], vec!["second"], vec!["third"]]);
+    }
+
+    #[tokio::test]
+    async fn rotate_by_interval() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("traces.jsonl");
+        let mut exporter = FileExporterBuilder::new(&path)
+            .with_rotation_interval(Duration::ZERO)
+            .build_span_exporter()
+            .unwrap();
+
+        exporter.export(vec![span("first")]).await.unwrap();
+        exporter.export(vec![span("second")]).await.unwrap();
+
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 2);
+    }
+
+    #[tokio::test]
+    async fn export_after_shutdown() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut exporter = FileExporterBuilder::new(dir.path().join("traces.jsonl"))
+            .build_span_exporter()
+            .unwrap();
+
+        exporter.shutdown();
+
+        assert!(exporter.export(vec![span("first")]).await.is_err());
+    }
+}
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use http::{header::CONTENT_TYPE, Method};
 use opentelemetry::logs::{LogError, LogResult};
+use opentelemetry_http::suppress_telemetry;
 use opentelemetry_sdk::export::logs::{LogBatch, LogExporter};
 
 use super::OtlpHttpClient;
@@ -32,7 +33,7 @@ impl LogExporter for OtlpHttpClient {
         }
 
         let request_uri = request.uri().to_string();
-        let response = client.send(request).await?;
+        let response = suppress_telemetry(client.send(request)).await?;
 
         if !response.status().is_success() {
             let error = format!(
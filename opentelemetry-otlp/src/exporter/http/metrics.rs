@@ -3,6 +3,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use http::{header::CONTENT_TYPE, Method};
 use opentelemetry::metrics::{MetricsError, Result};
+use opentelemetry_http::suppress_telemetry;
 use opentelemetry_sdk::metrics::data::ResourceMetrics;
 
 use crate::{metric::MetricsClient, Error};
@@ -33,8 +34,7 @@ impl MetricsClient for OtlpHttpClient {
             request.headers_mut().insert(k.clone(), v.clone());
         }
 
-        client
-            .send(request)
+        suppress_telemetry(client.send(request))
             .await
             .map_err(|e| MetricsError::ExportErr(Box::new(Error::RequestFailed(e))))?;
 
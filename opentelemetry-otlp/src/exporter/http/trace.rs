@@ -3,6 +3,7 @@ use std::sync::Arc;
 use futures_core::future::BoxFuture;
 use http::{header::CONTENT_TYPE, Method};
 use opentelemetry::trace::TraceError;
+use opentelemetry_http::suppress_telemetry;
 use opentelemetry_sdk::export::trace::{ExportResult, SpanData, SpanExporter};
 
 use super::OtlpHttpClient;
@@ -47,7 +48,7 @@ impl SpanExporter for OtlpHttpClient {
 
         Box::pin(async move {
             let request_uri = request.uri().to_string();
-            let response = client.send(request).await?;
+            let response = suppress_telemetry(client.send(request)).await?;
 
             if !response.status().is_success() {
                 let error = format!(
@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use core::fmt;
 use opentelemetry::logs::{LogError, LogResult};
+use opentelemetry::{trace::FutureExt, Context};
 use opentelemetry_proto::tonic::collector::logs::v1::{
     logs_service_client::LogsServiceClient, ExportLogsServiceRequest,
 };
@@ -75,6 +76,7 @@ impl LogExporter for TonicLogsClient {
                 extensions,
                 ExportLogsServiceRequest { resource_logs },
             ))
+            .with_context(Context::current().with_telemetry_suppressed())
             .await
             .map_err(crate::Error::from)?;
 
@@ -3,6 +3,7 @@ use std::sync::Mutex;
 
 use async_trait::async_trait;
 use opentelemetry::metrics::{MetricsError, Result};
+use opentelemetry::{trace::FutureExt, Context};
 use opentelemetry_proto::tonic::collector::metrics::v1::{
     metrics_service_client::MetricsServiceClient, ExportMetricsServiceRequest,
 };
@@ -78,6 +79,7 @@ impl MetricsClient for TonicMetricsClient {
                 extensions,
                 ExportMetricsServiceRequest::from(&*metrics),
             ))
+            .with_context(Context::current().with_telemetry_suppressed())
             .await
             .map_err(crate::Error::from)?;
 
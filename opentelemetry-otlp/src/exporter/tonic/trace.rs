@@ -1,7 +1,8 @@
 use core::fmt;
 
 use futures_core::future::BoxFuture;
-use opentelemetry::trace::TraceError;
+use opentelemetry::trace::{FutureExt, TraceError};
+use opentelemetry::Context;
 use opentelemetry_proto::tonic::collector::trace::v1::{
     trace_service_client::TraceServiceClient, ExportTraceServiceRequest,
 };
@@ -81,6 +82,7 @@ impl SpanExporter for TonicTracesClient {
                     extensions,
                     ExportTraceServiceRequest { resource_spans },
                 ))
+                .with_context(Context::current().with_telemetry_suppressed())
                 .await
                 .map_err(crate::Error::from)?;
 
@@ -112,6 +112,11 @@
 //! * `reqwest-rustls`: Use reqwest with TLS with system trust roots via `rustls-native-certs` crate.
 //! * `reqwest-rustls-webkpi-roots`: Use reqwest with TLS with Mozilla's trust roots via `webkpi-roots` crate.
 //!
+//! The following feature flags offer additional transports:
+//!
+//! * `file`: Write export requests to a local file as JSON lines or length-prefixed protobuf,
+//!   with size and time based rotation. See [`FileExporterBuilder`].
+//!
 //! # Kitchen Sink Full Configuration
 //!
 //! Example showing how to override all configuration options.
@@ -256,6 +261,9 @@ pub use crate::exporter::{
 
 use opentelemetry_sdk::export::ExportError;
 
+#[cfg(feature = "file")]
+pub use crate::exporter::file::{FileExporterBuilder, FileFormat, SyncPolicy};
+
 #[cfg(any(feature = "http-proto", feature = "http-json"))]
 pub use crate::exporter::http::HttpExporterBuilder;
 
@@ -291,6 +299,15 @@ impl OtlpExporterPipeline {
     pub fn http(self) -> HttpExporterBuilder {
         HttpExporterBuilder::default()
     }
+
+    /// Write to the file at `path`, return a `FileExporterBuilder` to config the file rotation
+    /// and build the exporter.
+    ///
+    /// This exporter can be used in `tracing`, `metrics` and `logs` pipelines.
+    #[cfg(feature = "file")]
+    pub fn file(self, path: impl Into<std::path::PathBuf>) -> FileExporterBuilder {
+        FileExporterBuilder::new(path)
+    }
 }
 
 /// Create a new pipeline builder with the recommended configuration.
@@ -367,6 +384,11 @@ pub enum Error {
     #[error("prost encoding error {0}")]
     EncodeError(#[from] prost::EncodeError),
 
+    /// Writing to the file of the file exporter failed.
+    #[cfg(feature = "file")]
+    #[error("file exporter I/O error {0}")]
+    Io(#[from] std::io::Error),
+
     /// The lock in exporters has been poisoned.
     #[cfg(feature = "metrics")]
     #[error("the lock of the {0} has been poisoned")]
@@ -8,6 +8,9 @@ use crate::exporter::tonic::TonicExporterBuilder;
 #[cfg(feature = "http-proto")]
 use crate::exporter::http::HttpExporterBuilder;
 
+#[cfg(feature = "file")]
+use crate::exporter::file::FileExporterBuilder;
+
 use crate::{NoExporterConfig, OtlpPipeline};
 use async_trait::async_trait;
 use std::fmt::Debug;
@@ -54,6 +57,9 @@ pub enum LogExporterBuilder {
     /// Http log exporter builder
     #[cfg(feature = "http-proto")]
     Http(HttpExporterBuilder),
+    /// File log exporter builder
+    #[cfg(feature = "file")]
+    File(FileExporterBuilder),
 }
 
 impl LogExporterBuilder {
@@ -64,6 +70,8 @@ impl LogExporterBuilder {
             LogExporterBuilder::Tonic(builder) => builder.build_log_exporter(),
             #[cfg(feature = "http-proto")]
             LogExporterBuilder::Http(builder) => builder.build_log_exporter(),
+            #[cfg(feature = "file")]
+            LogExporterBuilder::File(builder) => builder.build_log_exporter(),
         }
     }
 }
@@ -82,6 +90,13 @@ impl From<HttpExporterBuilder> for LogExporterBuilder {
     }
 }
 
+#[cfg(feature = "file")]
+impl From<FileExporterBuilder> for LogExporterBuilder {
+    fn from(exporter: FileExporterBuilder) -> Self {
+        LogExporterBuilder::File(exporter)
+    }
+}
+
 /// OTLP exporter that sends log data
 #[derive(Debug)]
 pub struct LogExporter {
@@ -103,6 +118,10 @@ impl opentelemetry_sdk::export::logs::LogExporter for LogExporter {
         self.client.export(batch).await
     }
 
+    fn shutdown(&mut self) {
+        self.client.shutdown();
+    }
+
     fn set_resource(&mut self, resource: &opentelemetry_sdk::Resource) {
         self.client.set_resource(resource);
     }
@@ -29,6 +29,9 @@ use std::time;
 #[cfg(feature = "http-proto")]
 use crate::exporter::http::HttpExporterBuilder;
 
+#[cfg(feature = "file")]
+use crate::exporter::file::FileExporterBuilder;
+
 /// Target to which the exporter is going to send metrics, defaults to https://localhost:4317/v1/metrics.
 /// Learn about the relationship between this constant and default/spans/logs at
 /// <https://github.com/open-telemetry/opentelemetry-specification/blob/main/specification/protocol/exporter.md#endpoint-urls-for-otlphttp>
@@ -62,6 +65,7 @@ impl OtlpPipeline {
 
 /// OTLP metrics exporter builder.
 #[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
 #[non_exhaustive]
 pub enum MetricsExporterBuilder {
     /// Tonic metrics exporter builder
@@ -70,10 +74,13 @@ pub enum MetricsExporterBuilder {
     /// Http metrics exporter builder
     #[cfg(feature = "http-proto")]
     Http(HttpExporterBuilder),
+    /// File metrics exporter builder
+    #[cfg(feature = "file")]
+    File(FileExporterBuilder),
 
     /// Missing exporter builder
     #[doc(hidden)]
-    #[cfg(not(any(feature = "http-proto", feature = "grpc-tonic")))]
+    #[cfg(not(any(feature = "http-proto", feature = "grpc-tonic", feature = "file")))]
     Unconfigured,
 }
 
@@ -93,7 +100,11 @@ impl MetricsExporterBuilder {
             MetricsExporterBuilder::Http(builder) => {
                 builder.build_metrics_exporter(aggregation_selector, temporality_selector)
             }
-            #[cfg(not(any(feature = "http-proto", feature = "grpc-tonic")))]
+            #[cfg(feature = "file")]
+            MetricsExporterBuilder::File(builder) => {
+                builder.build_metrics_exporter(aggregation_selector, temporality_selector)
+            }
+            #[cfg(not(any(feature = "http-proto", feature = "grpc-tonic", feature = "file")))]
             MetricsExporterBuilder::Unconfigured => {
                 drop(temporality_selector);
                 drop(aggregation_selector);
@@ -119,6 +130,13 @@ impl From<HttpExporterBuilder> for MetricsExporterBuilder {
     }
 }
 
+#[cfg(feature = "file")]
+impl From<FileExporterBuilder> for MetricsExporterBuilder {
+    fn from(exporter: FileExporterBuilder) -> Self {
+        MetricsExporterBuilder::File(exporter)
+    }
+}
+
 /// Pipeline to build OTLP metrics exporter
 ///
 /// Note that currently the OTLP metrics exporter only supports tonic as it's grpc layer and tokio as
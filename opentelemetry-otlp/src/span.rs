@@ -18,6 +18,9 @@ use crate::exporter::tonic::TonicExporterBuilder;
 #[cfg(any(feature = "http-proto", feature = "http-json"))]
 use crate::exporter::http::HttpExporterBuilder;
 
+#[cfg(feature = "file")]
+use crate::exporter::file::FileExporterBuilder;
+
 use crate::{NoExporterConfig, OtlpPipeline};
 
 /// Target to which the exporter is going to send spans, defaults to https://localhost:4317/v1/traces.
@@ -170,6 +173,9 @@ pub enum SpanExporterBuilder {
     /// Http span exporter builder
     #[cfg(any(feature = "http-proto", feature = "http-json"))]
     Http(HttpExporterBuilder),
+    /// File span exporter builder
+    #[cfg(feature = "file")]
+    File(FileExporterBuilder),
 }
 
 impl SpanExporterBuilder {
@@ -180,6 +186,8 @@ impl SpanExporterBuilder {
             SpanExporterBuilder::Tonic(builder) => builder.build_span_exporter(),
             #[cfg(any(feature = "http-proto", feature = "http-json"))]
             SpanExporterBuilder::Http(builder) => builder.build_span_exporter(),
+            #[cfg(feature = "file")]
+            SpanExporterBuilder::File(builder) => builder.build_span_exporter(),
         }
     }
 }
@@ -198,6 +206,13 @@ impl From<HttpExporterBuilder> for SpanExporterBuilder {
     }
 }
 
+#[cfg(feature = "file")]
+impl From<FileExporterBuilder> for SpanExporterBuilder {
+    fn from(exporter: FileExporterBuilder) -> Self {
+        SpanExporterBuilder::File(exporter)
+    }
+}
+
 /// OTLP exporter that sends tracing information
 #[derive(Debug)]
 pub struct SpanExporter(Box<dyn opentelemetry_sdk::export::trace::SpanExporter>);
@@ -214,6 +229,14 @@ impl opentelemetry_sdk::export::trace::SpanExporter for SpanExporter {
         self.0.export(batch)
     }
 
+    fn shutdown(&mut self) {
+        self.0.shutdown();
+    }
+
+    fn force_flush(&mut self) -> BoxFuture<'static, ExportResult> {
+        self.0.force_flush()
+    }
+
     fn set_resource(&mut self, resource: &opentelemetry_sdk::Resource) {
         self.0.set_resource(resource);
     }
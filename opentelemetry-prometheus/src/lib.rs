@@ -212,6 +212,13 @@ static HISTOGRAM_TYPES: Lazy<[TypeId; 3]> = Lazy::new(|| {
         TypeId::of::<data::Histogram<f64>>(),
     ]
 });
+static SUMMARY_TYPES: Lazy<[TypeId; 3]> = Lazy::new(|| {
+    [
+        TypeId::of::<data::Summary<i64>>(),
+        TypeId::of::<data::Summary<u64>>(),
+        TypeId::of::<data::Summary<f64>>(),
+    ]
+});
 static SUM_TYPES: Lazy<[TypeId; 3]> = Lazy::new(|| {
     [
         TypeId::of::<data::Sum<i64>>(),
@@ -236,6 +243,8 @@ impl Collector {
 
         if HISTOGRAM_TYPES.contains(&type_id) {
             Some((MetricType::HISTOGRAM, name))
+        } else if SUMMARY_TYPES.contains(&type_id) {
+            Some((MetricType::SUMMARY, name))
         } else if GAUGE_TYPES.contains(&type_id) {
             Some((MetricType::GAUGE, name))
         } else if SUM_TYPES.contains(&type_id) {
@@ -367,6 +376,12 @@ impl prometheus::core::Collector for Collector {
                     add_histogram_metric(&mut res, hist, description, &scope_labels, name);
                 } else if let Some(hist) = data.downcast_ref::<data::Histogram<f64>>() {
                     add_histogram_metric(&mut res, hist, description, &scope_labels, name);
+                } else if let Some(summary) = data.downcast_ref::<data::Summary<i64>>() {
+                    add_summary_metric(&mut res, summary, description, &scope_labels, name);
+                } else if let Some(summary) = data.downcast_ref::<data::Summary<u64>>() {
+                    add_summary_metric(&mut res, summary, description, &scope_labels, name);
+                } else if let Some(summary) = data.downcast_ref::<data::Summary<f64>>() {
+                    add_summary_metric(&mut res, summary, description, &scope_labels, name);
                 } else if let Some(sum) = data.downcast_ref::<data::Sum<u64>>() {
                     add_sum_metric(&mut res, sum, description, &scope_labels, name);
                 } else if let Some(sum) = data.downcast_ref::<data::Sum<i64>>() {
@@ -492,6 +507,46 @@ fn add_histogram_metric<T: Numeric>(
     }
 }
 
+fn add_summary_metric<T: Numeric>(
+    res: &mut Vec<MetricFamily>,
+    summary: &data::Summary<T>,
+    description: String,
+    extra: &[LabelPair],
+    name: Cow<'static, str>,
+) {
+    for dp in &summary.data_points {
+        let kvs = get_attrs(
+            &mut dp.attributes.iter().map(|kv| (&kv.key, &kv.value)),
+            extra,
+        );
+        let quantiles = dp
+            .quantile_values
+            .iter()
+            .map(|qv| {
+                let mut q = prometheus::proto::Quantile::default();
+                q.set_quantile(qv.quantile);
+                q.set_value(qv.value);
+                q
+            })
+            .collect();
+
+        let mut s = prometheus::proto::Summary::default();
+        s.set_sample_sum(dp.sum.as_f64());
+        s.set_sample_count(dp.count);
+        s.set_quantile(protobuf::RepeatedField::from_vec(quantiles));
+        let mut pm = prometheus::proto::Metric::default();
+        pm.set_label(protobuf::RepeatedField::from_vec(kvs));
+        pm.set_summary(s);
+
+        let mut mf = prometheus::proto::MetricFamily::default();
+        mf.set_name(name.to_string());
+        mf.set_help(description.clone());
+        mf.set_field_type(prometheus::proto::MetricType::SUMMARY);
+        mf.set_metric(protobuf::RepeatedField::from_vec(vec![pm]));
+        res.push(mf);
+    }
+}
+
 fn add_sum_metric<T: Numeric>(
     res: &mut Vec<MetricFamily>,
     sum: &data::Sum<T>,
@@ -130,6 +130,40 @@ fn prometheus_exporter_integration() {
             }),
             ..Default::default()
         },
+        TestCase {
+            name: "signed histogram",
+            expected_file: "histogram_signed.txt",
+            record_metrics: Box::new(|meter| {
+                let attrs = vec![Key::new("A").string("B"), Key::new("C").string("D")];
+                let histogram = meter
+                    .i64_histogram("histogram_skew")
+                    .with_description("a signed histogram")
+                    .with_unit("ms")
+                    .init();
+                histogram.record(-5, &attrs);
+                histogram.record(3, &attrs);
+                histogram.record(7, &attrs);
+                histogram.record(101, &attrs);
+            }),
+            ..Default::default()
+        },
+        TestCase {
+            name: "summary",
+            expected_file: "summary.txt",
+            record_metrics: Box::new(|meter| {
+                let attrs = vec![Key::new("A").string("B"), Key::new("C").string("D")];
+                let histogram = meter
+                    .f64_histogram("summary_baz")
+                    .with_description("a very nice summary")
+                    .with_unit("By")
+                    .init();
+                histogram.record(23.0, &attrs);
+                histogram.record(7.0, &attrs);
+                histogram.record(101.0, &attrs);
+                histogram.record(105.0, &attrs);
+            }),
+            ..Default::default()
+        },
         TestCase {
             name: "sanitized attributes to labels",
             expected_file: "sanitized_labels.txt",
@@ -385,6 +419,15 @@ fn prometheus_exporter_integration() {
                 )
                 .unwrap(),
             )
+            .with_view(
+                new_view(
+                    Instrument::new().name("summary_*"),
+                    Stream::new().aggregation(Aggregation::Summary {
+                        quantiles: vec![0.0, 0.5, 1.0],
+                    }),
+                )
+                .unwrap(),
+            )
             .build();
         let meter = provider.versioned_meter(
             "testmeter",
@@ -159,8 +159,20 @@ pub(crate) mod serializers {
             }
         }
 
-        let value = deserializer.deserialize_map(ValueVisitor)?;
-        Ok(Some(value))
+        // Missing values are serialized as `null`.
+        struct OptionalValue(AnyValue);
+
+        impl<'de> Deserialize<'de> for OptionalValue {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_map(ValueVisitor).map(OptionalValue)
+            }
+        }
+
+        let value = Option::<OptionalValue>::deserialize(deserializer)?;
+        Ok(value.map(|value| value.0))
     }
 
     pub fn serialize_u64_to_string<S>(value: &u64, serializer: S) -> Result<S::Ok, S::Error>
@@ -12,7 +12,7 @@ pub mod tonic {
     use opentelemetry_sdk::metrics::data::{
         self, Exemplar as SdkExemplar, ExponentialHistogram as SdkExponentialHistogram,
         Gauge as SdkGauge, Histogram as SdkHistogram, Metric as SdkMetric,
-        ScopeMetrics as SdkScopeMetrics, Sum as SdkSum, Temporality,
+        ScopeMetrics as SdkScopeMetrics, Sum as SdkSum, Summary as SdkSummary, Temporality,
     };
     use opentelemetry_sdk::Resource as SdkResource;
 
@@ -24,6 +24,7 @@ pub mod tonic {
             exponential_histogram_data_point::Buckets as TonicBuckets,
             metric::Data as TonicMetricData, number_data_point,
             number_data_point::Value as TonicDataPointValue,
+            summary_data_point::ValueAtQuantile as TonicValueAtQuantile,
             AggregationTemporality as TonicTemporality, AggregationTemporality,
             DataPointFlags as TonicDataPointFlags, Exemplar as TonicExemplar,
             ExponentialHistogram as TonicExponentialHistogram,
@@ -31,7 +32,8 @@ pub mod tonic {
             Gauge as TonicGauge, Histogram as TonicHistogram,
             HistogramDataPoint as TonicHistogramDataPoint, Metric as TonicMetric,
             NumberDataPoint as TonicNumberDataPoint, ResourceMetrics as TonicResourceMetrics,
-            ScopeMetrics as TonicScopeMetrics, Sum as TonicSum,
+            ScopeMetrics as TonicScopeMetrics, Sum as TonicSum, Summary as TonicSummary,
+            SummaryDataPoint as TonicSummaryDataPoint,
         },
         resource::v1::Resource as TonicResource,
     };
@@ -171,6 +173,12 @@ pub mod tonic {
                 Ok(TonicMetricData::ExponentialHistogram(hist.into()))
             } else if let Some(hist) = data.downcast_ref::<SdkExponentialHistogram<f64>>() {
                 Ok(TonicMetricData::ExponentialHistogram(hist.into()))
+            } else if let Some(summary) = data.downcast_ref::<SdkSummary<i64>>() {
+                Ok(TonicMetricData::Summary(summary.into()))
+            } else if let Some(summary) = data.downcast_ref::<SdkSummary<u64>>() {
+                Ok(TonicMetricData::Summary(summary.into()))
+            } else if let Some(summary) = data.downcast_ref::<SdkSummary<f64>>() {
+                Ok(TonicMetricData::Summary(summary.into()))
             } else if let Some(sum) = data.downcast_ref::<SdkSum<u64>>() {
                 Ok(TonicMetricData::Sum(sum.into()))
             } else if let Some(sum) = data.downcast_ref::<SdkSum<i64>>() {
@@ -278,6 +286,36 @@ pub mod tonic {
         }
     }
 
+    impl<T> From<&SdkSummary<T>> for TonicSummary
+    where
+        T: Numeric,
+    {
+        fn from(summary: &SdkSummary<T>) -> Self {
+            TonicSummary {
+                data_points: summary
+                    .data_points
+                    .iter()
+                    .map(|dp| TonicSummaryDataPoint {
+                        attributes: dp.attributes.iter().map(Into::into).collect(),
+                        start_time_unix_nano: to_nanos(dp.start_time),
+                        time_unix_nano: to_nanos(dp.time),
+                        count: dp.count,
+                        sum: dp.sum.into_f64(),
+                        quantile_values: dp
+                            .quantile_values
+                            .iter()
+                            .map(|qv| TonicValueAtQuantile {
+                                quantile: qv.quantile,
+                                value: qv.value,
+                            })
+                            .collect(),
+                        flags: TonicDataPointFlags::default() as u32,
+                    })
+                    .collect(),
+            }
+        }
+    }
+
     impl<T> From<&SdkSum<T>> for TonicSum
     where
         T: fmt::Debug + Into<TonicExemplarValue> + Into<TonicDataPointValue> + Copy,
@@ -178,6 +178,15 @@ mod json_deserialize {
         assert_eq!(keyvalue.value.unwrap().value.unwrap(), Value::IntValue(303));
     }
 
+    #[test]
+    fn test_deserialize_null_value() {
+        let keyvalue: KeyValue =
+            serde_json::from_str(r#"{"key": "service.name", "value": null}"#).unwrap();
+
+        assert_eq!(keyvalue.key, "service.name".to_string());
+        assert_eq!(keyvalue.value, None);
+    }
+
     #[test]
     fn test_event() {
         let event_json: Event = serde_json::from_str(EVENT_JSON).unwrap();
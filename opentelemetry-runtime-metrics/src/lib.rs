@@ -0,0 +1,154 @@
+//! Process and runtime metrics for OpenTelemetry.
+//!
+//! This crate registers observable instruments reporting metrics about the
+//! current process, following the [process metrics semantic conventions], and
+//! optionally about a [Tokio] runtime.
+//!
+//! # Process metrics
+//!
+//! Process metrics are read from `/proc` and are only reported on Linux:
+//!
+//! | Metric | Instrument | Attributes |
+//! |--------|------------|------------|
+//! | `process.cpu.time` | counter | `cpu.mode` |
+//! | `process.cpu.utilization` | gauge | `cpu.mode` |
+//! | `process.memory.usage` | up down counter | |
+//! | `process.memory.virtual` | up down counter | |
+//! | `process.open_file_descriptor.count` | up down counter | |
+//! | `process.thread.count` | up down counter | |
+//! | `process.context_switches` | counter | `process.context_switch_type` |
+//! | `process.paging.faults` | counter | `process.paging.fault_type` |
+//! | `process.disk.io` | counter | `disk.io.direction` |
+//!
+//! `process.network.io` is not reported: `/proc/self/net/dev` holds the traffic
+//! of the whole network namespace, which would be counted once per process
+//! sharing it, e.g. in a container.
+//!
+//! # Tokio metrics
+//!
+//! With the `tokio` feature enabled, metrics about the runtime of a
+//! [`tokio::runtime::Handle`] can be reported, see [`RuntimeMetricsBuilder::with_tokio_runtime`].
+//!
+//! # Example
+//!
+//! ```
+//! use opentelemetry_runtime_metrics::RuntimeMetricsBuilder;
+//! use opentelemetry_sdk::metrics::SdkMeterProvider;
+//!
+//! let provider = SdkMeterProvider::builder().build();
+//!
+//! RuntimeMetricsBuilder::new()
+//!     .register(&provider)
+//!     .expect("process metrics are valid instruments");
+//! ```
+//!
+//! [process metrics semantic conventions]: https://github.com/open-telemetry/semantic-conventions/blob/main/docs/system/process-metrics.md
+//! [Tokio]: https://tokio.rs
+#![warn(
+    future_incompatible,
+    missing_debug_implementations,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    unreachable_pub,
+    unused
+)]
+#![cfg_attr(
+    docsrs,
+    feature(doc_cfg, doc_auto_cfg),
+    deny(rustdoc::broken_intra_doc_links)
+)]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/open-telemetry/opentelemetry-rust/main/assets/logo.svg"
+)]
+#![cfg_attr(test, deny(warnings))]
+
+use opentelemetry::metrics::{MeterProvider, Result};
+
+#[cfg(target_os = "linux")]
+mod process;
+#[cfg(feature = "tokio")]
+mod tokio;
+
+const INSTRUMENTATION_LIBRARY_NAME: &str = "opentelemetry-runtime-metrics";
+
+/// Registers process and runtime instruments on a [`MeterProvider`].
+///
+/// Measurements are taken when the meter provider collects its observable
+/// instruments, registering the same metrics twice results in duplicate
+/// instruments.
+#[derive(Debug)]
+pub struct RuntimeMetricsBuilder {
+    process: bool,
+    #[cfg(feature = "tokio")]
+    tokio_handle: Option<::tokio::runtime::Handle>,
+}
+
+impl Default for RuntimeMetricsBuilder {
+    fn default() -> Self {
+        RuntimeMetricsBuilder {
+            process: true,
+            #[cfg(feature = "tokio")]
+            tokio_handle: None,
+        }
+    }
+}
+
+impl RuntimeMetricsBuilder {
+    /// Create a builder reporting process metrics only.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether to report process metrics, enabled by default.
+    pub fn with_process_metrics(mut self, enabled: bool) -> Self {
+        self.process = enabled;
+        self
+    }
+
+    /// Report metrics about the runtime `handle` belongs to.
+    ///
+    /// `tokio.worker.count` is always reported, the other metrics rely on
+    /// unstable Tokio APIs and are only reported when compiling with
+    /// `--cfg tokio_unstable`. Counters additionally require 64-bit atomics.
+    ///
+    /// | Metric | Instrument | Attributes |
+    /// |--------|------------|------------|
+    /// | `tokio.worker.count` | up down counter | |
+    /// | `tokio.blocking_thread.count` | up down counter | |
+    /// | `tokio.blocking_thread.idle.count` | up down counter | |
+    /// | `tokio.task.active.count` | up down counter | |
+    /// | `tokio.global_queue.depth` | gauge | |
+    /// | `tokio.blocking_queue.depth` | gauge | |
+    /// | `tokio.worker.local_queue.depth` | gauge | `tokio.worker.index` |
+    /// | `tokio.worker.park.count` | counter | `tokio.worker.index` |
+    /// | `tokio.worker.poll.count` | counter | `tokio.worker.index` |
+    /// | `tokio.worker.steal.count` | counter | `tokio.worker.index` |
+    /// | `tokio.worker.busy.time` | counter | `tokio.worker.index` |
+    #[cfg(feature = "tokio")]
+    pub fn with_tokio_runtime(mut self, handle: ::tokio::runtime::Handle) -> Self {
+        self.tokio_handle = Some(handle);
+        self
+    }
+
+    /// Register the configured instruments on `provider`.
+    pub fn register<P: MeterProvider>(self, provider: &P) -> Result<()> {
+        let meter = provider.versioned_meter(
+            INSTRUMENTATION_LIBRARY_NAME,
+            Some(env!("CARGO_PKG_VERSION")),
+            Some(opentelemetry_semantic_conventions::SCHEMA_URL),
+            None,
+        );
+
+        if self.process {
+            #[cfg(target_os = "linux")]
+            process::register(&meter)?;
+        }
+        #[cfg(feature = "tokio")]
+        if let Some(handle) = self.tokio_handle {
+            tokio::register(&meter, handle)?;
+        }
+
+        Ok(())
+    }
+}
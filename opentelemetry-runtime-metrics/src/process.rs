@@ -0,0 +1,316 @@
+//! Process metrics read from the Linux `/proc` filesystem.
+
+use std::{fs, io, sync::Mutex, time::Instant};
+
+use opentelemetry::{
+    global,
+    metrics::{Meter, MetricsError, Result},
+    KeyValue,
+};
+use opentelemetry_semantic_conventions::{attribute, metric};
+
+/// Register the process instruments on `meter`.
+pub(crate) fn register(meter: &Meter) -> Result<()> {
+    let ticks_per_second = clock_ticks_per_second();
+    let cpu_time =
+        move || read("/proc/self/stat", Stat::parse).map(|s| s.cpu_time(ticks_per_second));
+
+    meter
+        .f64_observable_counter(metric::PROCESS_CPU_TIME)
+        .with_description("Total CPU seconds broken down by different states.")
+        .with_unit("s")
+        .with_callback(move |observer| {
+            if let Some(cpu) = cpu_time() {
+                observer.observe(cpu.user, &[KeyValue::new(attribute::CPU_MODE, "user")]);
+                observer.observe(cpu.system, &[KeyValue::new(attribute::CPU_MODE, "system")]);
+            }
+        })
+        .try_init()?;
+
+    let cpus = std::thread::available_parallelism().map_or(1, |n| n.get()) as f64;
+    let previous = Mutex::new((Instant::now(), cpu_time().unwrap_or_default()));
+    meter
+        .f64_observable_gauge(metric::PROCESS_CPU_UTILIZATION)
+        .with_description(
+            "Difference in process.cpu.time since the last measurement, divided by the elapsed time and number of CPUs available to the process.",
+        )
+        .with_unit("1")
+        .with_callback(move |observer| {
+            let (Some(cpu), Ok(mut previous)) = (cpu_time(), previous.lock()) else {
+                return;
+            };
+            let now = Instant::now();
+            let elapsed = now.duration_since(previous.0).as_secs_f64() * cpus;
+            if elapsed > 0.0 {
+                let user = (cpu.user - previous.1.user) / elapsed;
+                let system = (cpu.system - previous.1.system) / elapsed;
+                observer.observe(user, &[KeyValue::new(attribute::CPU_MODE, "user")]);
+                observer.observe(system, &[KeyValue::new(attribute::CPU_MODE, "system")]);
+            }
+            *previous = (now, cpu);
+        })
+        .try_init()?;
+
+    meter
+        .i64_observable_up_down_counter(metric::PROCESS_MEMORY_USAGE)
+        .with_description("The amount of physical memory in use.")
+        .with_unit("By")
+        .with_callback(|observer| {
+            if let Some(status) = read("/proc/self/status", Status::parse) {
+                observer.observe(status.resident_bytes as i64, &[]);
+            }
+        })
+        .try_init()?;
+
+    meter
+        .i64_observable_up_down_counter(metric::PROCESS_MEMORY_VIRTUAL)
+        .with_description("The amount of committed virtual memory.")
+        .with_unit("By")
+        .with_callback(|observer| {
+            if let Some(status) = read("/proc/self/status", Status::parse) {
+                observer.observe(status.virtual_bytes as i64, &[]);
+            }
+        })
+        .try_init()?;
+
+    meter
+        .i64_observable_up_down_counter(metric::PROCESS_OPEN_FILE_DESCRIPTOR_COUNT)
+        .with_description("Number of file descriptors in use by the process.")
+        .with_unit("{count}")
+        .with_callback(|observer| match fs::read_dir("/proc/self/fd") {
+            Ok(entries) => observer.observe(entries.count() as i64, &[]),
+            Err(err) => report("/proc/self/fd", err),
+        })
+        .try_init()?;
+
+    meter
+        .i64_observable_up_down_counter(metric::PROCESS_THREAD_COUNT)
+        .with_description("Process threads count.")
+        .with_unit("{thread}")
+        .with_callback(|observer| {
+            if let Some(status) = read("/proc/self/status", Status::parse) {
+                observer.observe(status.threads as i64, &[]);
+            }
+        })
+        .try_init()?;
+
+    meter
+        .u64_observable_counter(metric::PROCESS_CONTEXT_SWITCHES)
+        .with_description("Number of times the process has been context switched.")
+        .with_unit("{count}")
+        .with_callback(|observer| {
+            if let Some(status) = read("/proc/self/status", Status::parse) {
+                let switch_type =
+                    |value| KeyValue::new(attribute::PROCESS_CONTEXT_SWITCH_TYPE, value);
+                observer.observe(status.voluntary_switches, &[switch_type("voluntary")]);
+                observer.observe(status.involuntary_switches, &[switch_type("involuntary")]);
+            }
+        })
+        .try_init()?;
+
+    meter
+        .u64_observable_counter(metric::PROCESS_PAGING_FAULTS)
+        .with_description("Number of page faults the process has made.")
+        .with_unit("{fault}")
+        .with_callback(|observer| {
+            if let Some(stat) = read("/proc/self/stat", Stat::parse) {
+                let fault_type = |value| KeyValue::new(attribute::PROCESS_PAGING_FAULT_TYPE, value);
+                observer.observe(stat.major_faults, &[fault_type("major")]);
+                observer.observe(stat.minor_faults, &[fault_type("minor")]);
+            }
+        })
+        .try_init()?;
+
+    meter
+        .u64_observable_counter(metric::PROCESS_DISK_IO)
+        .with_description("Disk bytes transferred.")
+        .with_unit("By")
+        .with_callback(|observer| {
+            if let Some(io) = read("/proc/self/io", DiskIo::parse) {
+                let direction = |value| KeyValue::new(attribute::DISK_IO_DIRECTION, value);
+                observer.observe(io.read_bytes, &[direction("read")]);
+                observer.observe(io.write_bytes, &[direction("write")]);
+            }
+        })
+        .try_init()?;
+
+    Ok(())
+}
+
+fn clock_ticks_per_second() -> f64 {
+    // SAFETY: sysconf has no preconditions.
+    match unsafe { libc::sysconf(libc::_SC_CLK_TCK) } {
+        ticks if ticks > 0 => ticks as f64,
+        // USER_HZ on all common architectures.
+        _ => 100.0,
+    }
+}
+
+fn read<T>(path: &str, parse: fn(&str) -> Option<T>) -> Option<T> {
+    match fs::read_to_string(path) {
+        Ok(content) => {
+            let parsed = parse(&content);
+            if parsed.is_none() {
+                global::handle_error(MetricsError::Other(format!("unexpected format of {path}")));
+            }
+            parsed
+        }
+        Err(err) => {
+            report(path, err);
+            None
+        }
+    }
+}
+
+fn report(path: &str, err: io::Error) {
+    global::handle_error(MetricsError::Other(format!("failed to read {path}: {err}")));
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct CpuTime {
+    user: f64,
+    system: f64,
+}
+
+/// The fields of `/proc/self/stat` reported as metrics.
+#[derive(Debug, PartialEq)]
+struct Stat {
+    minor_faults: u64,
+    major_faults: u64,
+    user_ticks: u64,
+    system_ticks: u64,
+}
+
+impl Stat {
+    fn parse(content: &str) -> Option<Self> {
+        // The executable name in the second field may contain spaces and
+        // parentheses, so fields are counted from the last closing parenthesis
+        // which is followed by the third field.
+        let (_, fields) = content.rsplit_once(')')?;
+        let fields: Vec<&str> = fields.split_whitespace().collect();
+        let field = |n: usize| fields.get(n - 3)?.parse().ok();
+
+        Some(Stat {
+            minor_faults: field(10)?,
+            major_faults: field(12)?,
+            user_ticks: field(14)?,
+            system_ticks: field(15)?,
+        })
+    }
+
+    fn cpu_time(&self, ticks_per_second: f64) -> CpuTime {
+        CpuTime {
+            user: self.user_ticks as f64 / ticks_per_second,
+            system: self.system_ticks as f64 / ticks_per_second,
+        }
+    }
+}
+
+/// The fields of `/proc/self/status` reported as metrics.
+#[derive(Debug, Default, PartialEq)]
+struct Status {
+    resident_bytes: u64,
+    virtual_bytes: u64,
+    threads: u64,
+    voluntary_switches: u64,
+    involuntary_switches: u64,
+}
+
+impl Status {
+    fn parse(content: &str) -> Option<Self> {
+        let mut status = Status::default();
+        for line in content.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            let kilobytes = || Some(value.strip_suffix("kB")?.trim().parse::<u64>().ok()? * 1024);
+            match key {
+                "VmRSS" => status.resident_bytes = kilobytes()?,
+                "VmSize" => status.virtual_bytes = kilobytes()?,
+                "Threads" => status.threads = value.parse().ok()?,
+                "voluntary_ctxt_switches" => status.voluntary_switches = value.parse().ok()?,
+                "nonvoluntary_ctxt_switches" => status.involuntary_switches = value.parse().ok()?,
+                _ => {}
+            }
+        }
+        Some(status)
+    }
+}
+
+/// The storage I/O of `/proc/self/io`.
+#[derive(Debug, Default, PartialEq)]
+struct DiskIo {
+    read_bytes: u64,
+    write_bytes: u64,
+}
+
+impl DiskIo {
+    fn parse(content: &str) -> Option<Self> {
+        let mut io = DiskIo::default();
+        for line in content.lines() {
+            match line.split_once(':') {
+                Some(("read_bytes", value)) => io.read_bytes = value.trim().parse().ok()?,
+                Some(("write_bytes", value)) => io.write_bytes = value.trim().parse().ok()?,
+                _ => {}
+            }
+        }
+        Some(io)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_stat() {
+        let content = "1234 (my (weird) app) S 1 1234 1234 0 -1 4194560 2150 0 3 0 250 75 0 0 20 0 4 0 1000 10000000 500 18446744073709551615";
+        let stat = Stat::parse(content).unwrap();
+        assert_eq!(
+            stat,
+            Stat {
+                minor_faults: 2150,
+                major_faults: 3,
+                user_ticks: 250,
+                system_ticks: 75,
+            }
+        );
+        assert_eq!(
+            stat.cpu_time(100.0),
+            CpuTime {
+                user: 2.5,
+                system: 0.75
+            }
+        );
+
+        assert_eq!(Stat::parse("1234 (truncated) S 1"), None);
+    }
+
+    #[test]
+    fn parse_status() {
+        let content = "Name:\tapp\nVmSize:\t  10240 kB\nVmRSS:\t    512 kB\nThreads:\t4\nvoluntary_ctxt_switches:\t10\nnonvoluntary_ctxt_switches:\t2\n";
+        assert_eq!(
+            Status::parse(content),
+            Some(Status {
+                resident_bytes: 512 * 1024,
+                virtual_bytes: 10240 * 1024,
+                threads: 4,
+                voluntary_switches: 10,
+                involuntary_switches: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_disk_io() {
+        let content = "rchar: 100\nwchar: 200\nread_bytes: 4096\nwrite_bytes: 8192\ncancelled_write_bytes: 0\n";
+        assert_eq!(
+            DiskIo::parse(content),
+            Some(DiskIo {
+                read_bytes: 4096,
+                write_bytes: 8192,
+            })
+        );
+    }
+}
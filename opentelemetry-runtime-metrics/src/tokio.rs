@@ -0,0 +1,166 @@
+//! Metrics of a Tokio runtime.
+
+use opentelemetry::metrics::{Meter, Result};
+use tokio::runtime::Handle;
+
+/// Register the instruments of the runtime `handle` belongs to on `meter`.
+pub(crate) fn register(meter: &Meter, handle: Handle) -> Result<()> {
+    let metrics = handle.metrics();
+    meter
+        .i64_observable_up_down_counter("tokio.worker.count")
+        .with_description("Number of worker threads used by the runtime.")
+        .with_unit("{thread}")
+        .with_callback(move |observer| observer.observe(metrics.num_workers() as i64, &[]))
+        .try_init()?;
+
+    #[cfg(tokio_unstable)]
+    unstable::register(meter, &handle)?;
+
+    Ok(())
+}
+
+#[cfg(tokio_unstable)]
+mod unstable {
+    use opentelemetry::{
+        metrics::{Meter, Result},
+        KeyValue,
+    };
+    use tokio::runtime::{Handle, RuntimeMetrics};
+
+    /// The name, description and unit of an instrument with the function reading
+    /// its value.
+    type Instrument<F> = (&'static str, &'static str, &'static str, F);
+    type RuntimeValue = fn(&RuntimeMetrics) -> usize;
+    #[cfg(target_has_atomic = "64")]
+    type WorkerValue = fn(&RuntimeMetrics, usize) -> u64;
+
+    fn worker(index: usize) -> [KeyValue; 1] {
+        [KeyValue::new("tokio.worker.index", index as i64)]
+    }
+
+    pub(super) fn register(meter: &Meter, handle: &Handle) -> Result<()> {
+        let up_down_counters: [Instrument<RuntimeValue>; 3] = [
+            (
+                "tokio.blocking_thread.count",
+                "Number of additional threads spawned by the runtime for blocking operations.",
+                "{thread}",
+                RuntimeMetrics::num_blocking_threads,
+            ),
+            (
+                "tokio.blocking_thread.idle.count",
+                "Number of idle threads spawned by the runtime for blocking operations.",
+                "{thread}",
+                RuntimeMetrics::num_idle_blocking_threads,
+            ),
+            (
+                "tokio.task.active.count",
+                "Number of active tasks in the runtime.",
+                "{task}",
+                RuntimeMetrics::active_tasks_count,
+            ),
+        ];
+        for (name, description, unit, value) in up_down_counters {
+            let metrics = handle.metrics();
+            meter
+                .i64_observable_up_down_counter(name)
+                .with_description(description)
+                .with_unit(unit)
+                .with_callback(move |observer| observer.observe(value(&metrics) as i64, &[]))
+                .try_init()?;
+        }
+
+        let queue_depths: [Instrument<RuntimeValue>; 2] = [
+            (
+                "tokio.global_queue.depth",
+                "Number of tasks in the global queue of the runtime.",
+                "{task}",
+                RuntimeMetrics::injection_queue_depth,
+            ),
+            (
+                "tokio.blocking_queue.depth",
+                "Number of tasks in the blocking thread pool queue of the runtime.",
+                "{task}",
+                RuntimeMetrics::blocking_queue_depth,
+            ),
+        ];
+        for (name, description, unit, value) in queue_depths {
+            let metrics = handle.metrics();
+            meter
+                .u64_observable_gauge(name)
+                .with_description(description)
+                .with_unit(unit)
+                .with_callback(move |observer| observer.observe(value(&metrics) as u64, &[]))
+                .try_init()?;
+        }
+
+        let metrics = handle.metrics();
+        meter
+            .u64_observable_gauge("tokio.worker.local_queue.depth")
+            .with_description("Number of tasks in the local queue of each worker.")
+            .with_unit("{task}")
+            .with_callback(move |observer| {
+                for index in 0..metrics.num_workers() {
+                    let depth = metrics.worker_local_queue_depth(index) as u64;
+                    observer.observe(depth, &worker(index));
+                }
+            })
+            .try_init()?;
+
+        #[cfg(target_has_atomic = "64")]
+        register_worker_counters(meter, handle)?;
+
+        Ok(())
+    }
+
+    #[cfg(target_has_atomic = "64")]
+    fn register_worker_counters(meter: &Meter, handle: &Handle) -> Result<()> {
+        let counters: [Instrument<WorkerValue>; 3] = [
+            (
+                "tokio.worker.park.count",
+                "Number of times each worker thread parked.",
+                "{park}",
+                RuntimeMetrics::worker_park_count,
+            ),
+            (
+                "tokio.worker.poll.count",
+                "Number of tasks polled by each worker thread.",
+                "{poll}",
+                RuntimeMetrics::worker_poll_count,
+            ),
+            (
+                "tokio.worker.steal.count",
+                "Number of tasks each worker thread stole from other workers.",
+                "{task}",
+                RuntimeMetrics::worker_steal_count,
+            ),
+        ];
+        for (name, description, unit, value) in counters {
+            let metrics = handle.metrics();
+            meter
+                .u64_observable_counter(name)
+                .with_description(description)
+                .with_unit(unit)
+                .with_callback(move |observer| {
+                    for index in 0..metrics.num_workers() {
+                        observer.observe(value(&metrics, index), &worker(index));
+                    }
+                })
+                .try_init()?;
+        }
+
+        let metrics = handle.metrics();
+        meter
+            .f64_observable_counter("tokio.worker.busy.time")
+            .with_description("Time each worker thread spent busy.")
+            .with_unit("s")
+            .with_callback(move |observer| {
+                for index in 0..metrics.num_workers() {
+                    let busy = metrics.worker_total_busy_duration(index).as_secs_f64();
+                    observer.observe(busy, &worker(index));
+                }
+            })
+            .try_init()?;
+
+        Ok(())
+    }
+}
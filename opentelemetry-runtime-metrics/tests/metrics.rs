@@ -0,0 +1,62 @@
+use opentelemetry_runtime_metrics::RuntimeMetricsBuilder;
+use opentelemetry_sdk::{
+    metrics::{PeriodicReader, SdkMeterProvider},
+    runtime,
+    testing::metrics::InMemoryMetricsExporter,
+};
+
+fn collect(provider: &SdkMeterProvider, exporter: &InMemoryMetricsExporter) -> Vec<String> {
+    provider.force_flush().unwrap();
+    let resource_metrics = exporter.get_finished_metrics().unwrap();
+    resource_metrics
+        .iter()
+        .flat_map(|rm| &rm.scope_metrics)
+        .inspect(|sm| assert_eq!(sm.scope.name, "opentelemetry-runtime-metrics"))
+        .flat_map(|sm| &sm.metrics)
+        .map(|m| m.name.to_string())
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test(flavor = "multi_thread")]
+async fn process_metrics() {
+    let exporter = InMemoryMetricsExporter::default();
+    let reader = PeriodicReader::builder(exporter.clone(), runtime::Tokio).build();
+    let provider = SdkMeterProvider::builder().with_reader(reader).build();
+
+    RuntimeMetricsBuilder::new().register(&provider).unwrap();
+
+    let names = collect(&provider, &exporter);
+    for name in [
+        "process.cpu.time",
+        "process.cpu.utilization",
+        "process.memory.usage",
+        "process.memory.virtual",
+        "process.open_file_descriptor.count",
+        "process.thread.count",
+        "process.context_switches",
+        "process.paging.faults",
+        "process.disk.io",
+    ] {
+        assert!(names.iter().any(|n| n == name), "{name} not in {names:?}");
+    }
+    assert!(!names.iter().any(|n| n == "process.network.io"));
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn tokio_metrics() {
+    let exporter = InMemoryMetricsExporter::default();
+    let reader = PeriodicReader::builder(exporter.clone(), runtime::Tokio).build();
+    let provider = SdkMeterProvider::builder().with_reader(reader).build();
+
+    RuntimeMetricsBuilder::new()
+        .with_process_metrics(false)
+        .with_tokio_runtime(tokio::runtime::Handle::current())
+        .register(&provider)
+        .unwrap();
+
+    let names = collect(&provider, &exporter);
+    assert!(names.iter().any(|n| n == "tokio.worker.count"));
+    assert!(names.iter().all(|n| n.starts_with("tokio.")));
+}
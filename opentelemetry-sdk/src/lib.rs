@@ -86,6 +86,8 @@
 //! For `trace` the following feature flags are available:
 //!
 //! * `jaeger_remote_sampler`: Enables the [Jaeger remote sampler](https://www.jaegertracing.io/docs/1.53/sampling/).
+//! * `span_redaction`: Enables the [`RedactingSpanProcessor`](trace::RedactingSpanProcessor)
+//!   dropping and redacting spans before export.
 //!
 //! For `logs` the following feature flags are available:
 //!
@@ -0,0 +1,110 @@
+use crate::logs::{LogProcessor, LogRecord};
+use opentelemetry::baggage::BaggageExt;
+use opentelemetry::logs::{LogRecord as _, LogResult};
+use opentelemetry::{Context, InstrumentationLibrary, Key};
+use std::fmt;
+
+/// A [`LogProcessor`] copying baggage entries of the current context onto log
+/// records as attributes.
+///
+/// This is the log counterpart of the
+/// [`BaggageSpanProcessor`](crate::trace::BaggageSpanProcessor). It must be
+/// added before the processors exporting the records, as processors see the
+/// changes of the ones added before them.
+///
+/// # Examples
+///
+/// ```
+/// use opentelemetry_sdk::logs::{BaggageLogProcessor, LoggerProvider};
+///
+/// let processor = BaggageLogProcessor::new(|key| key.as_str().starts_with("app."));
+/// let provider = LoggerProvider::builder()
+///     .with_log_processor(processor)
+///     .build();
+/// ```
+pub struct BaggageLogProcessor {
+    predicate: Box<dyn Fn(&Key) -> bool + Send + Sync>,
+}
+
+impl BaggageLogProcessor {
+    /// Create a processor copying the baggage entries whose key matches
+    /// `predicate`.
+    pub fn new<P>(predicate: P) -> Self
+    where
+        P: Fn(&Key) -> bool + Send + Sync + 'static,
+    {
+        BaggageLogProcessor {
+            predicate: Box::new(predicate),
+        }
+    }
+
+    /// Create a processor copying all baggage entries.
+    pub fn all_keys() -> Self {
+        BaggageLogProcessor::new(|_| true)
+    }
+}
+
+impl fmt::Debug for BaggageLogProcessor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BaggageLogProcessor")
+            .finish_non_exhaustive()
+    }
+}
+
+impl LogProcessor for BaggageLogProcessor {
+    fn emit(&self, record: &mut LogRecord, _instrumentation: &InstrumentationLibrary) {
+        let cx = Context::current();
+        for (key, (value, _metadata)) in cx.baggage() {
+            if (self.predicate)(key) {
+                record.add_attribute(key.clone(), value.clone());
+            }
+        }
+    }
+
+    fn force_flush(&self) -> LogResult<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> LogResult<()> {
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use super::*;
+    use crate::logs::LoggerProvider;
+    use crate::testing::logs::InMemoryLogsExporter;
+    use opentelemetry::logs::{AnyValue, Logger, LoggerProvider as _};
+    use opentelemetry::KeyValue;
+
+    #[test]
+    fn copies_selected_baggage() {
+        let exporter = InMemoryLogsExporter::default();
+        let provider = LoggerProvider::builder()
+            .with_log_processor(BaggageLogProcessor::new(|key| {
+                key.as_str() != "session.token"
+            }))
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let logger = provider.logger("test");
+
+        {
+            let _guard = Context::current_with_baggage([
+                KeyValue::new("tenant.id", "acme"),
+                KeyValue::new("session.token", "secret"),
+            ])
+            .attach();
+            logger.emit(logger.create_log_record());
+        }
+        logger.emit(logger.create_log_record());
+
+        let logs = exporter.get_emitted_logs().unwrap();
+        assert_eq!(logs.len(), 2);
+        assert_eq!(
+            logs[0].record.attributes_iter().collect::<Vec<_>>(),
+            vec![&(Key::new("tenant.id"), AnyValue::from("acme"))]
+        );
+        assert_eq!(logs[1].record.attributes_iter().count(), 0);
+    }
+}
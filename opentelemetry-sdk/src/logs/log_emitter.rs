@@ -247,6 +247,10 @@ impl opentelemetry::logs::Logger for Logger {
 
     /// Emit a `LogRecord`.
     fn emit(&self, mut record: Self::LogRecord) {
+        // records emitted while telemetry is suppressed, e.g. by exporters, are dropped
+        if Context::is_current_telemetry_suppressed() {
+            return;
+        }
         let provider = self.provider();
         let processors = provider.log_processors();
         let trace_context = Context::map_current(|cx| {
@@ -269,6 +273,9 @@ impl opentelemetry::logs::Logger for Logger {
 
     #[cfg(feature = "logs_level_enabled")]
     fn event_enabled(&self, level: Severity, target: &str) -> bool {
+        if Context::is_current_telemetry_suppressed() {
+            return false;
+        }
         let provider = self.provider();
 
         let mut enabled = false;
@@ -478,6 +485,25 @@ mod tests {
         assert_eq!(counter.load(std::sync::atomic::Ordering::SeqCst), 3);
     }
 
+    #[test]
+    fn suppressed_telemetry_records_are_dropped() {
+        let counter = Arc::new(AtomicU64::new(0));
+        let logger_provider = LoggerProvider::builder()
+            .with_log_processor(ShutdownTestLogProcessor::new(counter.clone()))
+            .build();
+        let logger = logger_provider.logger("test-logger");
+
+        {
+            let _guard = Context::enter_telemetry_suppressed();
+            #[cfg(feature = "logs_level_enabled")]
+            assert!(!logger.event_enabled(Severity::Error, "test"));
+            logger.emit(logger.create_log_record());
+        }
+        logger.emit(logger.create_log_record());
+
+        assert_eq!(counter.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
     #[test]
     fn shutdown_idempotent_test() {
         let counter = Arc::new(AtomicU64::new(0));
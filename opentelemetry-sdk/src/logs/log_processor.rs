@@ -1,3 +1,4 @@
+use crate::util::block_on_with_timeout;
 use crate::{
     export::logs::{ExportResult, LogBatch, LogExporter},
     logs::LogRecord,
@@ -16,7 +17,10 @@ use opentelemetry::{
     logs::{LogError, LogResult},
     InstrumentationLibrary,
 };
-use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, SyncSender};
+use std::thread::{self, JoinHandle};
+use std::time::Instant;
 use std::{cmp::min, env, sync::Mutex};
 use std::{
     fmt::{self, Debug, Formatter},
@@ -509,6 +513,250 @@ enum BatchMessage {
     SetResource(Arc<Resource>),
 }
 
+/// A [`LogProcessor`] batching log records like the [`BatchLogProcessor`], on a
+/// dedicated background thread instead of an async runtime.
+///
+/// Log records are queued in a bounded channel and exported by the thread,
+/// which drives the exporter with a blocking executor. The exporter must
+/// therefore not rely on an async runtime being present, e.g. it may use a
+/// blocking HTTP client.
+///
+/// [`force_flush`] and [`shutdown`] block until the thread exported the queued
+/// log records, which is bounded by the export timeout. As the export doesn't
+/// run on the calling thread, they can safely be called from within an async
+/// runtime.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "testing")]
+/// # {
+/// use opentelemetry_sdk::logs::{LoggerProvider, ThreadedBatchLogProcessor};
+/// use opentelemetry_sdk::testing::logs::InMemoryLogsExporter;
+///
+/// let processor = ThreadedBatchLogProcessor::builder(InMemoryLogsExporter::default()).build();
+/// let provider = LoggerProvider::builder()
+///     .with_log_processor(processor)
+///     .build();
+/// # }
+/// ```
+///
+/// [`force_flush`]: LogProcessor::force_flush
+/// [`shutdown`]: LogProcessor::shutdown
+#[derive(Debug)]
+pub struct ThreadedBatchLogProcessor {
+    message_sender: SyncSender<ThreadMessage>,
+    handle: Mutex<Option<JoinHandle<()>>>,
+    is_shutdown: AtomicBool,
+}
+
+/// Messages sent to the thread of a [`ThreadedBatchLogProcessor`].
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+enum ThreadMessage {
+    ExportLog((LogRecord, InstrumentationLibrary)),
+    Flush(mpsc::Sender<ExportResult>),
+    Shutdown(mpsc::Sender<ExportResult>),
+    SetResource(Arc<Resource>),
+}
+
+impl ThreadedBatchLogProcessor {
+    pub(crate) fn new(exporter: Box<dyn LogExporter>, config: BatchConfig) -> Self {
+        let (message_sender, message_receiver) = mpsc::sync_channel(config.max_queue_size);
+        let handle = thread::Builder::new()
+            .name("OpenTelemetry.Logs.BatchProcessor".into())
+            .spawn(move || {
+                ThreadedBatchLogProcessorInternal {
+                    logs: Vec::new(),
+                    config,
+                    exporter,
+                }
+                .run(message_receiver)
+            })
+            .expect("failed to spawn the batch log processor thread");
+
+        ThreadedBatchLogProcessor {
+            message_sender,
+            handle: Mutex::new(Some(handle)),
+            is_shutdown: AtomicBool::new(false),
+        }
+    }
+
+    /// Create a new threaded batch processor builder
+    pub fn builder<E>(exporter: E) -> ThreadedBatchLogProcessorBuilder<E>
+    where
+        E: LogExporter,
+    {
+        ThreadedBatchLogProcessorBuilder {
+            exporter,
+            config: Default::default(),
+        }
+    }
+
+    fn send_and_wait(
+        &self,
+        message: impl FnOnce(mpsc::Sender<ExportResult>) -> ThreadMessage,
+    ) -> LogResult<()> {
+        let (res_sender, res_receiver) = mpsc::channel();
+        self.message_sender
+            .send(message(res_sender))
+            .map_err(|err| LogError::Other(err.into()))?;
+        res_receiver
+            .recv()
+            .map_err(|err| LogError::Other(err.into()))
+            .and_then(std::convert::identity)
+    }
+}
+
+impl LogProcessor for ThreadedBatchLogProcessor {
+    fn emit(&self, record: &mut LogRecord, instrumentation: &InstrumentationLibrary) {
+        if self.is_shutdown.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let result = self.message_sender.try_send(ThreadMessage::ExportLog((
+            record.clone(),
+            instrumentation.clone(),
+        )));
+
+        if let Err(err) = result {
+            global::handle_error(LogError::Other(err.into()));
+        }
+    }
+
+    fn force_flush(&self) -> LogResult<()> {
+        if self.is_shutdown.load(Ordering::Relaxed) {
+            return Err(LogError::Other("batch log processor is shut down".into()));
+        }
+        self.send_and_wait(ThreadMessage::Flush)
+    }
+
+    fn shutdown(&self) -> LogResult<()> {
+        if self.is_shutdown.swap(true, Ordering::Relaxed) {
+            return Err(LogError::Other(
+                "batch log processor is already shut down".into(),
+            ));
+        }
+        let result = self.send_and_wait(ThreadMessage::Shutdown);
+        if let Some(handle) = self.handle.lock().ok().and_then(|mut handle| handle.take()) {
+            let _ = handle.join();
+        }
+        result
+    }
+
+    fn set_resource(&self, resource: &Resource) {
+        let resource = Arc::new(resource.clone());
+        let _ = self
+            .message_sender
+            .send(ThreadMessage::SetResource(resource));
+    }
+}
+
+struct ThreadedBatchLogProcessorInternal {
+    logs: Vec<(LogRecord, InstrumentationLibrary)>,
+    config: BatchConfig,
+    exporter: Box<dyn LogExporter>,
+}
+
+impl ThreadedBatchLogProcessorInternal {
+    fn run(mut self, messages: Receiver<ThreadMessage>) {
+        let mut next_export = Instant::now() + self.config.scheduled_delay;
+        loop {
+            let timeout = next_export.saturating_duration_since(Instant::now());
+            match messages.recv_timeout(timeout) {
+                // Log has finished, add to buffer of pending logs.
+                Ok(ThreadMessage::ExportLog(log)) => {
+                    self.logs.push(log);
+
+                    if self.logs.len() >= self.config.max_export_batch_size {
+                        if let Err(err) = self.export_batch() {
+                            global::handle_error(err);
+                        }
+                    }
+                }
+                Ok(ThreadMessage::Flush(res_sender)) => {
+                    let _ = res_sender.send(self.export_all());
+                }
+                Ok(ThreadMessage::Shutdown(res_sender)) => {
+                    let result = self.export_all();
+                    self.exporter.shutdown();
+                    let _ = res_sender.send(result);
+                    break;
+                }
+                Ok(ThreadMessage::SetResource(resource)) => {
+                    self.exporter.set_resource(&resource);
+                }
+                // Log batch interval time reached, export current logs.
+                Err(RecvTimeoutError::Timeout) => {
+                    if let Err(err) = self.export_all() {
+                        global::handle_error(err);
+                    }
+                    next_export = Instant::now() + self.config.scheduled_delay;
+                }
+                // The processor was dropped without being shut down.
+                Err(RecvTimeoutError::Disconnected) => {
+                    if let Err(err) = self.export_all() {
+                        global::handle_error(err);
+                    }
+                    self.exporter.shutdown();
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Exports all buffered logs in batches, returning the first error.
+    fn export_all(&mut self) -> ExportResult {
+        let mut result = Ok(());
+        while !self.logs.is_empty() {
+            if let Err(err) = self.export_batch() {
+                result = result.and(Err(err));
+            }
+        }
+        result
+    }
+
+    fn export_batch(&mut self) -> ExportResult {
+        let count = min(self.logs.len(), self.config.max_export_batch_size);
+        let batch = self.logs.drain(..count).collect::<Vec<_>>();
+        if batch.is_empty() {
+            return Ok(());
+        }
+        let log_vec: Vec<(&LogRecord, &InstrumentationLibrary)> = batch
+            .iter()
+            .map(|log_data| (&log_data.0, &log_data.1))
+            .collect();
+        let timeout = self.config.max_export_timeout;
+        block_on_with_timeout(
+            self.exporter.export(LogBatch::new(log_vec.as_slice())),
+            timeout,
+        )
+        .unwrap_or(Err(LogError::ExportTimedOut(timeout)))
+    }
+}
+
+/// A builder for creating [`ThreadedBatchLogProcessor`] instances.
+#[derive(Debug)]
+pub struct ThreadedBatchLogProcessorBuilder<E> {
+    exporter: E,
+    config: BatchConfig,
+}
+
+impl<E> ThreadedBatchLogProcessorBuilder<E>
+where
+    E: LogExporter + 'static,
+{
+    /// Set the BatchConfig for [`ThreadedBatchLogProcessorBuilder`]
+    pub fn with_batch_config(self, config: BatchConfig) -> Self {
+        ThreadedBatchLogProcessorBuilder { config, ..self }
+    }
+
+    /// Build a threaded batch processor, spawning its thread.
+    pub fn build(self) -> ThreadedBatchLogProcessor {
+        ThreadedBatchLogProcessor::new(Box::new(self.exporter), self.config)
+    }
+}
+
 #[cfg(all(test, feature = "testing", feature = "logs"))]
 mod tests {
     use super::{
@@ -525,6 +773,7 @@ mod tests {
                 OTEL_BLRP_MAX_QUEUE_SIZE_DEFAULT, OTEL_BLRP_SCHEDULE_DELAY_DEFAULT,
             },
             BatchConfig, BatchConfigBuilder, LogProcessor, LoggerProvider, SimpleLogProcessor,
+            ThreadedBatchLogProcessor,
         },
         runtime,
         testing::logs::InMemoryLogsExporter,
@@ -788,6 +1037,47 @@ mod tests {
         assert_eq!(1, exporter.get_emitted_logs().unwrap().len())
     }
 
+    #[tokio::test]
+    async fn test_threaded_batch_shutdown() {
+        let exporter = InMemoryLogsExporterBuilder::default()
+            .keep_records_on_shutdown()
+            .build();
+        let processor = ThreadedBatchLogProcessor::builder(exporter.clone()).build();
+
+        let mut record: LogRecord = Default::default();
+        let instrumentation: InstrumentationLibrary = Default::default();
+
+        // Flushing and shutting down block the only thread of the runtime,
+        // which must not prevent the export.
+        processor.emit(&mut record, &instrumentation);
+        processor.force_flush().unwrap();
+        assert_eq!(1, exporter.get_emitted_logs().unwrap().len());
+        processor.shutdown().unwrap();
+        assert!(processor.shutdown().is_err());
+
+        processor.emit(&mut record, &instrumentation);
+        assert_eq!(1, exporter.get_emitted_logs().unwrap().len())
+    }
+
+    #[test]
+    fn test_set_resource_threaded_batch_processor() {
+        let exporter = MockLogExporter {
+            resource: Arc::new(Mutex::new(None)),
+        };
+        let processor = ThreadedBatchLogProcessor::builder(exporter.clone()).build();
+        let provider = LoggerProvider::builder()
+            .with_log_processor(processor)
+            .with_resource(Resource::new(vec![
+                KeyValue::new("k1", "v1"),
+                KeyValue::new("k2", "v3"),
+            ]))
+            .build();
+        // The resource is set before the flush is handled by the processor thread.
+        provider.force_flush();
+        assert_eq!(exporter.get_resource().unwrap().into_iter().count(), 2);
+        let _ = provider.shutdown();
+    }
+
     #[test]
     fn test_simple_shutdown() {
         let exporter = InMemoryLogsExporterBuilder::default()
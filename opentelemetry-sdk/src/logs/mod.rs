@@ -1,12 +1,14 @@
 //! # OpenTelemetry Log SDK
+mod baggage_processor;
 mod log_emitter;
 mod log_processor;
 pub(crate) mod record;
 
+pub use baggage_processor::BaggageLogProcessor;
 pub use log_emitter::{Builder, Logger, LoggerProvider};
 pub use log_processor::{
     BatchConfig, BatchConfigBuilder, BatchLogProcessor, BatchLogProcessorBuilder, LogProcessor,
-    SimpleLogProcessor,
+    SimpleLogProcessor, ThreadedBatchLogProcessor, ThreadedBatchLogProcessorBuilder,
 };
 pub use record::{LogRecord, TraceContext};
 
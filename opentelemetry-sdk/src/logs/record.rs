@@ -228,6 +228,23 @@ mod tests {
         assert!(log_record.attributes_contains(&key, &value));
     }
 
+    #[test]
+    fn test_record_exception() {
+        let mut log_record = LogRecord::default();
+        let err = std::io::Error::from(std::io::ErrorKind::Other);
+        log_record.record_exception(&err);
+        assert!(log_record.attributes_contains(
+            &Key::new("exception.type"),
+            &AnyValue::String("std::io::error::Error".into())
+        ));
+        assert!(log_record.attributes_contains(
+            &Key::new("exception.message"),
+            &AnyValue::String(err.to_string().into())
+        ));
+        assert!(log_record
+            .attributes_contains(&Key::new("exception.escaped"), &AnyValue::Boolean(false)));
+    }
+
     #[test]
     fn compare_trace_context() {
         let trace_context = TraceContext {
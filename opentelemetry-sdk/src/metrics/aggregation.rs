@@ -89,6 +89,21 @@ pub enum Aggregation {
         /// `false`
         record_min_max: bool,
     },
+
+    /// An aggregation that summarizes a set of measurements as their count, sum,
+    /// minimum, maximum and estimated values at a set of quantiles.
+    ///
+    /// Quantiles are estimated from a base2 exponential histogram of the
+    /// measurements, which bounds the relative error of each estimate by the
+    /// resolution of the histogram buckets.
+    Summary {
+        /// The quantiles to report, each in the interval `[0.0, 1.0]`.
+        ///
+        /// The `0.0` quantile reports the minimum and the `1.0` quantile the
+        /// maximum value recorded. See [Aggregation::DEFAULT_SUMMARY_QUANTILES]
+        /// for a reasonable default.
+        quantiles: Vec<f64>,
+    },
 }
 
 impl fmt::Display for Aggregation {
@@ -101,6 +116,7 @@ impl fmt::Display for Aggregation {
             Aggregation::LastValue => "LastValue",
             Aggregation::ExplicitBucketHistogram { .. } => "ExplicitBucketHistogram",
             Aggregation::Base2ExponentialHistogram { .. } => "Base2ExponentialHistogram",
+            Aggregation::Summary { .. } => "Summary",
         };
 
         f.write_str(name)
@@ -108,6 +124,10 @@ impl fmt::Display for Aggregation {
 }
 
 impl Aggregation {
+    /// The quantiles reported by a [Aggregation::Summary] when no others are
+    /// needed: min, median, p90, p99 and max.
+    pub const DEFAULT_SUMMARY_QUANTILES: [f64; 5] = [0.0, 0.5, 0.9, 0.99, 1.0];
+
     /// Validate that this aggregation has correct configuration
     pub fn validate(&self) -> Result<()> {
         match self {
@@ -141,6 +161,24 @@ impl Aggregation {
                     )));
                 }
 
+                Ok(())
+            }
+            Aggregation::Summary { quantiles } => {
+                if let Some(q) = quantiles.iter().find(|q| !(0.0..=1.0).contains(*q)) {
+                    return Err(MetricsError::Config(format!(
+                        "aggregation: summary: quantile ({}) is not in the interval [0.0, 1.0]",
+                        q,
+                    )));
+                }
+                for x in quantiles.windows(2) {
+                    if x[0] >= x[1] {
+                        return Err(MetricsError::Config(format!(
+                            "aggregation: summary: non-monotonic quantiles: {:?}",
+                            quantiles,
+                        )));
+                    }
+                }
+
                 Ok(())
             }
         }
@@ -226,6 +264,34 @@ mod tests {
                 },
                 check: config_error.clone(),
             },
+            TestCase {
+                name: "summary with default quantiles",
+                input: Aggregation::Summary {
+                    quantiles: Aggregation::DEFAULT_SUMMARY_QUANTILES.to_vec(),
+                },
+                check: ok.clone(),
+            },
+            TestCase {
+                name: "summary with quantile out of range",
+                input: Aggregation::Summary {
+                    quantiles: vec![0.5, 1.5],
+                },
+                check: config_error.clone(),
+            },
+            TestCase {
+                name: "summary with NaN quantile",
+                input: Aggregation::Summary {
+                    quantiles: vec![f64::NAN],
+                },
+                check: config_error.clone(),
+            },
+            TestCase {
+                name: "summary with non-monotonic quantiles",
+                input: Aggregation::Summary {
+                    quantiles: vec![0.9, 0.5],
+                },
+                check: config_error.clone(),
+            },
         ];
         for test in test_cases {
             assert!((test.check)(test.input.validate()), "{}", test.name)
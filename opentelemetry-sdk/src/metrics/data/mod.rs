@@ -45,7 +45,8 @@ pub struct Metric {
 
 /// The store of data reported by an [Instrument].
 ///
-/// It will be one of: [Gauge], [Sum], or [Histogram].
+/// It will be one of: [Gauge], [Sum], [Histogram], [ExponentialHistogram] or
+/// [Summary].
 ///
 /// [Instrument]: crate::metrics::Instrument
 pub trait Aggregation: fmt::Debug + any::Any + Send + Sync {
@@ -255,8 +256,28 @@ pub struct ExponentialHistogramDataPoint<T> {
     pub exemplars: Vec<Exemplar<T>>,
 }
 
+impl<T: Copy> Clone for ExponentialHistogramDataPoint<T> {
+    fn clone(&self) -> Self {
+        Self {
+            attributes: self.attributes.clone(),
+            start_time: self.start_time,
+            time: self.time,
+            count: self.count,
+            min: self.min,
+            max: self.max,
+            sum: self.sum,
+            scale: self.scale,
+            zero_count: self.zero_count,
+            positive_bucket: self.positive_bucket.clone(),
+            negative_bucket: self.negative_bucket.clone(),
+            zero_threshold: self.zero_threshold,
+            exemplars: self.exemplars.clone(),
+        }
+    }
+}
+
 /// A set of bucket counts, encoded in a contiguous array of counts.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ExponentialBucket {
     /// The bucket index of the first entry in the `counts` vec.
     pub offset: i32,
@@ -268,6 +289,80 @@ pub struct ExponentialBucket {
     pub counts: Vec<u64>,
 }
 
+/// The quantile summary of all measurements of values from an instrument.
+#[derive(Debug)]
+pub struct Summary<T> {
+    /// The individual aggregated measurements with unique attributes.
+    pub data_points: Vec<SummaryDataPoint<T>>,
+
+    /// Describes if the aggregation is reported as the change from the last report
+    /// time, or the cumulative changes since a fixed start time.
+    ///
+    /// OTLP summaries carry no temporality, exporters that cannot represent delta
+    /// summaries should treat each collection as a separate snapshot.
+    pub temporality: Temporality,
+}
+
+impl<T: fmt::Debug + Send + Sync + 'static> Aggregation for Summary<T> {
+    fn as_any(&self) -> &dyn any::Any {
+        self
+    }
+    fn as_mut(&mut self) -> &mut dyn any::Any {
+        self
+    }
+}
+
+/// A single summary data point in a time series.
+#[derive(Debug)]
+pub struct SummaryDataPoint<T> {
+    /// The set of key value pairs that uniquely identify the time series.
+    pub attributes: Vec<KeyValue>,
+    /// When the time series was started.
+    pub start_time: SystemTime,
+    /// The time when the time series was recorded.
+    pub time: SystemTime,
+
+    /// The number of updates this summary has been calculated with.
+    pub count: u64,
+    /// The minimum value recorded.
+    pub min: Option<T>,
+    /// The maximum value recorded.
+    pub max: Option<T>,
+    /// The sum of the values recorded.
+    pub sum: T,
+
+    /// The estimated values at each configured quantile, in increasing quantile
+    /// order.
+    pub quantile_values: Vec<QuantileValue>,
+}
+
+impl<T: Copy> Clone for SummaryDataPoint<T> {
+    fn clone(&self) -> Self {
+        Self {
+            attributes: self.attributes.clone(),
+            start_time: self.start_time,
+            time: self.time,
+            count: self.count,
+            min: self.min,
+            max: self.max,
+            sum: self.sum,
+            quantile_values: self.quantile_values.clone(),
+        }
+    }
+}
+
+/// The value of a distribution at a given quantile.
+///
+/// By convention the `0.0` quantile is the minimum and the `1.0` quantile the
+/// maximum value observed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuantileValue {
+    /// The quantile of the distribution, in the interval `[0.0, 1.0]`.
+    pub quantile: f64,
+    /// The value at the given quantile.
+    pub value: f64,
+}
+
 /// A measurement sampled from a time series providing a typical example.
 #[derive(Debug)]
 pub struct Exemplar<T> {
@@ -2,7 +2,7 @@ use std::{any::Any, borrow::Cow, collections::HashSet, hash::Hash, sync::Arc};
 
 use opentelemetry::{
     metrics::{AsyncInstrument, SyncCounter, SyncGauge, SyncHistogram, SyncUpDownCounter},
-    Key, KeyValue,
+    Context, Key, KeyValue,
 };
 
 use crate::{
@@ -252,35 +252,40 @@ pub(crate) struct ResolvedMeasures<T> {
     pub(crate) measures: Vec<Arc<dyn Measure<T>>>,
 }
 
-impl<T: Copy + 'static> SyncCounter<T> for ResolvedMeasures<T> {
-    fn add(&self, val: T, attrs: &[KeyValue]) {
+impl<T: Copy + 'static> ResolvedMeasures<T> {
+    fn measure(&self, val: T, attrs: &[KeyValue]) {
+        // measurements made while telemetry is suppressed, e.g. by exporters,
+        // are ignored
+        if Context::is_current_telemetry_suppressed() {
+            return;
+        }
         for measure in &self.measures {
             measure.call(val, attrs)
         }
     }
 }
 
+impl<T: Copy + 'static> SyncCounter<T> for ResolvedMeasures<T> {
+    fn add(&self, val: T, attrs: &[KeyValue]) {
+        self.measure(val, attrs)
+    }
+}
+
 impl<T: Copy + 'static> SyncUpDownCounter<T> for ResolvedMeasures<T> {
     fn add(&self, val: T, attrs: &[KeyValue]) {
-        for measure in &self.measures {
-            measure.call(val, attrs)
-        }
+        self.measure(val, attrs)
     }
 }
 
 impl<T: Copy + 'static> SyncGauge<T> for ResolvedMeasures<T> {
     fn record(&self, val: T, attrs: &[KeyValue]) {
-        for measure in &self.measures {
-            measure.call(val, attrs)
-        }
+        self.measure(val, attrs)
     }
 }
 
 impl<T: Copy + 'static> SyncHistogram<T> for ResolvedMeasures<T> {
     fn record(&self, val: T, attrs: &[KeyValue]) {
-        for measure in &self.measures {
-            measure.call(val, attrs)
-        }
+        self.measure(val, attrs)
     }
 }
 
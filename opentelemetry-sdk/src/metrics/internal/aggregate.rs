@@ -6,7 +6,7 @@ use crate::metrics::data::{Aggregation, Gauge, Temporality};
 
 use super::{
     exponential_histogram::ExpoHistogram, histogram::Histogram, last_value::LastValue,
-    precomputed_sum::PrecomputedSum, sum::Sum, Number,
+    precomputed_sum::PrecomputedSum, sum::Sum, summary::Summary, Number,
 };
 
 const STREAM_CARDINALITY_LIMIT: u32 = 2000;
@@ -203,6 +203,25 @@ impl<T: Number<T>> AggregateBuilder<T> {
             },
         )
     }
+
+    /// Builds a summary aggregate function input and output.
+    pub(crate) fn summary(
+        &self,
+        quantiles: Vec<f64>,
+        record_sum: bool,
+    ) -> (impl Measure<T>, impl ComputeAggregation) {
+        let s = Arc::new(Summary::new(quantiles, record_sum));
+        let agg_s = Arc::clone(&s);
+        let t = self.temporality;
+
+        (
+            self.filter(move |n, a: &[KeyValue]| s.measure(n, a)),
+            move |dest: Option<&mut dyn Aggregation>| match t {
+                Some(Temporality::Delta) => agg_s.delta(dest),
+                _ => agg_s.cumulative(dest),
+            },
+        )
+    }
 }
 
 #[cfg(test)]
@@ -4,6 +4,7 @@ mod histogram;
 mod last_value;
 mod precomputed_sum;
 mod sum;
+mod summary;
 
 use core::fmt;
 use std::collections::HashMap;
@@ -135,8 +136,12 @@ impl<AU: AtomicallyUpdate<T>, T: Number<T>, O: Operation> ValueMap<AU, T, O> {
             let new_tracker = Arc::new(AU::new_atomic_tracker(self.buckets_count));
             O::update_tracker(&*new_tracker, measurement, index);
 
-            // Insert tracker with the attributes in the provided and sorted orders
-            trackers.insert(attributes.to_vec(), new_tracker.clone());
+            // Insert tracker with the attributes in the provided and sorted orders.
+            // Attributes with duplicate keys are only inserted deduplicated, so
+            // that collected data points never contain a key twice.
+            if attributes.len() == sorted_attrs.len() {
+                trackers.insert(attributes.to_vec(), new_tracker.clone());
+            }
             trackers.insert(sorted_attrs, new_tracker);
 
             self.count.fetch_add(1, Ordering::SeqCst);
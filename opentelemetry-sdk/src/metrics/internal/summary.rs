@@ -0,0 +1,257 @@
+use opentelemetry::KeyValue;
+
+use crate::metrics::data::{self, Aggregation, QuantileValue, Temporality};
+
+use super::{exponential_histogram::ExpoHistogram, Number, EXPO_MAX_SCALE};
+
+/// The maximum number of buckets used to estimate quantiles.
+const SUMMARY_MAX_SIZE: u32 = 160;
+
+/// Summarizes a set of measurements as their count, sum, min, max and a set of
+/// estimated quantiles.
+///
+/// Measurements are recorded in a base2 exponential histogram, quantiles are
+/// interpolated from its buckets at collection time.
+pub(crate) struct Summary<T> {
+    histogram: ExpoHistogram<T>,
+    quantiles: Vec<f64>,
+}
+
+impl<T: Number<T>> Summary<T> {
+    pub(crate) fn new(quantiles: Vec<f64>, record_sum: bool) -> Self {
+        Summary {
+            histogram: ExpoHistogram::new(SUMMARY_MAX_SIZE, EXPO_MAX_SCALE, true, record_sum),
+            quantiles,
+        }
+    }
+
+    pub(crate) fn measure(&self, measurement: T, attrs: &[KeyValue]) {
+        self.histogram.measure(measurement, attrs)
+    }
+
+    pub(crate) fn delta(
+        &self,
+        dest: Option<&mut dyn Aggregation>,
+    ) -> (usize, Option<Box<dyn Aggregation>>) {
+        let mut hist = data::ExponentialHistogram {
+            data_points: vec![],
+            temporality: Temporality::Delta,
+        };
+        self.histogram.delta(Some(&mut hist));
+        self.summarize(hist, dest)
+    }
+
+    pub(crate) fn cumulative(
+        &self,
+        dest: Option<&mut dyn Aggregation>,
+    ) -> (usize, Option<Box<dyn Aggregation>>) {
+        let mut hist = data::ExponentialHistogram {
+            data_points: vec![],
+            temporality: Temporality::Cumulative,
+        };
+        self.histogram.cumulative(Some(&mut hist));
+        self.summarize(hist, dest)
+    }
+
+    fn summarize(
+        &self,
+        hist: data::ExponentialHistogram<T>,
+        dest: Option<&mut dyn Aggregation>,
+    ) -> (usize, Option<Box<dyn Aggregation>>) {
+        let s = dest.and_then(|d| d.as_mut().downcast_mut::<data::Summary<T>>());
+        let mut new_agg = if s.is_none() {
+            Some(data::Summary {
+                data_points: vec![],
+                temporality: hist.temporality,
+            })
+        } else {
+            None
+        };
+        let s = s.unwrap_or_else(|| new_agg.as_mut().expect("present if s is none"));
+        s.temporality = hist.temporality;
+        s.data_points.clear();
+
+        let n = hist.data_points.len();
+        if n > s.data_points.capacity() {
+            s.data_points.reserve_exact(n - s.data_points.capacity());
+        }
+
+        for dp in hist.data_points {
+            let quantile_values = self
+                .quantiles
+                .iter()
+                .map(|&q| QuantileValue {
+                    quantile: q,
+                    value: quantile(&dp, q),
+                })
+                .collect();
+
+            s.data_points.push(data::SummaryDataPoint {
+                attributes: dp.attributes,
+                start_time: dp.start_time,
+                time: dp.time,
+                count: dp.count as u64,
+                min: dp.min,
+                max: dp.max,
+                sum: dp.sum,
+                quantile_values,
+            });
+        }
+
+        (n, new_agg.map(|a| Box::new(a) as Box<_>))
+    }
+}
+
+/// Estimates the value at quantile `q` of the distribution in `dp`.
+///
+/// The bucket holding the target rank is located by walking the negative
+/// buckets from the most negative, then the zero bucket, then the positive
+/// buckets. The estimate is linearly interpolated within that bucket and
+/// clamped to the recorded min and max.
+fn quantile<T: Number<T>>(dp: &data::ExponentialHistogramDataPoint<T>, q: f64) -> f64 {
+    let min = dp.min.map(Number::into_float);
+    let max = dp.max.map(Number::into_float);
+    if dp.count == 0 {
+        return 0.0;
+    }
+    match (q, min, max) {
+        (q, Some(min), _) if q <= 0.0 => return min,
+        (q, _, Some(max)) if q >= 1.0 => return max,
+        _ => {}
+    }
+
+    let rank = (q * dp.count as f64).max(1.0);
+    let base = 2f64.powf(2f64.powi(-(dp.scale as i32)));
+    // Returns the bucket bounds and how far into the bucket `rank` falls.
+    let locate = |index: i32, seen: u64, count: u64| {
+        let lower = base.powi(index);
+        let fraction = (rank - seen as f64) / count as f64;
+        (lower, lower * base, fraction)
+    };
+
+    let mut seen = 0;
+    let mut estimate = None;
+    let neg = &dp.negative_bucket;
+    for (i, &count) in neg.counts.iter().enumerate().rev() {
+        if (seen + count) as f64 >= rank {
+            let (lower, upper, fraction) = locate(neg.offset + i as i32, seen, count);
+            estimate = Some(-upper + (upper - lower) * fraction);
+            break;
+        }
+        seen += count;
+    }
+    if estimate.is_none() {
+        seen += dp.zero_count;
+        if seen as f64 >= rank {
+            estimate = Some(0.0);
+        }
+    }
+    if estimate.is_none() {
+        let pos = &dp.positive_bucket;
+        for (i, &count) in pos.counts.iter().enumerate() {
+            if (seen + count) as f64 >= rank {
+                let (lower, upper, fraction) = locate(pos.offset + i as i32, seen, count);
+                estimate = Some(lower + (upper - lower) * fraction);
+                break;
+            }
+            seen += count;
+        }
+    }
+
+    let mut value = estimate.or(max).unwrap_or_default();
+    if let Some(min) = min {
+        value = value.max(min);
+    }
+    if let Some(max) = max {
+        value = value.min(max);
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metrics::data;
+
+    use super::*;
+
+    fn assert_within(actual: f64, expected: f64, relative_error: f64) {
+        assert!(
+            (actual - expected).abs() <= expected.abs() * relative_error,
+            "expected {expected} +/- {}%, got {actual}",
+            relative_error * 100.0
+        );
+    }
+
+    #[test]
+    fn summary_quantiles() {
+        let summary = Summary::<u64>::new(vec![0.0, 0.5, 0.9, 0.99, 1.0], true);
+        let attrs = [KeyValue::new("a", 1)];
+        for i in 1..=1000 {
+            summary.measure(i, &attrs);
+        }
+
+        let (count, agg) = summary.cumulative(None);
+        assert_eq!(count, 1);
+        let agg = agg.unwrap();
+        let s = agg.as_any().downcast_ref::<data::Summary<u64>>().unwrap();
+        assert_eq!(s.temporality, Temporality::Cumulative);
+
+        let dp = &s.data_points[0];
+        assert_eq!(dp.attributes, attrs.to_vec());
+        assert_eq!(dp.count, 1000);
+        assert_eq!(dp.sum, 500500);
+        assert_eq!(dp.min, Some(1));
+        assert_eq!(dp.max, Some(1000));
+
+        let values: Vec<f64> = dp.quantile_values.iter().map(|q| q.value).collect();
+        assert_eq!(values[0], 1.0);
+        assert_within(values[1], 500.0, 0.02);
+        assert_within(values[2], 900.0, 0.02);
+        assert_within(values[3], 990.0, 0.02);
+        assert_eq!(values[4], 1000.0);
+    }
+
+    #[test]
+    fn summary_negative_and_zero_values() {
+        let summary = Summary::<f64>::new(vec![0.0, 0.25, 0.5, 0.75, 1.0], true);
+        for v in [-100.0, -10.0, 0.0, 10.0, 100.0] {
+            summary.measure(v, &[]);
+        }
+
+        let (_, agg) = summary.delta(None);
+        let agg = agg.unwrap();
+        let s = agg.as_any().downcast_ref::<data::Summary<f64>>().unwrap();
+        assert_eq!(s.temporality, Temporality::Delta);
+
+        let values: Vec<f64> = s.data_points[0]
+            .quantile_values
+            .iter()
+            .map(|q| q.value)
+            .collect();
+        assert_eq!(values[0], -100.0);
+        assert_within(values[1], -10.0, 0.02);
+        assert_eq!(values[2], 0.0);
+        assert_within(values[3], 10.0, 0.02);
+        assert_eq!(values[4], 100.0);
+    }
+
+    #[test]
+    fn summary_delta_resets() {
+        let summary = Summary::<i64>::new(vec![0.5], true);
+        summary.measure(7, &[]);
+
+        let mut s = data::Summary::<i64> {
+            data_points: vec![],
+            temporality: Temporality::Cumulative,
+        };
+        let (count, new_agg) = summary.delta(Some(&mut s));
+        assert_eq!(count, 1);
+        assert!(new_agg.is_none());
+        assert_eq!(s.temporality, Temporality::Delta);
+        assert_eq!(s.data_points[0].quantile_values[0].value, 7.0);
+
+        let (count, _) = summary.delta(Some(&mut s));
+        assert_eq!(count, 0);
+        assert!(s.data_points.is_empty());
+    }
+}
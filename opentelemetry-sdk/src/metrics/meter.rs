@@ -396,6 +396,18 @@ impl InstrumentProvider for SdkMeter {
         p.lookup(InstrumentKind::Histogram, name, description, unit)
             .map(|i| Histogram::new(Arc::new(i)))
     }
+
+    fn i64_histogram(
+        &self,
+        name: Cow<'static, str>,
+        description: Option<Cow<'static, str>>,
+        unit: Option<Cow<'static, str>>,
+    ) -> Result<Histogram<i64>> {
+        validate_instrument_config(name.as_ref(), &unit, self.validation_policy)?;
+        let p = InstrumentResolver::new(self, &self.i64_resolver);
+        p.lookup(InstrumentKind::Histogram, name, description, unit)
+            .map(|i| Histogram::new(Arc::new(i)))
+    }
 }
 
 /// Validation policy for instrument
@@ -631,6 +643,7 @@ mod tests {
             );
             assert(meter.f64_histogram(name.into(), None, None).map(|_| ()));
             assert(meter.u64_histogram(name.into(), None, None).map(|_| ()));
+            assert(meter.i64_histogram(name.into(), None, None).map(|_| ()));
         }
 
         // (unit, expected error)
@@ -723,6 +736,11 @@ mod tests {
                     .u64_histogram("test".into(), None, unit.clone())
                     .map(|_| ()),
             );
+            assert(
+                meter
+                    .i64_histogram("test".into(), None, unit.clone())
+                    .map(|_| ()),
+            );
         }
     }
 }
@@ -206,6 +206,50 @@ mod tests {
         assert_eq!(data_point.value, 50, "Unexpected data point value");
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn counter_aggregation_suppressed_telemetry() {
+        let mut test_context = TestContext::new(Temporality::Cumulative);
+        let counter = test_context.u64_counter("test", "my_counter", None);
+
+        counter.add(50, &[]);
+        {
+            let _guard = opentelemetry::Context::enter_telemetry_suppressed();
+            counter.add(100, &[]);
+        }
+        test_context.flush_metrics();
+
+        let sum = test_context.get_aggregation::<data::Sum<u64>>("my_counter", None);
+        assert_eq!(sum.data_points.len(), 1, "Expected only one data point");
+        assert_eq!(
+            sum.data_points[0].value, 50,
+            "Suppressed measurements must be ignored"
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn counter_aggregation_duplicate_attribute_keys() {
+        let mut test_context = TestContext::new(Temporality::Cumulative);
+        let counter = test_context.u64_counter("test", "my_counter", None);
+
+        let attributes = [
+            KeyValue::new("A", "x"),
+            KeyValue::new("A", "q"),
+            KeyValue::new("B", "b"),
+        ];
+        counter.add(1, &attributes);
+        counter.add(2, &attributes);
+        test_context.flush_metrics();
+
+        let sum = test_context.get_aggregation::<data::Sum<u64>>("my_counter", None);
+        assert_eq!(sum.data_points.len(), 1, "Expected only one data point");
+        assert_eq!(sum.data_points[0].value, 3);
+        assert_eq!(
+            sum.data_points[0].attributes,
+            vec![KeyValue::new("A", "q"), KeyValue::new("B", "b")],
+            "Duplicate keys must keep the last value"
+        );
+    }
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
     async fn counter_aggregation_overflow_delta() {
         counter_aggregation_overflow_helper(Temporality::Delta);
@@ -260,6 +304,70 @@ mod tests {
         histogram_aggregation_helper(Temporality::Delta);
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn i64_histogram_aggregation_cumulative() {
+        // Run this test with stdout enabled to see output.
+        // cargo test i64_histogram_aggregation_cumulative --features=testing -- --nocapture
+        i64_histogram_aggregation_helper(Temporality::Cumulative);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn i64_histogram_aggregation_delta() {
+        // Run this test with stdout enabled to see output.
+        // cargo test i64_histogram_aggregation_delta --features=testing -- --nocapture
+        i64_histogram_aggregation_helper(Temporality::Delta);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn i64_histogram_exponential_aggregation() {
+        // Run this test with stdout enabled to see output.
+        // cargo test i64_histogram_exponential_aggregation --features=testing -- --nocapture
+
+        // Arrange
+        let exporter = InMemoryMetricsExporter::default();
+        let reader = PeriodicReader::builder(exporter.clone(), runtime::Tokio).build();
+        let view = new_view(
+            Instrument::new().name("test_histogram"),
+            Stream::new().aggregation(Aggregation::Base2ExponentialHistogram {
+                max_size: 160,
+                max_scale: 20,
+                record_min_max: true,
+            }),
+        )
+        .expect("Expected to create a new view");
+        let meter_provider = SdkMeterProvider::builder()
+            .with_reader(reader)
+            .with_view(view)
+            .build();
+
+        // Act
+        let meter = meter_provider.meter("test");
+        let histogram = meter.i64_histogram("test_histogram").init();
+        for v in [-8, -4, 0, 4] {
+            histogram.record(v, &[]);
+        }
+        meter_provider.force_flush().unwrap();
+
+        // Assert
+        let resource_metrics = exporter
+            .get_finished_metrics()
+            .expect("metrics are expected to be exported.");
+        let metric = &resource_metrics[0].scope_metrics[0].metrics[0];
+        let histogram = metric
+            .data
+            .as_any()
+            .downcast_ref::<data::ExponentialHistogram<i64>>()
+            .expect("Exponential histogram aggregation expected for i64 histogram");
+        let data_point = &histogram.data_points[0];
+        assert_eq!(data_point.count, 4);
+        assert_eq!(data_point.sum, -8);
+        assert_eq!(data_point.min, Some(-8));
+        assert_eq!(data_point.max, Some(4));
+        assert_eq!(data_point.zero_count, 1);
+        assert_eq!(data_point.negative_bucket.counts.iter().sum::<u64>(), 2);
+        assert_eq!(data_point.positive_bucket.counts.iter().sum::<u64>(), 1);
+    }
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
     async fn updown_counter_aggregation_cumulative() {
         // Run this test with stdout enabled to see output.
@@ -695,6 +803,60 @@ mod tests {
         );
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn histogram_aggregation_with_summary_view() {
+        // Run this test with stdout enabled to see output.
+        // cargo test histogram_aggregation_with_summary_view --features=testing -- --nocapture
+
+        // Arrange
+        let exporter = InMemoryMetricsExporter::default();
+        let reader = PeriodicReader::builder(exporter.clone(), runtime::Tokio).build();
+        let criteria = Instrument::new().name("test_histogram");
+        let stream = Stream::new().aggregation(Aggregation::Summary {
+            quantiles: Aggregation::DEFAULT_SUMMARY_QUANTILES.to_vec(),
+        });
+        let view = new_view(criteria, stream).expect("Expected to create a new view");
+        let meter_provider = SdkMeterProvider::builder()
+            .with_reader(reader)
+            .with_view(view)
+            .build();
+
+        // Act
+        let meter = meter_provider.meter("test");
+        let histogram = meter.u64_histogram("test_histogram").init();
+        for v in 1..=100 {
+            histogram.record(v, &[KeyValue::new("key1", "value1")]);
+        }
+        meter_provider.force_flush().unwrap();
+
+        // Assert
+        let resource_metrics = exporter
+            .get_finished_metrics()
+            .expect("metrics are expected to be exported.");
+        let metric = &resource_metrics[0].scope_metrics[0].metrics[0];
+        let summary = metric
+            .data
+            .as_any()
+            .downcast_ref::<data::Summary<u64>>()
+            .expect("Summary aggregation expected for histogram with summary view");
+        assert_eq!(summary.data_points.len(), 1);
+
+        let data_point = &summary.data_points[0];
+        assert_eq!(data_point.count, 100);
+        assert_eq!(data_point.sum, 5050);
+        assert_eq!(data_point.min, Some(1));
+        assert_eq!(data_point.max, Some(100));
+        let quantiles: Vec<f64> = data_point
+            .quantile_values
+            .iter()
+            .map(|q| q.quantile)
+            .collect();
+        assert_eq!(quantiles, Aggregation::DEFAULT_SUMMARY_QUANTILES.to_vec());
+        assert_eq!(data_point.quantile_values[0].value, 1.0);
+        assert!((data_point.quantile_values[1].value - 50.0).abs() < 1.0);
+        assert_eq!(data_point.quantile_values[4].value, 100.0);
+    }
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
     #[ignore = "Spatial aggregation is not yet implemented."]
     async fn spatial_aggregation_when_view_drops_attributes_observable_counter() {
@@ -1791,6 +1953,42 @@ mod tests {
         }
     }
 
+    fn i64_histogram_aggregation_helper(temporality: Temporality) {
+        // Arrange
+        let mut test_context = TestContext::new(temporality);
+        let histogram = test_context.meter().i64_histogram("my_histogram").init();
+
+        // Act
+        let mut rand = rngs::SmallRng::from_entropy();
+        let values = (0..50)
+            .map(|_| rand.gen_range(-100..100))
+            .collect::<Vec<i64>>();
+        for value in values.iter() {
+            histogram.record(*value, &[KeyValue::new("key1", "value1")]);
+        }
+
+        test_context.flush_metrics();
+
+        // Assert
+        let histogram_data =
+            test_context.get_aggregation::<data::Histogram<i64>>("my_histogram", None);
+        assert_eq!(histogram_data.data_points.len(), 1);
+        assert_eq!(histogram_data.temporality, temporality);
+
+        let data_point =
+            find_histogram_datapoint_with_key_value(&histogram_data.data_points, "key1", "value1")
+                .expect("datapoint with key1=value1 expected");
+        assert_eq!(data_point.count, values.len() as u64);
+        assert_eq!(data_point.sum, values.iter().sum::<i64>());
+        assert_eq!(data_point.min.unwrap(), *values.iter().min().unwrap());
+        assert_eq!(data_point.max.unwrap(), *values.iter().max().unwrap());
+        // The first default bucket is (-∞, 0].
+        assert_eq!(
+            data_point.bucket_counts[0],
+            values.iter().filter(|v| **v <= 0).count() as u64
+        );
+    }
+
     fn gauge_aggregation_helper(temporality: Temporality) {
         // Arrange
         let mut test_context = TestContext::new(temporality);
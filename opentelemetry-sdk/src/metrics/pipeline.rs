@@ -504,6 +504,15 @@ fn aggregate_fn<T: Number<T>>(
                 record_sum,
             ))))
         }
+        Aggregation::Summary { quantiles } => {
+            let record_sum = !matches!(
+                kind,
+                InstrumentKind::UpDownCounter
+                    | InstrumentKind::ObservableUpDownCounter
+                    | InstrumentKind::ObservableGauge
+            );
+            Ok(Some(box_val(b.summary(quantiles.to_vec(), record_sum))))
+        }
     }
 }
 
@@ -511,21 +520,22 @@ fn aggregate_fn<T: Number<T>>(
 ///
 /// Current compatibility:
 ///
-/// | Instrument Kind          | Drop | LastValue | Sum | Histogram | Exponential Histogram |
-/// |--------------------------|------|-----------|-----|-----------|-----------------------|
-/// | Counter                  | ✓    |           | ✓   | ✓         | ✓                     |
-/// | UpDownCounter            | ✓    |           | ✓   | ✓         | ✓                     |
-/// | Histogram                | ✓    |           | ✓   | ✓         | ✓                     |
-/// | Observable Counter       | ✓    |           | ✓   | ✓         | ✓                     |
-/// | Observable UpDownCounter | ✓    |           | ✓   | ✓         | ✓                     |
-/// | Gauge                    | ✓    | ✓         |     | ✓         | ✓                     |
-/// | Observable Gauge         | ✓    | ✓         |     | ✓         | ✓                     |
+/// | Instrument Kind          | Drop | LastValue | Sum | Histogram | Exponential Histogram | Summary |
+/// |--------------------------|------|-----------|-----|-----------|-----------------------|---------|
+/// | Counter                  | ✓    |           | ✓   | ✓         | ✓                     | ✓       |
+/// | UpDownCounter            | ✓    |           | ✓   | ✓         | ✓                     | ✓       |
+/// | Histogram                | ✓    |           | ✓   | ✓         | ✓                     | ✓       |
+/// | Observable Counter       | ✓    |           | ✓   | ✓         | ✓                     | ✓       |
+/// | Observable UpDownCounter | ✓    |           | ✓   | ✓         | ✓                     | ✓       |
+/// | Gauge                    | ✓    | ✓         |     | ✓         | ✓                     | ✓       |
+/// | Observable Gauge         | ✓    | ✓         |     | ✓         | ✓                     | ✓       |
 fn is_aggregator_compatible(kind: &InstrumentKind, agg: &aggregation::Aggregation) -> Result<()> {
     use aggregation::Aggregation;
     match agg {
         Aggregation::Default => Ok(()),
         Aggregation::ExplicitBucketHistogram { .. }
-        | Aggregation::Base2ExponentialHistogram { .. } => {
+        | Aggregation::Base2ExponentialHistogram { .. }
+        | Aggregation::Summary { .. } => {
             if matches!(
                 kind,
                 InstrumentKind::Counter
@@ -233,6 +233,30 @@ impl InMemoryMetricsExporter {
                 data_points: hist.data_points.clone(),
                 temporality: hist.temporality,
             }))
+        } else if let Some(hist) = data
+            .as_any()
+            .downcast_ref::<data::ExponentialHistogram<i64>>()
+        {
+            Some(Box::new(data::ExponentialHistogram {
+                data_points: hist.data_points.clone(),
+                temporality: hist.temporality,
+            }))
+        } else if let Some(hist) = data
+            .as_any()
+            .downcast_ref::<data::ExponentialHistogram<f64>>()
+        {
+            Some(Box::new(data::ExponentialHistogram {
+                data_points: hist.data_points.clone(),
+                temporality: hist.temporality,
+            }))
+        } else if let Some(hist) = data
+            .as_any()
+            .downcast_ref::<data::ExponentialHistogram<u64>>()
+        {
+            Some(Box::new(data::ExponentialHistogram {
+                data_points: hist.data_points.clone(),
+                temporality: hist.temporality,
+            }))
         } else if let Some(sum) = data.as_any().downcast_ref::<data::Sum<i64>>() {
             Some(Box::new(data::Sum {
                 data_points: sum.data_points.clone(),
@@ -263,6 +287,21 @@ impl InMemoryMetricsExporter {
             Some(Box::new(data::Gauge {
                 data_points: gauge.data_points.clone(),
             }))
+        } else if let Some(summary) = data.as_any().downcast_ref::<data::Summary<i64>>() {
+            Some(Box::new(data::Summary {
+                data_points: summary.data_points.clone(),
+                temporality: summary.temporality,
+            }))
+        } else if let Some(summary) = data.as_any().downcast_ref::<data::Summary<f64>>() {
+            Some(Box::new(data::Summary {
+                data_points: summary.data_points.clone(),
+                temporality: summary.temporality,
+            }))
+        } else if let Some(summary) = data.as_any().downcast_ref::<data::Summary<u64>>() {
+            Some(Box::new(data::Summary {
+                data_points: summary.data_points.clone(),
+                temporality: summary.temporality,
+            }))
         } else {
             // unknown data type
             None
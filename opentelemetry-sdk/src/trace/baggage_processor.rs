@@ -0,0 +1,114 @@
+use crate::export::trace::SpanData;
+use crate::trace::{Span, SpanProcessor};
+use opentelemetry::baggage::BaggageExt;
+use opentelemetry::trace::{Span as _, TraceResult};
+use opentelemetry::{Context, Key, KeyValue};
+use std::fmt;
+
+/// A [`SpanProcessor`] copying baggage entries of the parent context onto
+/// spans as attributes when they start.
+///
+/// Baggage set by upstream services, e.g. a tenant id or user tier propagated
+/// by the [`BaggagePropagator`], then shows up on every span of the service.
+/// Baggage is sent along with requests to downstream services, so only keys
+/// selected by the predicate are copied to avoid recording sensitive entries.
+///
+/// # Examples
+///
+/// ```
+/// use opentelemetry_sdk::trace::{BaggageSpanProcessor, TracerProvider};
+///
+/// let processor = BaggageSpanProcessor::new(|key| key.as_str().starts_with("app."));
+/// let provider = TracerProvider::builder()
+///     .with_span_processor(processor)
+///     .build();
+/// ```
+///
+/// [`BaggagePropagator`]: crate::propagation::BaggagePropagator
+pub struct BaggageSpanProcessor {
+    predicate: Box<dyn Fn(&Key) -> bool + Send + Sync>,
+}
+
+impl BaggageSpanProcessor {
+    /// Create a processor copying the baggage entries whose key matches
+    /// `predicate`.
+    pub fn new<P>(predicate: P) -> Self
+    where
+        P: Fn(&Key) -> bool + Send + Sync + 'static,
+    {
+        BaggageSpanProcessor {
+            predicate: Box::new(predicate),
+        }
+    }
+
+    /// Create a processor copying all baggage entries.
+    pub fn all_keys() -> Self {
+        BaggageSpanProcessor::new(|_| true)
+    }
+}
+
+impl fmt::Debug for BaggageSpanProcessor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BaggageSpanProcessor")
+            .finish_non_exhaustive()
+    }
+}
+
+impl SpanProcessor for BaggageSpanProcessor {
+    fn on_start(&self, span: &mut Span, cx: &Context) {
+        for (key, (value, _metadata)) in cx.baggage() {
+            if (self.predicate)(key) {
+                span.set_attribute(KeyValue::new(key.clone(), value.clone()));
+            }
+        }
+    }
+
+    fn on_end(&self, _span: SpanData) {
+        // Ignored
+    }
+
+    fn force_flush(&self) -> TraceResult<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> TraceResult<()> {
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use super::*;
+    use crate::testing::trace::InMemorySpanExporterBuilder;
+    use crate::trace::TracerProvider;
+    use opentelemetry::trace::{Tracer, TracerProvider as _};
+
+    #[test]
+    fn copies_selected_baggage() {
+        let exporter = InMemorySpanExporterBuilder::new().build();
+        let provider = TracerProvider::builder()
+            .with_span_processor(BaggageSpanProcessor::new(|key| {
+                key.as_str() != "session.token"
+            }))
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let tracer = provider.tracer("test");
+
+        let cx = Context::new().with_baggage([
+            KeyValue::new("tenant.id", "acme"),
+            KeyValue::new("session.token", "secret"),
+        ]);
+        tracer.start_with_context("in baggage", &cx).end();
+        tracer
+            .start_with_context("without baggage", &Context::new())
+            .end();
+
+        let spans = exporter.get_finished_spans().unwrap();
+        assert_eq!(spans.len(), 2);
+        assert_eq!(
+            spans[0].attributes,
+            vec![KeyValue::new("tenant.id", "acme")]
+        );
+        assert!(spans[1].attributes.is_empty());
+    }
+}
@@ -75,6 +75,12 @@ impl Config {
         self
     }
 
+    /// Specify the max length of string attribute values, longer values are truncated.
+    pub fn with_max_attribute_value_length(mut self, max_length: u32) -> Self {
+        self.span_limits.max_attribute_value_length = Some(max_length);
+        self
+    }
+
     /// Specify all limit via the span_limits
     pub fn with_span_limits(mut self, span_limits: SpanLimits) -> Self {
         self.span_limits = span_limits;
@@ -98,27 +104,36 @@ impl Default for Config {
             resource: Cow::Owned(Resource::default()),
         };
 
-        if let Some(max_attributes_per_span) = env::var("OTEL_SPAN_ATTRIBUTE_COUNT_LIMIT")
-            .ok()
-            .and_then(|count_limit| u32::from_str(&count_limit).ok())
-        {
+        if let Some(max_attributes_per_span) = env_limit(&[
+            "OTEL_SPAN_ATTRIBUTE_COUNT_LIMIT",
+            "OTEL_ATTRIBUTE_COUNT_LIMIT",
+        ]) {
             config.span_limits.max_attributes_per_span = max_attributes_per_span;
         }
 
-        if let Some(max_events_per_span) = env::var("OTEL_SPAN_EVENT_COUNT_LIMIT")
-            .ok()
-            .and_then(|max_events| u32::from_str(&max_events).ok())
-        {
+        if let Some(max_events_per_span) = env_limit(&["OTEL_SPAN_EVENT_COUNT_LIMIT"]) {
             config.span_limits.max_events_per_span = max_events_per_span;
         }
 
-        if let Some(max_links_per_span) = env::var("OTEL_SPAN_LINK_COUNT_LIMIT")
-            .ok()
-            .and_then(|max_links| u32::from_str(&max_links).ok())
-        {
+        if let Some(max_links_per_span) = env_limit(&["OTEL_SPAN_LINK_COUNT_LIMIT"]) {
             config.span_limits.max_links_per_span = max_links_per_span;
         }
 
+        if let Some(max_attributes_per_event) = env_limit(&["OTEL_EVENT_ATTRIBUTE_COUNT_LIMIT"]) {
+            config.span_limits.max_attributes_per_event = max_attributes_per_event;
+        }
+
+        if let Some(max_attributes_per_link) = env_limit(&["OTEL_LINK_ATTRIBUTE_COUNT_LIMIT"]) {
+            config.span_limits.max_attributes_per_link = max_attributes_per_link;
+        }
+
+        if let Some(max_attribute_value_length) = env_limit(&[
+            "OTEL_SPAN_ATTRIBUTE_VALUE_LENGTH_LIMIT",
+            "OTEL_ATTRIBUTE_VALUE_LENGTH_LIMIT",
+        ]) {
+            config.span_limits.max_attribute_value_length = Some(max_attribute_value_length);
+        }
+
         let sampler_arg = env::var("OTEL_TRACES_SAMPLER_ARG").ok();
         if let Ok(sampler) = env::var("OTEL_TRACES_SAMPLER") {
             config.sampler = match sampler.as_str() {
@@ -188,3 +203,69 @@ impl Default for Config {
         config
     }
 }
+
+/// Reads a limit from the first of `names` that is set to a valid value, the span specific
+/// variable takes precedence over the general one.
+fn env_limit(names: &[&str]) -> Option<u32> {
+    names.iter().find_map(|name| {
+        env::var(name)
+            .ok()
+            .and_then(|limit| u32::from_str(limit.trim()).ok())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMIT_VARS: [&str; 8] = [
+        "OTEL_SPAN_ATTRIBUTE_COUNT_LIMIT",
+        "OTEL_ATTRIBUTE_COUNT_LIMIT",
+        "OTEL_SPAN_EVENT_COUNT_LIMIT",
+        "OTEL_SPAN_LINK_COUNT_LIMIT",
+        "OTEL_EVENT_ATTRIBUTE_COUNT_LIMIT",
+        "OTEL_LINK_ATTRIBUTE_COUNT_LIMIT",
+        "OTEL_SPAN_ATTRIBUTE_VALUE_LENGTH_LIMIT",
+        "OTEL_ATTRIBUTE_VALUE_LENGTH_LIMIT",
+    ];
+
+    #[test]
+    fn span_limits_default() {
+        let config = temp_env::with_vars_unset(LIMIT_VARS, Config::default);
+        assert_eq!(config.span_limits.max_attributes_per_span, 128);
+        assert_eq!(config.span_limits.max_attribute_value_length, None);
+    }
+
+    #[test]
+    fn span_limits_from_env() {
+        let config = temp_env::with_vars(
+            [
+                ("OTEL_SPAN_ATTRIBUTE_COUNT_LIMIT", Some("10")),
+                ("OTEL_ATTRIBUTE_COUNT_LIMIT", Some("20")),
+                ("OTEL_SPAN_EVENT_COUNT_LIMIT", Some("11")),
+                ("OTEL_SPAN_LINK_COUNT_LIMIT", Some("12")),
+                ("OTEL_EVENT_ATTRIBUTE_COUNT_LIMIT", Some("13")),
+                ("OTEL_LINK_ATTRIBUTE_COUNT_LIMIT", Some("14")),
+                ("OTEL_SPAN_ATTRIBUTE_VALUE_LENGTH_LIMIT", Some("invalid")),
+                ("OTEL_ATTRIBUTE_VALUE_LENGTH_LIMIT", Some("256")),
+            ],
+            Config::default,
+        );
+        let limits = config.span_limits;
+        assert_eq!(limits.max_attributes_per_span, 10);
+        assert_eq!(limits.max_events_per_span, 11);
+        assert_eq!(limits.max_links_per_span, 12);
+        assert_eq!(limits.max_attributes_per_event, 13);
+        assert_eq!(limits.max_attributes_per_link, 14);
+        assert_eq!(limits.max_attribute_value_length, Some(256));
+
+        let config = temp_env::with_vars(
+            [
+                ("OTEL_SPAN_ATTRIBUTE_VALUE_LENGTH_LIMIT", Some("64")),
+                ("OTEL_ATTRIBUTE_VALUE_LENGTH_LIMIT", Some("256")),
+            ],
+            Config::default,
+        );
+        assert_eq!(config.span_limits.max_attribute_value_length, Some(64));
+    }
+}
@@ -6,17 +6,23 @@
 //! * The [`Span`] struct with is a mutable object storing information about the
 //!   current operation execution.
 //! * The [`TracerProvider`] struct which configures and produces [`Tracer`]s.
+mod baggage_processor;
 mod config;
 mod events;
 mod id_generator;
 mod links;
 mod provider;
+#[cfg(feature = "span_redaction")]
+mod redacting_processor;
 mod sampler;
 mod span;
 mod span_limit;
+#[cfg(feature = "metrics")]
+mod span_metrics;
 mod span_processor;
 mod tracer;
 
+pub use baggage_processor::BaggageSpanProcessor;
 pub use config::{config, Config};
 pub use events::SpanEvents;
 
@@ -28,10 +34,17 @@ pub use span::Span;
 pub use span_limit::SpanLimits;
 pub use span_processor::{
     BatchConfig, BatchConfigBuilder, BatchSpanProcessor, BatchSpanProcessorBuilder,
-    SimpleSpanProcessor, SpanProcessor,
+    SimpleSpanProcessor, SpanProcessor, ThreadedBatchSpanProcessor,
+    ThreadedBatchSpanProcessorBuilder,
 };
 pub use tracer::Tracer;
 
+#[cfg(feature = "metrics")]
+pub use span_metrics::{DurationUnit, SpanMetricsProcessor, SpanMetricsProcessorBuilder};
+
+#[cfg(feature = "span_redaction")]
+pub use redacting_processor::{RedactingSpanProcessor, RedactingSpanProcessorBuilder, Redaction};
+
 #[cfg(feature = "jaeger_remote_sampler")]
 pub use sampler::{JaegerRemoteSampler, JaegerRemoteSamplerBuilder};
 
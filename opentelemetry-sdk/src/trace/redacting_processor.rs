@@ -0,0 +1,398 @@
+use crate::export::trace::SpanData;
+use crate::resource::Resource;
+use crate::trace::{Span, SpanProcessor};
+use opentelemetry::trace::TraceResult;
+use opentelemetry::{Array, Context, Key, KeyValue, StringValue, Value};
+use regex::Regex;
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+use std::fmt;
+
+/// How the values of redacted attributes are rewritten.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Redaction {
+    /// Remove the attribute.
+    Remove,
+    /// Replace the value with a fixed string, e.g. `"***"`.
+    Mask(Cow<'static, str>),
+    /// Replace the value with the hex encoded SHA-256 hash of its string
+    /// representation, so equal values can still be correlated.
+    Hash,
+}
+
+impl Redaction {
+    fn apply(&self, value: &Value) -> Option<Value> {
+        match self {
+            Redaction::Remove => None,
+            Redaction::Mask(mask) => Some(Value::String(mask.clone().into())),
+            Redaction::Hash => {
+                let hash = Sha256::digest(value.as_str().as_bytes());
+                let hex = hash.iter().map(|b| format!("{b:02x}")).collect::<String>();
+                Some(Value::String(hex.into()))
+            }
+        }
+    }
+}
+
+enum KeyMatcher {
+    Exact(Key),
+    Pattern(Regex),
+}
+
+impl KeyMatcher {
+    fn matches(&self, key: &Key) -> bool {
+        match self {
+            KeyMatcher::Exact(expected) => expected == key,
+            KeyMatcher::Pattern(pattern) => pattern.is_match(key.as_str()),
+        }
+    }
+}
+
+type SpanFilter = Box<dyn Fn(&SpanData) -> bool + Send + Sync>;
+
+/// A [`SpanProcessor`] dropping and redacting finished spans before passing
+/// them to another processor, e.g. to scrub personal data in-process before
+/// spans are batched and exported.
+///
+/// Spans matching any of the drop filters are not passed on. The attributes
+/// of the others, their events and their links are rewritten: the first
+/// [`Redaction`] whose key matches is applied, then the value masks replace
+/// matching parts of the remaining string values.
+///
+/// The wrapped processor still sees spans start, as they can only be filtered
+/// once finished.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "rt-tokio", feature = "testing"))]
+/// # {
+/// use opentelemetry_sdk::testing::trace::NoopSpanExporter;
+/// use opentelemetry_sdk::trace::{
+///     BatchSpanProcessor, RedactingSpanProcessor, Redaction, TracerProvider,
+/// };
+/// use opentelemetry_sdk::runtime;
+/// use regex::Regex;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let batch = BatchSpanProcessor::builder(NoopSpanExporter::new(), runtime::Tokio).build();
+///     let processor = RedactingSpanProcessor::builder(batch)
+///         .with_dropped_span_name("GET /health")
+///         .with_attribute_redaction("enduser.id", Redaction::Hash)
+///         .with_attribute_pattern_redaction(Regex::new(r"password").unwrap(), Redaction::Remove)
+///         .with_value_mask(Regex::new(r"[\w.+-]+@[\w-]+\.[\w.]+").unwrap(), "<email>")
+///         .build();
+///     let provider = TracerProvider::builder()
+///         .with_span_processor(processor)
+///         .build();
+/// }
+/// # }
+/// ```
+pub struct RedactingSpanProcessor {
+    inner: Box<dyn SpanProcessor>,
+    filters: Vec<SpanFilter>,
+    redactions: Vec<(KeyMatcher, Redaction)>,
+    masks: Vec<(Regex, Cow<'static, str>)>,
+}
+
+impl RedactingSpanProcessor {
+    /// Create a builder for a processor passing spans on to `inner`.
+    pub fn builder<P: SpanProcessor + 'static>(inner: P) -> RedactingSpanProcessorBuilder {
+        RedactingSpanProcessorBuilder {
+            processor: RedactingSpanProcessor {
+                inner: Box::new(inner),
+                filters: Vec::new(),
+                redactions: Vec::new(),
+                masks: Vec::new(),
+            },
+        }
+    }
+
+    fn redact(&self, attributes: &mut Vec<KeyValue>) {
+        attributes.retain_mut(|kv| {
+            if let Some((_, redaction)) = self
+                .redactions
+                .iter()
+                .find(|(matcher, _)| matcher.matches(&kv.key))
+            {
+                return match redaction.apply(&kv.value) {
+                    Some(value) => {
+                        kv.value = value;
+                        true
+                    }
+                    None => false,
+                };
+            }
+            if !self.masks.is_empty() {
+                self.mask(&mut kv.value);
+            }
+            true
+        });
+    }
+
+    fn mask(&self, value: &mut Value) {
+        match value {
+            Value::String(s) => {
+                if let Some(masked) = self.mask_str(s.as_str()) {
+                    *s = masked.into();
+                }
+            }
+            Value::Array(Array::String(values)) => {
+                for s in values {
+                    if let Some(masked) = self.mask_str(s.as_str()) {
+                        *s = StringValue::from(masked);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// The masked string, if any mask matches `s`.
+    fn mask_str(&self, s: &str) -> Option<String> {
+        let mut masked: Option<String> = None;
+        for (pattern, replacement) in &self.masks {
+            let current = masked.as_deref().unwrap_or(s);
+            if let Cow::Owned(replaced) = pattern.replace_all(current, replacement.as_ref()) {
+                masked = Some(replaced);
+            }
+        }
+        masked
+    }
+}
+
+impl fmt::Debug for RedactingSpanProcessor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RedactingSpanProcessor")
+            .field("inner", &self.inner)
+            .field("filters", &self.filters.len())
+            .field("redactions", &self.redactions.len())
+            .field("masks", &self.masks.len())
+            .finish()
+    }
+}
+
+impl SpanProcessor for RedactingSpanProcessor {
+    fn on_start(&self, span: &mut Span, cx: &Context) {
+        self.inner.on_start(span, cx);
+    }
+
+    fn on_end(&self, mut span: SpanData) {
+        if self.filters.iter().any(|filter| filter(&span)) {
+            return;
+        }
+        if !self.redactions.is_empty() || !self.masks.is_empty() {
+            self.redact(&mut span.attributes);
+            for event in span.events.events.iter_mut() {
+                self.redact(&mut event.attributes);
+            }
+            for link in span.links.links.iter_mut() {
+                self.redact(&mut link.attributes);
+            }
+        }
+        self.inner.on_end(span);
+    }
+
+    fn force_flush(&self) -> TraceResult<()> {
+        self.inner.force_flush()
+    }
+
+    fn shutdown(&self) -> TraceResult<()> {
+        self.inner.shutdown()
+    }
+
+    fn set_resource(&mut self, resource: &Resource) {
+        self.inner.set_resource(resource);
+    }
+}
+
+/// Builder for [`RedactingSpanProcessor`].
+#[derive(Debug)]
+pub struct RedactingSpanProcessorBuilder {
+    processor: RedactingSpanProcessor,
+}
+
+impl RedactingSpanProcessorBuilder {
+    /// Drop spans for which `filter` returns `true`.
+    pub fn with_drop_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&SpanData) -> bool + Send + Sync + 'static,
+    {
+        self.processor.filters.push(Box::new(filter));
+        self
+    }
+
+    /// Drop spans named `name`.
+    pub fn with_dropped_span_name(self, name: impl Into<Cow<'static, str>>) -> Self {
+        let name = name.into();
+        self.with_drop_filter(move |span| span.name == name)
+    }
+
+    /// Drop spans of the instrumentation scope named `name`.
+    pub fn with_dropped_scope(self, name: impl Into<Cow<'static, str>>) -> Self {
+        let name = name.into();
+        self.with_drop_filter(move |span| span.instrumentation_lib.name == name)
+    }
+
+    /// Drop spans having the attribute `key` with a value for which
+    /// `predicate` returns `true`.
+    pub fn with_dropped_attribute<F>(self, key: impl Into<Key>, predicate: F) -> Self
+    where
+        F: Fn(&Value) -> bool + Send + Sync + 'static,
+    {
+        let key = key.into();
+        self.with_drop_filter(move |span| {
+            span.attributes
+                .iter()
+                .any(|kv| kv.key == key && predicate(&kv.value))
+        })
+    }
+
+    /// Redact the attributes named `key`.
+    pub fn with_attribute_redaction(mut self, key: impl Into<Key>, redaction: Redaction) -> Self {
+        self.processor
+            .redactions
+            .push((KeyMatcher::Exact(key.into()), redaction));
+        self
+    }
+
+    /// Redact the attributes whose name matches `pattern`.
+    pub fn with_attribute_pattern_redaction(
+        mut self,
+        pattern: Regex,
+        redaction: Redaction,
+    ) -> Self {
+        self.processor
+            .redactions
+            .push((KeyMatcher::Pattern(pattern), redaction));
+        self
+    }
+
+    /// Replace the parts of string attribute values matching `pattern` with
+    /// `replacement`, which can refer to capture groups as in
+    /// [`Regex::replace_all`].
+    pub fn with_value_mask(
+        mut self,
+        pattern: Regex,
+        replacement: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.processor.masks.push((pattern, replacement.into()));
+        self
+    }
+
+    /// Build the processor.
+    pub fn build(self) -> RedactingSpanProcessor {
+        self.processor
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use super::*;
+    use crate::testing::trace::{new_test_export_span_data, InMemorySpanExporterBuilder};
+    use crate::trace::{SimpleSpanProcessor, SpanEvents};
+    use opentelemetry::trace::Event;
+    use opentelemetry::InstrumentationLibrary;
+    use std::time::SystemTime;
+
+    fn span(name: &'static str, attributes: Vec<KeyValue>) -> SpanData {
+        SpanData {
+            name: name.into(),
+            attributes,
+            ..new_test_export_span_data()
+        }
+    }
+
+    #[test]
+    fn drops_spans() {
+        let exporter = InMemorySpanExporterBuilder::new().build();
+        let processor =
+            RedactingSpanProcessor::builder(SimpleSpanProcessor::new(Box::new(exporter.clone())))
+                .with_dropped_span_name("GET /health")
+                .with_dropped_scope("noisy")
+                .with_dropped_attribute("http.route", |value| value.as_str() == "/metrics")
+                .build();
+
+        processor.on_end(span("GET /health", vec![]));
+        processor.on_end(SpanData {
+            instrumentation_lib: InstrumentationLibrary::builder("noisy").build(),
+            ..span("poll", vec![])
+        });
+        processor.on_end(span("GET", vec![KeyValue::new("http.route", "/metrics")]));
+        processor.on_end(span("GET", vec![KeyValue::new("http.route", "/cart")]));
+
+        let spans = exporter.get_finished_spans().unwrap();
+        assert_eq!(spans.len(), 1);
+        assert_eq!(
+            spans[0].attributes,
+            vec![KeyValue::new("http.route", "/cart")]
+        );
+    }
+
+    #[test]
+    fn redacts_attributes() {
+        let exporter = InMemorySpanExporterBuilder::new().build();
+        let processor =
+            RedactingSpanProcessor::builder(SimpleSpanProcessor::new(Box::new(exporter.clone())))
+                .with_attribute_redaction("enduser.id", Redaction::Hash)
+                .with_attribute_redaction("card.number", Redaction::Mask("****".into()))
+                .with_attribute_pattern_redaction(
+                    Regex::new(r"password").unwrap(),
+                    Redaction::Remove,
+                )
+                .with_value_mask(Regex::new(r"[\w.]+@[\w.]+").unwrap(), "<email>")
+                .build();
+
+        let mut events = SpanEvents::default();
+        events.events.push(Event::new(
+            "login",
+            SystemTime::UNIX_EPOCH,
+            vec![
+                KeyValue::new("user.password", "hunter2"),
+                KeyValue::new("message", "sent to jane@example.com"),
+            ],
+            0,
+        ));
+        processor.on_end(SpanData {
+            events,
+            ..span(
+                "login",
+                vec![
+                    KeyValue::new("enduser.id", "jane"),
+                    KeyValue::new("card.number", 4111111111111111),
+                    KeyValue::new(
+                        "emails",
+                        Value::Array(Array::String(vec![
+                            "jane@example.com".into(),
+                            "none".into(),
+                        ])),
+                    ),
+                    KeyValue::new("count", 1),
+                ],
+            )
+        });
+
+        let spans = exporter.get_finished_spans().unwrap();
+        assert_eq!(
+            spans[0].attributes,
+            vec![
+                KeyValue::new(
+                    "enduser.id",
+                    "81f8f6dde88365f3928796ec7aa53f72820b06db8664f5fe76a7eb13e24546a2"
+                ),
+                KeyValue::new("card.number", "****"),
+                KeyValue::new(
+                    "emails",
+                    Value::Array(Array::String(vec!["<email>".into(), "none".into()])),
+                ),
+                KeyValue::new("count", 1),
+            ]
+        );
+        assert_eq!(
+            spans[0].events.events[0].attributes,
+            vec![KeyValue::new("message", "sent to <email>")]
+        );
+    }
+}
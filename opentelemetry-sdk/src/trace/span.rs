@@ -99,11 +99,13 @@ impl opentelemetry::trace::Span for Span {
     {
         let span_events_limit = self.span_limits.max_events_per_span as usize;
         let event_attributes_limit = self.span_limits.max_attributes_per_event as usize;
+        let span_limits = self.span_limits;
         self.with_data(|data| {
             if data.events.len() < span_events_limit {
                 let dropped_attributes_count =
                     attributes.len().saturating_sub(event_attributes_limit);
                 attributes.truncate(event_attributes_limit);
+                span_limits.truncate_attribute_values(&mut attributes);
 
                 data.events.add_event(Event::new(
                     name,
@@ -134,10 +136,12 @@ impl opentelemetry::trace::Span for Span {
     /// Note that the OpenTelemetry project documents certain ["standard
     /// attributes"](https://github.com/open-telemetry/opentelemetry-specification/tree/v0.5.0/specification/trace/semantic_conventions/README.md)
     /// that have prescribed semantic meanings.
-    fn set_attribute(&mut self, attribute: KeyValue) {
+    fn set_attribute(&mut self, mut attribute: KeyValue) {
         let span_attribute_limit = self.span_limits.max_attributes_per_span as usize;
+        let span_limits = self.span_limits;
         self.with_data(|data| {
             if data.attributes.len() < span_attribute_limit {
+                span_limits.truncate_attribute_values(std::slice::from_mut(&mut attribute));
                 data.attributes.push(attribute);
             } else {
                 data.dropped_attributes_count += 1;
@@ -173,12 +177,14 @@ impl opentelemetry::trace::Span for Span {
     fn add_link(&mut self, span_context: SpanContext, attributes: Vec<KeyValue>) {
         let span_links_limit = self.span_limits.max_links_per_span as usize;
         let link_attributes_limit = self.span_limits.max_attributes_per_link as usize;
+        let span_limits = self.span_limits;
         self.with_data(|data| {
             if data.links.links.len() < span_links_limit {
                 let dropped_attributes_count =
                     attributes.len().saturating_sub(link_attributes_limit);
                 let mut attributes = attributes;
                 attributes.truncate(link_attributes_limit);
+                span_limits.truncate_attribute_values(&mut attributes);
                 data.links.add_link(Link::new(
                     span_context,
                     attributes,
@@ -277,7 +283,7 @@ mod tests {
     };
     use crate::trace::{SpanEvents, SpanLinks};
     use opentelemetry::trace::{self, SpanBuilder, TraceFlags, TraceId, Tracer};
-    use opentelemetry::{trace::Span as _, trace::TracerProvider};
+    use opentelemetry::{trace::Span as _, trace::TracerProvider, Exception};
     use std::time::Duration;
     use std::vec;
 
@@ -385,6 +391,29 @@ mod tests {
         });
     }
 
+    #[test]
+    fn record_exception() {
+        let mut span = create_span();
+        let err = std::io::Error::from(std::io::ErrorKind::Other);
+        span.record_exception(Exception::new(&err).with_escaped(true));
+        span.with_data(|data| {
+            if let Some(event) = data.events.iter().next() {
+                assert_eq!(event.name, "exception");
+                assert!(event
+                    .attributes
+                    .contains(&KeyValue::new("exception.type", "std::io::error::Error")));
+                assert!(event
+                    .attributes
+                    .contains(&KeyValue::new("exception.message", err.to_string())));
+                assert!(event
+                    .attributes
+                    .contains(&KeyValue::new("exception.escaped", true)));
+            } else {
+                panic!("no event");
+            }
+        });
+    }
+
     #[test]
     fn set_attribute() {
         let mut span = create_span();
@@ -634,6 +663,60 @@ mod tests {
         assert_eq!(processed_link.attributes.len(), 128);
     }
 
+    #[test]
+    fn exceed_attribute_value_length_limit() {
+        let provider = crate::trace::TracerProvider::builder()
+            .with_config(crate::trace::Config::default().with_max_attribute_value_length(4))
+            .with_simple_exporter(NoopSpanExporter::new())
+            .build();
+        let tracer = provider.tracer("opentelemetry-test");
+        let linked = SpanContext::new(
+            TraceId::from_u128(1),
+            SpanId::from_u64(1),
+            TraceFlags::default(),
+            false,
+            Default::default(),
+        );
+
+        let span_builder = tracer
+            .span_builder("test")
+            .with_attributes(vec![KeyValue::new("builder", "truncated")])
+            .with_events(vec![Event::new(
+                "builder event",
+                opentelemetry::time::now(),
+                vec![KeyValue::new("k", "truncated")],
+                0,
+            )])
+            .with_links(vec![Link::new(
+                linked.clone(),
+                vec![KeyValue::new("k", "truncated")],
+                0,
+            )]);
+        let mut span = tracer.build(span_builder);
+        span.set_attribute(KeyValue::new("set", "ünïcödé"));
+        span.set_attribute(KeyValue::new("number", 123456789));
+        span.add_event("event", vec![KeyValue::new("k", "truncated")]);
+        span.add_link(linked, vec![KeyValue::new("k", "truncated")]);
+
+        let data = span.data.clone().expect("span should be recording");
+        assert_eq!(
+            data.attributes,
+            vec![
+                KeyValue::new("builder", "trun"),
+                KeyValue::new("set", "ünïc"),
+                KeyValue::new("number", 123456789),
+            ]
+        );
+        for event in data.events.iter() {
+            assert_eq!(event.attributes, vec![KeyValue::new("k", "trun")]);
+        }
+        for link in data.links.iter() {
+            assert_eq!(link.attributes, vec![KeyValue::new("k", "trun")]);
+        }
+        assert_eq!(data.events.len(), 2);
+        assert_eq!(data.links.len(), 2);
+    }
+
     #[test]
     fn exceed_span_links_limit() {
         let exporter = NoopSpanExporter::new();
@@ -1,3 +1,5 @@
+use opentelemetry::{Array, KeyValue, StringValue, Value};
+
 /// # Span limit
 /// Erroneous code can add unintended attributes, events, and links to a span. If these collections
 /// are unbounded, they can quickly exhaust available memory, resulting in crashes that are
@@ -9,9 +11,11 @@
 ///  - Maximum allowed span link count
 ///  - Maximum allowed attribute per span event count
 ///  - Maximum allowed attribute per span link count
+///  - Maximum allowed length of string attribute values
 ///
 /// If the limit has been breached. The attributes, events or links will be dropped based on their
-/// index in the collection. The one added to collections later will be dropped first.
+/// index in the collection. The one added to collections later will be dropped first. String and
+/// string array attribute values longer than the length limit are truncated.
 
 pub(crate) const DEFAULT_MAX_EVENT_PER_SPAN: u32 = 128;
 pub(crate) const DEFAULT_MAX_ATTRIBUTES_PER_SPAN: u32 = 128;
@@ -32,6 +36,9 @@ pub struct SpanLimits {
     pub max_attributes_per_event: u32,
     /// The max attributes that can be added into a `Link`
     pub max_attributes_per_link: u32,
+    /// The max length, in characters, of string and string array attribute values of a `Span`,
+    /// its events and links. Values are not truncated if `None`.
+    pub max_attribute_value_length: Option<u32>,
 }
 
 impl Default for SpanLimits {
@@ -42,6 +49,88 @@ impl Default for SpanLimits {
             max_links_per_span: DEFAULT_MAX_LINKS_PER_SPAN,
             max_attributes_per_link: DEFAULT_MAX_ATTRIBUTES_PER_LINK,
             max_attributes_per_event: DEFAULT_MAX_ATTRIBUTES_PER_EVENT,
+            max_attribute_value_length: None,
+        }
+    }
+}
+
+impl SpanLimits {
+    /// Truncates the string values of `attributes` to the max attribute value length.
+    pub(crate) fn truncate_attribute_values(&self, attributes: &mut [KeyValue]) {
+        if let Some(max_length) = self.max_attribute_value_length {
+            for attribute in attributes {
+                truncate_value(&mut attribute.value, max_length as usize);
+            }
         }
     }
 }
+
+fn truncate_value(value: &mut Value, max_length: usize) {
+    match value {
+        Value::String(s) => truncate_string(s, max_length),
+        Value::Array(Array::String(values)) => {
+            for s in values {
+                truncate_string(s, max_length);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Truncates `s` to at most `max_length` characters, so it is never cut in the middle of a UTF-8
+/// sequence.
+fn truncate_string(s: &mut StringValue, max_length: usize) {
+    if let Some((end, _)) = s.as_str().char_indices().nth(max_length) {
+        *s = StringValue::from(s.as_str()[..end].to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncate_attribute_values() {
+        let limits = SpanLimits {
+            max_attribute_value_length: Some(3),
+            ..SpanLimits::default()
+        };
+        let mut attributes = vec![
+            KeyValue::new("short", "ab"),
+            KeyValue::new("exact", "abc"),
+            KeyValue::new("long", "abcdef"),
+            KeyValue::new("multibyte", "héllo"),
+            KeyValue::new("emoji", "🦀🦀🦀🦀"),
+            KeyValue::new(
+                "array",
+                Value::Array(Array::String(vec!["abcd".into(), "a".into()])),
+            ),
+            KeyValue::new("int", 123456),
+        ];
+
+        limits.truncate_attribute_values(&mut attributes);
+
+        assert_eq!(
+            attributes,
+            vec![
+                KeyValue::new("short", "ab"),
+                KeyValue::new("exact", "abc"),
+                KeyValue::new("long", "abc"),
+                KeyValue::new("multibyte", "hél"),
+                KeyValue::new("emoji", "🦀🦀🦀"),
+                KeyValue::new(
+                    "array",
+                    Value::Array(Array::String(vec!["abc".into(), "a".into()])),
+                ),
+                KeyValue::new("int", 123456),
+            ]
+        );
+    }
+
+    #[test]
+    fn no_truncation_by_default() {
+        let mut attributes = vec![KeyValue::new("long", "a".repeat(10_000))];
+        SpanLimits::default().truncate_attribute_values(&mut attributes);
+        assert_eq!(attributes[0].value.as_str().len(), 10_000);
+    }
+}
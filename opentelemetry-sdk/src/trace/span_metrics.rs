@@ -0,0 +1,313 @@
+//! # Span Metrics Processor
+//!
+//! Records request, error and duration metrics of finished spans, like the
+//! collector's [spanmetrics connector], so operations get rate, error and
+//! duration (RED) metrics without instrumenting them with metrics.
+//!
+//! [spanmetrics connector]: https://github.com/open-telemetry/opentelemetry-collector-contrib/tree/main/connector/spanmetricsconnector
+use crate::export::trace::SpanData;
+use crate::metrics::SdkMeterProvider;
+use crate::trace::{Span, SpanProcessor};
+use opentelemetry::metrics::{Counter, Histogram, MeterProvider};
+use opentelemetry::trace::{SpanKind, Status, TraceResult};
+use opentelemetry::{Context, Key, KeyValue};
+use std::borrow::Cow;
+
+const DEFAULT_NAMESPACE: &str = "traces.span.metrics";
+
+/// The unit span durations are recorded in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DurationUnit {
+    /// Milliseconds, `ms`, the default like in the collector.
+    #[default]
+    Milliseconds,
+    /// Seconds, `s`, as recommended by the semantic conventions.
+    Seconds,
+}
+
+impl DurationUnit {
+    fn as_str(&self) -> &'static str {
+        match self {
+            DurationUnit::Milliseconds => "ms",
+            DurationUnit::Seconds => "s",
+        }
+    }
+}
+
+/// A [`SpanProcessor`] recording metrics of finished spans into a
+/// [`SdkMeterProvider`].
+///
+/// The following instruments are recorded, named after the namespace
+/// (`traces.span.metrics` by default):
+///
+/// | Name | Instrument | Description |
+/// |------|------------|-------------|
+/// | `<namespace>.calls` | `u64` counter | Number of finished spans |
+/// | `<namespace>.errors` | `u64` counter | Number of finished spans with an error status |
+/// | `<namespace>.duration` | `f64` histogram | Duration of spans |
+///
+/// Each measurement has the `span.name`, `span.kind` and `status.code`
+/// attributes, as in the collector, followed by the configured
+/// [dimensions](SpanMetricsProcessorBuilder::with_dimensions) the span has.
+/// The `service.name` and other resource attributes are those of the meter
+/// provider's resource.
+///
+/// The instruments can be customized with views, e.g. to change the histogram
+/// bucket boundaries.
+///
+/// # Examples
+///
+/// ```
+/// use opentelemetry_sdk::metrics::SdkMeterProvider;
+/// use opentelemetry_sdk::trace::{SpanMetricsProcessor, TracerProvider};
+///
+/// let meter_provider = SdkMeterProvider::default();
+/// let processor = SpanMetricsProcessor::builder(&meter_provider)
+///     .with_dimensions(["http.request.method", "http.response.status_code"])
+///     .build();
+/// let tracer_provider = TracerProvider::builder()
+///     .with_span_processor(processor)
+///     .build();
+/// ```
+#[derive(Debug)]
+pub struct SpanMetricsProcessor {
+    calls: Counter<u64>,
+    errors: Counter<u64>,
+    duration: Histogram<f64>,
+    duration_unit: DurationUnit,
+    dimensions: Vec<Key>,
+}
+
+impl SpanMetricsProcessor {
+    /// Create a builder recording into `meter_provider`.
+    pub fn builder(meter_provider: &SdkMeterProvider) -> SpanMetricsProcessorBuilder {
+        SpanMetricsProcessorBuilder {
+            meter_provider: meter_provider.clone(),
+            namespace: DEFAULT_NAMESPACE.into(),
+            duration_unit: DurationUnit::default(),
+            dimensions: Vec::new(),
+        }
+    }
+
+    fn attributes(&self, span: &SpanData) -> Vec<KeyValue> {
+        let mut attributes = Vec::with_capacity(3 + self.dimensions.len());
+        attributes.push(KeyValue::new("span.name", span.name.clone()));
+        attributes.push(KeyValue::new(
+            "span.kind",
+            match span.span_kind {
+                SpanKind::Client => "SPAN_KIND_CLIENT",
+                SpanKind::Server => "SPAN_KIND_SERVER",
+                SpanKind::Producer => "SPAN_KIND_PRODUCER",
+                SpanKind::Consumer => "SPAN_KIND_CONSUMER",
+                SpanKind::Internal => "SPAN_KIND_INTERNAL",
+            },
+        ));
+        attributes.push(KeyValue::new(
+            "status.code",
+            match span.status {
+                Status::Unset => "STATUS_CODE_UNSET",
+                Status::Ok => "STATUS_CODE_OK",
+                Status::Error { .. } => "STATUS_CODE_ERROR",
+            },
+        ));
+        for key in &self.dimensions {
+            if let Some(kv) = span.attributes.iter().find(|kv| &kv.key == key) {
+                attributes.push(kv.clone());
+            }
+        }
+        attributes
+    }
+}
+
+impl SpanProcessor for SpanMetricsProcessor {
+    fn on_start(&self, _span: &mut Span, _cx: &Context) {
+        // Ignored
+    }
+
+    fn on_end(&self, span: SpanData) {
+        let attributes = self.attributes(&span);
+        let duration = span
+            .end_time
+            .duration_since(span.start_time)
+            .unwrap_or_default();
+
+        self.calls.add(1, &attributes);
+        if matches!(span.status, Status::Error { .. }) {
+            self.errors.add(1, &attributes);
+        }
+        self.duration.record(
+            match self.duration_unit {
+                DurationUnit::Milliseconds => duration.as_secs_f64() * 1_000.0,
+                DurationUnit::Seconds => duration.as_secs_f64(),
+            },
+            &attributes,
+        );
+    }
+
+    fn force_flush(&self) -> TraceResult<()> {
+        // Metrics are exported by the readers of the meter provider.
+        Ok(())
+    }
+
+    fn shutdown(&self) -> TraceResult<()> {
+        Ok(())
+    }
+}
+
+/// Builder for [`SpanMetricsProcessor`].
+#[derive(Debug)]
+pub struct SpanMetricsProcessorBuilder {
+    meter_provider: SdkMeterProvider,
+    namespace: Cow<'static, str>,
+    duration_unit: DurationUnit,
+    dimensions: Vec<Key>,
+}
+
+impl SpanMetricsProcessorBuilder {
+    /// Set the prefix of the instrument names, `traces.span.metrics` by
+    /// default.
+    pub fn with_namespace(mut self, namespace: impl Into<Cow<'static, str>>) -> Self {
+        self.namespace = namespace.into();
+        self
+    }
+
+    /// Set the unit durations are recorded in, milliseconds by default.
+    pub fn with_duration_unit(mut self, unit: DurationUnit) -> Self {
+        self.duration_unit = unit;
+        self
+    }
+
+    /// Add span attributes to the attributes of the measurements.
+    ///
+    /// Spans without an attribute are recorded without it. Each distinct
+    /// combination of values is a separate time series, so dimensions should
+    /// have a small number of values.
+    pub fn with_dimensions<K: Into<Key>>(mut self, keys: impl IntoIterator<Item = K>) -> Self {
+        self.dimensions.extend(keys.into_iter().map(Into::into));
+        self
+    }
+
+    /// Create the instruments and the processor recording into them.
+    pub fn build(self) -> SpanMetricsProcessor {
+        let meter = self.meter_provider.versioned_meter(
+            "opentelemetry-sdk",
+            Some(env!("CARGO_PKG_VERSION")),
+            None::<&'static str>,
+            None,
+        );
+        let namespace = self.namespace;
+
+        SpanMetricsProcessor {
+            calls: meter
+                .u64_counter(format!("{namespace}.calls"))
+                .with_description("Number of finished spans.")
+                .with_unit("{call}")
+                .init(),
+            errors: meter
+                .u64_counter(format!("{namespace}.errors"))
+                .with_description("Number of finished spans with an error status.")
+                .with_unit("{call}")
+                .init(),
+            duration: meter
+                .f64_histogram(format!("{namespace}.duration"))
+                .with_description("Duration of spans.")
+                .with_unit(self.duration_unit.as_str())
+                .init(),
+            duration_unit: self.duration_unit,
+            dimensions: self.dimensions,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use super::*;
+    use crate::metrics::data::{Histogram as HistogramData, Sum};
+    use crate::metrics::PeriodicReader;
+    use crate::runtime;
+    use crate::testing::metrics::InMemoryMetricsExporter;
+    use crate::trace::TracerProvider;
+    use opentelemetry::trace::{Span as _, Tracer, TracerProvider as _};
+    use std::time::{Duration, SystemTime};
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn records_red_metrics() {
+        let exporter = InMemoryMetricsExporter::default();
+        let meter_provider = SdkMeterProvider::builder()
+            .with_reader(PeriodicReader::builder(exporter.clone(), runtime::Tokio).build())
+            .build();
+        let processor = SpanMetricsProcessor::builder(&meter_provider)
+            .with_namespace("spans")
+            .with_duration_unit(DurationUnit::Seconds)
+            .with_dimensions(["http.request.method"])
+            .build();
+        let tracer_provider = TracerProvider::builder()
+            .with_span_processor(processor)
+            .build();
+        let tracer = tracer_provider.tracer("test");
+
+        let start = SystemTime::UNIX_EPOCH;
+        for status in [Status::Ok, Status::error("timeout")] {
+            let mut span = tracer
+                .span_builder("GET /cart")
+                .with_kind(SpanKind::Server)
+                .with_start_time(start)
+                .with_attributes([
+                    KeyValue::new("http.request.method", "GET"),
+                    KeyValue::new("url.path", "/cart"),
+                ])
+                .start(&tracer);
+            span.set_status(status);
+            span.end_with_timestamp(start + Duration::from_millis(250));
+        }
+        meter_provider.force_flush().unwrap();
+
+        let resource_metrics = exporter.get_finished_metrics().unwrap();
+        let metrics = &resource_metrics[0].scope_metrics[0].metrics;
+        let metric = |name: &str| metrics.iter().find(|m| m.name == name).unwrap();
+        let attributes = |status: &'static str| {
+            vec![
+                KeyValue::new("span.name", "GET /cart"),
+                KeyValue::new("span.kind", "SPAN_KIND_SERVER"),
+                KeyValue::new("status.code", status),
+                KeyValue::new("http.request.method", "GET"),
+            ]
+        };
+        let sorted = |mut attributes: Vec<KeyValue>| {
+            attributes.sort_by(|a, b| a.key.cmp(&b.key));
+            attributes
+        };
+
+        let calls = metric("spans.calls")
+            .data
+            .as_any()
+            .downcast_ref::<Sum<u64>>()
+            .unwrap();
+        assert_eq!(calls.data_points.len(), 2);
+        assert!(calls.data_points.iter().all(|dp| dp.value == 1));
+
+        let errors = metric("spans.errors")
+            .data
+            .as_any()
+            .downcast_ref::<Sum<u64>>()
+            .unwrap();
+        assert_eq!(errors.data_points.len(), 1);
+        assert_eq!(
+            sorted(errors.data_points[0].attributes.clone()),
+            sorted(attributes("STATUS_CODE_ERROR"))
+        );
+
+        assert_eq!(metric("spans.duration").unit, "s");
+        let duration = metric("spans.duration")
+            .data
+            .as_any()
+            .downcast_ref::<HistogramData<f64>>()
+            .unwrap();
+        assert_eq!(duration.data_points.len(), 2);
+        for dp in &duration.data_points {
+            assert_eq!(dp.count, 1);
+            assert_eq!(dp.sum, 0.25);
+        }
+    }
+}
@@ -38,6 +38,7 @@ use crate::export::trace::{ExportResult, SpanData, SpanExporter};
 use crate::resource::Resource;
 use crate::runtime::{RuntimeChannel, TrySend};
 use crate::trace::Span;
+use crate::util::block_on_with_timeout;
 use futures_channel::oneshot;
 use futures_util::{
     future::{self, BoxFuture, Either},
@@ -51,7 +52,11 @@ use opentelemetry::{
     Context,
 };
 use std::cmp::min;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, SyncSender};
 use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Instant;
 use std::{env, fmt, str::FromStr, time::Duration};
 
 /// Delay interval between two consecutive exports.
@@ -690,13 +695,255 @@ where
     }
 }
 
+/// A [`SpanProcessor`] batching finished spans like the [`BatchSpanProcessor`],
+/// on a dedicated background thread instead of an async runtime.
+///
+/// Spans are queued in a bounded channel and exported by the thread, which
+/// drives the exporter with a blocking executor. The exporter must therefore
+/// not rely on an async runtime being present, e.g. it may use a blocking HTTP
+/// client. The [`BatchConfig`] applies as for the [`BatchSpanProcessor`],
+/// except that batches are always exported one after the other, ignoring
+/// `max_concurrent_exports`.
+///
+/// [`force_flush`] and [`shutdown`] block until the thread exported the queued
+/// spans, which is bounded by the export timeout. As the export doesn't run
+/// on the calling thread, they can safely be called from within an async
+/// runtime.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "testing")]
+/// # {
+/// use opentelemetry_sdk::testing::trace::NoopSpanExporter;
+/// use opentelemetry_sdk::trace::{ThreadedBatchSpanProcessor, TracerProvider};
+///
+/// let processor = ThreadedBatchSpanProcessor::builder(NoopSpanExporter::new()).build();
+/// let provider = TracerProvider::builder()
+///     .with_span_processor(processor)
+///     .build();
+/// # }
+/// ```
+///
+/// [`force_flush`]: SpanProcessor::force_flush
+/// [`shutdown`]: SpanProcessor::shutdown
+#[derive(Debug)]
+pub struct ThreadedBatchSpanProcessor {
+    message_sender: SyncSender<ThreadMessage>,
+    handle: Mutex<Option<JoinHandle<()>>>,
+    is_shutdown: AtomicBool,
+}
+
+/// Messages sent to the thread of a [`ThreadedBatchSpanProcessor`].
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+enum ThreadMessage {
+    ExportSpan(SpanData),
+    Flush(mpsc::Sender<ExportResult>),
+    Shutdown(mpsc::Sender<ExportResult>),
+    SetResource(Resource),
+}
+
+impl ThreadedBatchSpanProcessor {
+    pub(crate) fn new(exporter: Box<dyn SpanExporter>, config: BatchConfig) -> Self {
+        let (message_sender, message_receiver) = mpsc::sync_channel(config.max_queue_size);
+        let handle = thread::Builder::new()
+            .name("OpenTelemetry.Traces.BatchProcessor".into())
+            .spawn(move || {
+                ThreadedBatchSpanProcessorInternal {
+                    spans: Vec::new(),
+                    config,
+                    exporter,
+                }
+                .run(message_receiver)
+            })
+            .expect("failed to spawn the batch span processor thread");
+
+        ThreadedBatchSpanProcessor {
+            message_sender,
+            handle: Mutex::new(Some(handle)),
+            is_shutdown: AtomicBool::new(false),
+        }
+    }
+
+    /// Create a new threaded batch processor builder
+    pub fn builder<E>(exporter: E) -> ThreadedBatchSpanProcessorBuilder<E>
+    where
+        E: SpanExporter,
+    {
+        ThreadedBatchSpanProcessorBuilder {
+            exporter,
+            config: Default::default(),
+        }
+    }
+
+    fn send_and_wait(
+        &self,
+        message: impl FnOnce(mpsc::Sender<ExportResult>) -> ThreadMessage,
+    ) -> TraceResult<()> {
+        let (res_sender, res_receiver) = mpsc::channel();
+        self.message_sender
+            .send(message(res_sender))
+            .map_err(|err| TraceError::Other(err.into()))?;
+        res_receiver
+            .recv()
+            .map_err(|err| TraceError::Other(err.into()))
+            .and_then(std::convert::identity)
+    }
+}
+
+impl SpanProcessor for ThreadedBatchSpanProcessor {
+    fn on_start(&self, _span: &mut Span, _cx: &Context) {
+        // Ignored
+    }
+
+    fn on_end(&self, span: SpanData) {
+        if !span.span_context.is_sampled() || self.is_shutdown.load(Ordering::Relaxed) {
+            return;
+        }
+
+        if let Err(err) = self
+            .message_sender
+            .try_send(ThreadMessage::ExportSpan(span))
+        {
+            global::handle_error(TraceError::Other(err.into()));
+        }
+    }
+
+    fn force_flush(&self) -> TraceResult<()> {
+        if self.is_shutdown.load(Ordering::Relaxed) {
+            return Err(TraceError::Other(
+                "batch span processor is shut down".into(),
+            ));
+        }
+        self.send_and_wait(ThreadMessage::Flush)
+    }
+
+    fn shutdown(&self) -> TraceResult<()> {
+        if self.is_shutdown.swap(true, Ordering::Relaxed) {
+            return Err(TraceError::Other(
+                "batch span processor is already shut down".into(),
+            ));
+        }
+        let result = self.send_and_wait(ThreadMessage::Shutdown);
+        if let Some(handle) = self.handle.lock().ok().and_then(|mut handle| handle.take()) {
+            let _ = handle.join();
+        }
+        result
+    }
+
+    fn set_resource(&mut self, resource: &Resource) {
+        let _ = self
+            .message_sender
+            .send(ThreadMessage::SetResource(resource.clone()));
+    }
+}
+
+struct ThreadedBatchSpanProcessorInternal {
+    spans: Vec<SpanData>,
+    config: BatchConfig,
+    exporter: Box<dyn SpanExporter>,
+}
+
+impl ThreadedBatchSpanProcessorInternal {
+    fn run(mut self, messages: Receiver<ThreadMessage>) {
+        let mut next_export = Instant::now() + self.config.scheduled_delay;
+        loop {
+            let timeout = next_export.saturating_duration_since(Instant::now());
+            match messages.recv_timeout(timeout) {
+                // Span has finished, add to buffer of pending spans.
+                Ok(ThreadMessage::ExportSpan(span)) => {
+                    self.spans.push(span);
+
+                    if self.spans.len() >= self.config.max_export_batch_size {
+                        if let Err(err) = self.export_batch() {
+                            global::handle_error(err);
+                        }
+                    }
+                }
+                Ok(ThreadMessage::Flush(res_sender)) => {
+                    let _ = res_sender.send(self.export_all());
+                }
+                Ok(ThreadMessage::Shutdown(res_sender)) => {
+                    let result = self.export_all();
+                    self.exporter.shutdown();
+                    let _ = res_sender.send(result);
+                    break;
+                }
+                Ok(ThreadMessage::SetResource(resource)) => {
+                    self.exporter.set_resource(&resource);
+                }
+                // Batch interval time reached, export current spans.
+                Err(RecvTimeoutError::Timeout) => {
+                    if let Err(err) = self.export_all() {
+                        global::handle_error(err);
+                    }
+                    next_export = Instant::now() + self.config.scheduled_delay;
+                }
+                // The processor was dropped without being shut down.
+                Err(RecvTimeoutError::Disconnected) => {
+                    if let Err(err) = self.export_all() {
+                        global::handle_error(err);
+                    }
+                    self.exporter.shutdown();
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Exports all buffered spans in batches, returning the first error.
+    fn export_all(&mut self) -> ExportResult {
+        let mut result = Ok(());
+        while !self.spans.is_empty() {
+            if let Err(err) = self.export_batch() {
+                result = result.and(Err(err));
+            }
+        }
+        result
+    }
+
+    fn export_batch(&mut self) -> ExportResult {
+        let count = min(self.spans.len(), self.config.max_export_batch_size);
+        let batch = self.spans.drain(..count).collect::<Vec<_>>();
+        if batch.is_empty() {
+            return Ok(());
+        }
+        let timeout = self.config.max_export_timeout;
+        block_on_with_timeout(self.exporter.export(batch), timeout)
+            .unwrap_or(Err(TraceError::ExportTimedOut(timeout)))
+    }
+}
+
+/// A builder for creating [`ThreadedBatchSpanProcessor`] instances.
+#[derive(Debug)]
+pub struct ThreadedBatchSpanProcessorBuilder<E> {
+    exporter: E,
+    config: BatchConfig,
+}
+
+impl<E> ThreadedBatchSpanProcessorBuilder<E>
+where
+    E: SpanExporter + 'static,
+{
+    /// Set the BatchConfig for [ThreadedBatchSpanProcessorBuilder]
+    pub fn with_batch_config(self, config: BatchConfig) -> Self {
+        ThreadedBatchSpanProcessorBuilder { config, ..self }
+    }
+
+    /// Build a threaded batch processor, spawning its thread.
+    pub fn build(self) -> ThreadedBatchSpanProcessor {
+        ThreadedBatchSpanProcessor::new(Box::new(self.exporter), self.config)
+    }
+}
+
 #[cfg(all(test, feature = "testing", feature = "trace"))]
 mod tests {
     // cargo test trace::span_processor::tests:: --features=testing
     use super::{
-        BatchSpanProcessor, SimpleSpanProcessor, SpanProcessor, OTEL_BSP_EXPORT_TIMEOUT,
-        OTEL_BSP_MAX_EXPORT_BATCH_SIZE, OTEL_BSP_MAX_QUEUE_SIZE, OTEL_BSP_MAX_QUEUE_SIZE_DEFAULT,
-        OTEL_BSP_SCHEDULE_DELAY, OTEL_BSP_SCHEDULE_DELAY_DEFAULT,
+        BatchSpanProcessor, SimpleSpanProcessor, SpanProcessor, ThreadedBatchSpanProcessor,
+        OTEL_BSP_EXPORT_TIMEOUT, OTEL_BSP_MAX_EXPORT_BATCH_SIZE, OTEL_BSP_MAX_QUEUE_SIZE,
+        OTEL_BSP_MAX_QUEUE_SIZE_DEFAULT, OTEL_BSP_SCHEDULE_DELAY, OTEL_BSP_SCHEDULE_DELAY_DEFAULT,
     };
     use crate::export::trace::{ExportResult, SpanData, SpanExporter};
     use crate::runtime;
@@ -712,7 +959,7 @@ mod tests {
     use opentelemetry::trace::{SpanContext, SpanId, SpanKind, Status};
     use std::fmt::Debug;
     use std::future::Future;
-    use std::time::Duration;
+    use std::time::{Duration, Instant};
 
     #[test]
     fn simple_span_processor_on_end_calls_export() {
@@ -1042,4 +1289,73 @@ mod tests {
         let shutdown_res = processor.shutdown();
         assert!(shutdown_res.is_ok());
     }
+
+    #[test]
+    fn threaded_batch_span_processor_exports_full_batches() {
+        let exporter = InMemorySpanExporterBuilder::new().build();
+        let config = BatchConfig {
+            max_export_batch_size: 2,
+            scheduled_delay: Duration::from_secs(60 * 60 * 24), // set the tick to 24 hours so we know the spans are exported as a full batch
+            ..Default::default()
+        };
+        let processor = ThreadedBatchSpanProcessor::builder(exporter.clone())
+            .with_batch_config(config)
+            .build();
+        processor.on_end(new_test_export_span_data());
+        processor.on_end(new_test_export_span_data());
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while exporter.get_finished_spans().unwrap().len() < 2 {
+            assert!(
+                Instant::now() < deadline,
+                "timed out in 5 seconds. full batch was not exported"
+            );
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert!(processor.shutdown().is_ok());
+    }
+
+    #[tokio::test]
+    async fn threaded_batch_span_processor_flush_and_shutdown_in_runtime() {
+        let (exporter, mut export_receiver, mut shutdown_receiver) = new_tokio_test_exporter();
+        let config = BatchConfig {
+            scheduled_delay: Duration::from_secs(60 * 60 * 24), // set the tick to 24 hours so we know the span must be exported via force_flush
+            ..Default::default()
+        };
+        let processor = ThreadedBatchSpanProcessor::builder(exporter)
+            .with_batch_config(config)
+            .build();
+
+        // Both block the only thread of the runtime, which must not prevent the export.
+        processor.on_end(new_test_export_span_data());
+        assert!(processor.force_flush().is_ok());
+        assert_eq!(
+            export_receiver.try_recv().unwrap().span_context,
+            new_test_export_span_data().span_context
+        );
+
+        assert!(processor.shutdown().is_ok());
+        assert!(shutdown_receiver.try_recv().is_ok());
+        assert!(processor.shutdown().is_err());
+        assert!(processor.force_flush().is_err());
+    }
+
+    #[test]
+    fn threaded_batch_span_processor_export_timeout() {
+        let config = BatchConfig {
+            max_export_timeout: Duration::from_millis(5),
+            scheduled_delay: Duration::from_secs(60 * 60 * 24), // set the tick to 24 hours so we know the span must be exported via force_flush
+            ..Default::default()
+        };
+        let exporter = BlockingExporter {
+            delay_for: Duration::default(),
+            delay_fn: |_| futures_util::future::pending::<()>(),
+        };
+        let processor = ThreadedBatchSpanProcessor::builder(exporter)
+            .with_batch_config(config)
+            .build();
+        processor.on_end(new_test_export_span_data());
+        assert!(processor.force_flush().is_err());
+        assert!(processor.shutdown().is_ok());
+    }
 }
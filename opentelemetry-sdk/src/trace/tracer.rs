@@ -79,6 +79,7 @@ impl Tracer {
             .len()
             .saturating_sub(span_attributes_limit);
         attribute_options.truncate(span_attributes_limit);
+        span_limits.truncate_attribute_values(&mut attribute_options);
         let dropped_attributes_count = dropped_attributes_count as u32;
 
         // Links are available as Option<Vec<Link>> in the builder
@@ -99,6 +100,7 @@ impl Tracer {
                 let dropped_attributes_count =
                     link.attributes.len().saturating_sub(link_attributes_limit);
                 link.attributes.truncate(link_attributes_limit);
+                span_limits.truncate_attribute_values(&mut link.attributes);
                 link.dropped_attributes_count = dropped_attributes_count as u32;
             }
             SpanLinks {
@@ -131,6 +133,7 @@ impl Tracer {
                     .len()
                     .saturating_sub(event_attributes_limit);
                 event.attributes.truncate(event_attributes_limit);
+                span_limits.truncate_attribute_values(&mut event.attributes);
                 event.dropped_attributes_count = dropped_attributes_count as u32;
             }
             SpanEvents {
@@ -199,6 +202,17 @@ impl opentelemetry::trace::Tracer for Tracer {
             );
         }
 
+        // spans started while telemetry is suppressed, e.g. by exporters, are
+        // not recorded, but keep the parent span context for propagation
+        if parent_cx.is_telemetry_suppressed() {
+            let span_context = if parent_cx.has_active_span() {
+                parent_cx.span().span_context().clone()
+            } else {
+                SpanContext::empty_context()
+            };
+            return Span::new(span_context, None, self.clone(), SpanLimits::default());
+        }
+
         let config = provider.config();
         let span_id = builder
             .span_id
@@ -400,4 +414,26 @@ mod tests {
 
         assert!(!span.span_context().is_sampled());
     }
+
+    #[test]
+    fn suppressed_telemetry_spans_are_not_recording() {
+        let tracer_provider = crate::trace::TracerProvider::builder().build();
+        let tracer = tracer_provider.tracer("test");
+        let parent_span_context = SpanContext::new(
+            TraceId::from_u128(1),
+            SpanId::from_u64(1),
+            TraceFlags::SAMPLED,
+            true,
+            Default::default(),
+        );
+
+        let _attached = Context::new()
+            .with_remote_span_context(parent_span_context.clone())
+            .with_telemetry_suppressed()
+            .attach();
+        let span = tracer.start("must_not_be_recorded");
+
+        assert!(!span.is_recording());
+        assert_eq!(span.span_context(), &parent_span_context);
+    }
 }
@@ -7,3 +7,48 @@ pub fn tokio_interval_stream(
 ) -> tokio_stream::wrappers::IntervalStream {
     tokio_stream::wrappers::IntervalStream::new(tokio::time::interval(period))
 }
+
+/// Drives `future` to completion on the current thread like
+/// `futures_executor::block_on`, giving up once `timeout` elapsed.
+///
+/// This lets processors owning a thread bound the time spent exporting
+/// without an async runtime providing timers.
+#[cfg(any(feature = "trace", feature = "logs"))]
+pub(crate) fn block_on_with_timeout<F: std::future::Future>(
+    future: F,
+    timeout: std::time::Duration,
+) -> Option<F::Output> {
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::thread::{self, Thread};
+    use std::time::Instant;
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let deadline = Instant::now() + timeout;
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    futures_util::pin_mut!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return Some(output);
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return None;
+        }
+        // Wakes up when the future is woken, the timeout elapsed or spuriously,
+        // after which the future is polled again.
+        thread::park_timeout(deadline - now);
+    }
+}
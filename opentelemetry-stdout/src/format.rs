@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use std::io::{self, Write};
+use std::time::SystemTime;
+
+/// The layout exported telemetry is written in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OutputFormat {
+    /// Multi-line human readable output, one block per exported item.
+    #[default]
+    Pretty,
+    /// Single-line human readable output, one line per span, log record or
+    /// metric data point.
+    Compact,
+    /// One [OTLP JSON] encoded export request per line, which can be replayed
+    /// into a collector.
+    ///
+    /// Requires the `otlp-json` feature.
+    ///
+    /// [OTLP JSON]: https://opentelemetry.io/docs/specs/otlp/#json-protobuf-encoding
+    #[cfg(feature = "otlp-json")]
+    OtlpJson,
+}
+
+/// The sink exporters write to.
+pub(crate) type Writer = Box<dyn Write + Send>;
+
+pub(crate) fn stdout() -> Writer {
+    Box::new(io::stdout())
+}
+
+pub(crate) fn format_time(time: SystemTime) -> impl std::fmt::Display {
+    let datetime: DateTime<Utc> = time.into();
+    datetime.format("%Y-%m-%d %H:%M:%S%.6f")
+}
+
+/// Writes `value` as a single line of JSON.
+#[cfg(feature = "otlp-json")]
+pub(crate) fn write_json_line<T: serde::Serialize>(
+    writer: &mut dyn Write,
+    value: &T,
+) -> io::Result<()> {
+    serde_json::to_writer(&mut *writer, value)?;
+    writeln!(writer)
+}
@@ -14,6 +14,24 @@
 //! The following feature flags generate additional code and types:
 //! * `populate-logs-event-name`: Enables sending `LogRecord::event_name` as an attribute
 //!    with the key `name`
+//! * `otlp-json`: Enables [`OutputFormat::OtlpJson`], writing OTLP JSON encoded export requests.
+//!
+//! # Output
+//!
+//! Exporters write to stdout by default, any [`std::io::Write`] sink can be
+//! configured on their builders along with the [`OutputFormat`]:
+//!
+//! ```no_run
+//! # #[cfg(feature = "trace")]
+//! # {
+//! use opentelemetry_stdout::{OutputFormat, SpanExporter};
+//!
+//! let exporter = SpanExporter::builder()
+//!     .with_writer(std::io::stderr())
+//!     .with_format(OutputFormat::Compact)
+//!     .build();
+//! # }
+//! ```
 //!
 //! # Examples
 //!
@@ -66,6 +84,8 @@
 )]
 
 pub(crate) mod common;
+mod format;
+pub use format::OutputFormat;
 
 #[cfg(feature = "metrics")]
 mod metrics;
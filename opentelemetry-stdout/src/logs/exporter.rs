@@ -1,25 +1,31 @@
+use crate::format::{self, format_time, OutputFormat, Writer};
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
 use core::fmt;
-use opentelemetry::logs::LogResult;
+use opentelemetry::logs::{AnyValue, LogError, LogResult};
 use opentelemetry_sdk::export::logs::LogBatch;
 use opentelemetry_sdk::Resource;
-use std::sync::atomic;
+use std::io::{self, Write};
+use std::sync::{atomic, Mutex};
 
 /// An OpenTelemetry exporter that writes Logs to stdout on export.
 pub struct LogExporter {
     resource: Resource,
     is_shutdown: atomic::AtomicBool,
     resource_emitted: bool,
+    writer: Mutex<Writer>,
+    format: OutputFormat,
+}
+
+impl LogExporter {
+    /// Create a builder to configure this exporter.
+    pub fn builder() -> LogExporterBuilder {
+        LogExporterBuilder::default()
+    }
 }
 
 impl Default for LogExporter {
     fn default() -> Self {
-        LogExporter {
-            resource: Resource::default(),
-            is_shutdown: atomic::AtomicBool::new(false),
-            resource_emitted: false,
-        }
+        LogExporterBuilder::default().build()
     }
 }
 
@@ -35,25 +41,24 @@ impl opentelemetry_sdk::export::logs::LogExporter for LogExporter {
     async fn export(&mut self, batch: LogBatch<'_>) -> LogResult<()> {
         if self.is_shutdown.load(atomic::Ordering::SeqCst) {
             return Err("exporter is shut down".into());
-        } else {
-            println!("Logs");
-            if self.resource_emitted {
-                print_logs(batch);
-            } else {
-                self.resource_emitted = true;
-                println!("Resource");
-                if let Some(schema_url) = self.resource.schema_url() {
-                    println!("\t Resource SchemaUrl: {:?}", schema_url);
-                }
-                self.resource.iter().for_each(|(k, v)| {
-                    println!("\t ->  {}={:?}", k, v);
-                });
-
-                print_logs(batch);
-            }
+        }
 
-            Ok(())
+        let emit_resource = !self.resource_emitted;
+        self.resource_emitted = true;
+        let writer = self
+            .writer
+            .get_mut()
+            .map_err(|_| LogError::from("exporter writer is poisoned"))?;
+        match self.format {
+            OutputFormat::Pretty => {
+                print_logs(writer, emit_resource.then_some(&self.resource), batch)
+            }
+            OutputFormat::Compact => print_compact_logs(writer, batch),
+            #[cfg(feature = "otlp-json")]
+            OutputFormat::OtlpJson => print_otlp_json_logs(writer, &self.resource, batch),
         }
+        .and_then(|_| writer.flush())
+        .map_err(|err| LogError::Other(Box::new(err)))
     }
 
     fn shutdown(&mut self) {
@@ -65,44 +70,159 @@ impl opentelemetry_sdk::export::logs::LogExporter for LogExporter {
     }
 }
 
-fn print_logs(batch: LogBatch<'_>) {
+fn print_logs(
+    w: &mut dyn Write,
+    resource: Option<&Resource>,
+    batch: LogBatch<'_>,
+) -> io::Result<()> {
+    writeln!(w, "Logs")?;
+    if let Some(resource) = resource {
+        writeln!(w, "Resource")?;
+        if let Some(schema_url) = resource.schema_url() {
+            writeln!(w, "\t Resource SchemaUrl: {:?}", schema_url)?;
+        }
+        for (k, v) in resource.iter() {
+            writeln!(w, "\t ->  {}={:?}", k, v)?;
+        }
+    }
+
     for (i, log) in batch.iter().enumerate() {
-        println!("Log #{}", i);
+        writeln!(w, "Log #{}", i)?;
         let (record, _library) = log;
         if let Some(event_name) = record.event_name {
-            println!("\t EventName: {:?}", event_name);
+            writeln!(w, "\t EventName: {:?}", event_name)?;
         }
         if let Some(target) = &record.target {
-            println!("\t Target (Scope): {:?}", target);
+            writeln!(w, "\t Target (Scope): {:?}", target)?;
         }
         if let Some(trace_context) = &record.trace_context {
-            println!("\t TraceId: {:?}", trace_context.trace_id);
-            println!("\t SpanId: {:?}", trace_context.span_id);
+            writeln!(w, "\t TraceId: {:?}", trace_context.trace_id)?;
+            writeln!(w, "\t SpanId: {:?}", trace_context.span_id)?;
         }
         if let Some(timestamp) = record.timestamp {
-            let datetime: DateTime<Utc> = timestamp.into();
-            println!("\t Timestamp: {}", datetime.format("%Y-%m-%d %H:%M:%S%.6f"));
+            writeln!(w, "\t Timestamp: {}", format_time(timestamp))?;
         }
         if let Some(timestamp) = record.observed_timestamp {
-            let datetime: DateTime<Utc> = timestamp.into();
-            println!(
-                "\t Observed Timestamp: {}",
-                datetime.format("%Y-%m-%d %H:%M:%S%.6f")
-            );
+            writeln!(w, "\t Observed Timestamp: {}", format_time(timestamp))?;
         }
         if let Some(severity) = record.severity_text {
-            println!("\t SeverityText: {:?}", severity);
+            writeln!(w, "\t SeverityText: {:?}", severity)?;
         }
         if let Some(severity) = record.severity_number {
-            println!("\t SeverityNumber: {:?}", severity);
+            writeln!(w, "\t SeverityNumber: {:?}", severity)?;
         }
         if let Some(body) = &record.body {
-            println!("\t Body: {:?}", body);
+            writeln!(w, "\t Body: {:?}", body)?;
         }
 
-        println!("\t Attributes:");
+        writeln!(w, "\t Attributes:")?;
         for (k, v) in record.attributes_iter() {
-            println!("\t\t ->  {}: {:?}", k, v);
+            writeln!(w, "\t\t ->  {}: {:?}", k, v)?;
         }
     }
+    Ok(())
+}
+
+fn print_compact_logs(w: &mut dyn Write, batch: LogBatch<'_>) -> io::Result<()> {
+    for (record, library) in batch.iter() {
+        match record.timestamp.or(record.observed_timestamp) {
+            Some(timestamp) => write!(w, "{}", format_time(timestamp))?,
+            None => write!(w, "-")?,
+        }
+        match (record.severity_text, record.severity_number) {
+            (Some(text), _) => write!(w, " {}", text)?,
+            (None, Some(number)) => write!(w, " {}", number.name())?,
+            (None, None) => write!(w, " -")?,
+        }
+        write!(
+            w,
+            " {}",
+            record.target.as_deref().unwrap_or(library.name.as_ref())
+        )?;
+        if let Some(body) = &record.body {
+            write!(w, " {}", CompactValue(body))?;
+        }
+        if let Some(trace_context) = &record.trace_context {
+            write!(
+                w,
+                " trace_id={} span_id={}",
+                trace_context.trace_id, trace_context.span_id
+            )?;
+        }
+        for (k, v) in record.attributes_iter() {
+            write!(w, " {}={}", k, CompactValue(v))?;
+        }
+        writeln!(w)?;
+    }
+    Ok(())
+}
+
+/// Displays strings and scalars of a log value without their variant names.
+struct CompactValue<'a>(&'a AnyValue);
+
+impl fmt::Display for CompactValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            AnyValue::Int(value) => write!(f, "{}", value),
+            AnyValue::Double(value) => write!(f, "{}", value),
+            AnyValue::String(value) => write!(f, "{:?}", value.as_str()),
+            AnyValue::Boolean(value) => write!(f, "{}", value),
+            value => write!(f, "{:?}", value),
+        }
+    }
+}
+
+#[cfg(feature = "otlp-json")]
+fn print_otlp_json_logs(
+    w: &mut dyn Write,
+    resource: &Resource,
+    batch: LogBatch<'_>,
+) -> io::Result<()> {
+    use opentelemetry_proto::tonic::collector::logs::v1::ExportLogsServiceRequest;
+    use opentelemetry_proto::transform::logs::tonic::group_logs_by_resource_and_scope;
+
+    let request = ExportLogsServiceRequest {
+        resource_logs: group_logs_by_resource_and_scope(batch, &resource.into()),
+    };
+    format::write_json_line(w, &request)
+}
+
+/// Configuration for the stdout log exporter
+#[derive(Default)]
+pub struct LogExporterBuilder {
+    writer: Option<Writer>,
+    format: OutputFormat,
+}
+
+impl LogExporterBuilder {
+    /// Write logs to `writer` instead of stdout
+    pub fn with_writer(mut self, writer: impl Write + Send + 'static) -> Self {
+        self.writer = Some(Box::new(writer));
+        self
+    }
+
+    /// Set the format logs are written in, [`OutputFormat::Pretty`] by default
+    pub fn with_format(mut self, format: OutputFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Create a log exporter with the current configuration
+    pub fn build(self) -> LogExporter {
+        LogExporter {
+            resource: Resource::default(),
+            is_shutdown: atomic::AtomicBool::new(false),
+            resource_emitted: false,
+            writer: Mutex::new(self.writer.unwrap_or_else(format::stdout)),
+            format: self.format,
+        }
+    }
+}
+
+impl fmt::Debug for LogExporterBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LogExporterBuilder")
+            .field("format", &self.format)
+            .finish()
+    }
 }
@@ -1,5 +1,5 @@
+use crate::format::{self, format_time, OutputFormat, Writer};
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
 use core::{f64, fmt};
 use opentelemetry::metrics::{MetricsError, Result};
 use opentelemetry_sdk::metrics::{
@@ -12,13 +12,16 @@ use opentelemetry_sdk::metrics::{
     Aggregation, InstrumentKind,
 };
 use std::fmt::Debug;
-use std::sync::atomic;
+use std::io::{self, Write};
+use std::sync::{atomic, Mutex};
 
 /// An OpenTelemetry exporter that writes to stdout on export.
 pub struct MetricsExporter {
     is_shutdown: atomic::AtomicBool,
     temporality_selector: Box<dyn TemporalitySelector>,
     aggregation_selector: Box<dyn AggregationSelector>,
+    writer: Mutex<Writer>,
+    format: OutputFormat,
 }
 
 impl MetricsExporter {
@@ -58,23 +61,30 @@ impl PushMetricsExporter for MetricsExporter {
         if self.is_shutdown.load(atomic::Ordering::SeqCst) {
             Err(MetricsError::Other("exporter is shut down".into()))
         } else {
-            println!("Metrics");
-            println!("Resource");
-            if let Some(schema_url) = metrics.resource.schema_url() {
-                println!("\tResource SchemaUrl: {:?}", schema_url);
+            let mut writer = self
+                .writer
+                .lock()
+                .map_err(|_| MetricsError::Other("exporter writer is poisoned".into()))?;
+            let writer = &mut **writer;
+            match self.format {
+                OutputFormat::Pretty => print_resource_metrics(writer, metrics),
+                OutputFormat::Compact => print_compact_metrics(writer, &metrics.scope_metrics),
+                #[cfg(feature = "otlp-json")]
+                OutputFormat::OtlpJson => print_otlp_json_metrics(writer, metrics),
             }
-
-            metrics.resource.iter().for_each(|(k, v)| {
-                println!("\t ->  {}={:?}", k, v);
-            });
-            print_metrics(&metrics.scope_metrics);
-            Ok(())
+            .and_then(|_| writer.flush())
+            .map_err(|err| MetricsError::Other(err.to_string()))
         }
     }
 
     async fn force_flush(&self) -> Result<()> {
-        // exporter holds no state, nothing to flush
-        Ok(())
+        let mut writer = self
+            .writer
+            .lock()
+            .map_err(|_| MetricsError::Other("exporter writer is poisoned".into()))?;
+        writer
+            .flush()
+            .map_err(|err| MetricsError::Other(err.to_string()))
     }
 
     fn shutdown(&self) -> Result<()> {
@@ -83,151 +93,364 @@ impl PushMetricsExporter for MetricsExporter {
     }
 }
 
-fn print_metrics(metrics: &[ScopeMetrics]) {
+fn print_resource_metrics(w: &mut dyn Write, metrics: &data::ResourceMetrics) -> io::Result<()> {
+    writeln!(w, "Metrics")?;
+    writeln!(w, "Resource")?;
+    if let Some(schema_url) = metrics.resource.schema_url() {
+        writeln!(w, "\tResource SchemaUrl: {:?}", schema_url)?;
+    }
+
+    for (k, v) in metrics.resource.iter() {
+        writeln!(w, "\t ->  {}={:?}", k, v)?;
+    }
+    print_metrics(w, &metrics.scope_metrics)
+}
+
+fn print_metrics(w: &mut dyn Write, metrics: &[ScopeMetrics]) -> io::Result<()> {
     for (i, metric) in metrics.iter().enumerate() {
-        println!("\tInstrumentation Scope #{}", i);
-        println!("\t\tName         : {}", &metric.scope.name);
+        writeln!(w, "\tInstrumentation Scope #{}", i)?;
+        writeln!(w, "\t\tName         : {}", &metric.scope.name)?;
         if let Some(version) = &metric.scope.version {
-            println!("\t\tVersion  : {:?}", version);
+            writeln!(w, "\t\tVersion  : {:?}", version)?;
         }
         if let Some(schema_url) = &metric.scope.schema_url {
-            println!("\t\tSchemaUrl: {:?}", schema_url);
+            writeln!(w, "\t\tSchemaUrl: {:?}", schema_url)?;
         }
-        metric
-            .scope
-            .attributes
-            .iter()
-            .enumerate()
-            .for_each(|(index, kv)| {
-                if index == 0 {
-                    println!("\t\tScope Attributes:");
-                }
-                println!("\t\t\t ->  {}: {}", kv.key, kv.value);
-            });
-
-        metric.metrics.iter().enumerate().for_each(|(i, metric)| {
-            println!("Metric #{}", i);
-            println!("\t\tName         : {}", &metric.name);
-            println!("\t\tDescription  : {}", &metric.description);
-            println!("\t\tUnit         : {}", &metric.unit);
+        for (index, kv) in metric.scope.attributes.iter().enumerate() {
+            if index == 0 {
+                writeln!(w, "\t\tScope Attributes:")?;
+            }
+            writeln!(w, "\t\t\t ->  {}: {}", kv.key, kv.value)?;
+        }
+
+        for (i, metric) in metric.metrics.iter().enumerate() {
+            writeln!(w, "Metric #{}", i)?;
+            writeln!(w, "\t\tName         : {}", &metric.name)?;
+            writeln!(w, "\t\tDescription  : {}", &metric.description)?;
+            writeln!(w, "\t\tUnit         : {}", &metric.unit)?;
 
             let data = metric.data.as_any();
             if let Some(hist) = data.downcast_ref::<data::Histogram<u64>>() {
-                println!("\t\tType         : Histogram");
-                print_histogram(hist);
+                writeln!(w, "\t\tType         : Histogram")?;
+                print_histogram(w, hist)?;
+            } else if let Some(hist) = data.downcast_ref::<data::Histogram<i64>>() {
+                writeln!(w, "\t\tType         : Histogram")?;
+                print_histogram(w, hist)?;
             } else if let Some(hist) = data.downcast_ref::<data::Histogram<f64>>() {
-                println!("\t\tType         : Histogram");
-                print_histogram(hist);
+                writeln!(w, "\t\tType         : Histogram")?;
+                print_histogram(w, hist)?;
             } else if let Some(_hist) = data.downcast_ref::<data::ExponentialHistogram<u64>>() {
-                println!("\t\tType         : Exponential Histogram");
+                writeln!(w, "\t\tType         : Exponential Histogram")?;
+                // TODO
+            } else if let Some(_hist) = data.downcast_ref::<data::ExponentialHistogram<i64>>() {
+                writeln!(w, "\t\tType         : Exponential Histogram")?;
                 // TODO
             } else if let Some(_hist) = data.downcast_ref::<data::ExponentialHistogram<f64>>() {
-                println!("\t\tType         : Exponential Histogram");
+                writeln!(w, "\t\tType         : Exponential Histogram")?;
                 // TODO
+            } else if let Some(summary) = data.downcast_ref::<data::Summary<u64>>() {
+                writeln!(w, "\t\tType         : Summary")?;
+                print_summary(w, summary)?;
+            } else if let Some(summary) = data.downcast_ref::<data::Summary<i64>>() {
+                writeln!(w, "\t\tType         : Summary")?;
+                print_summary(w, summary)?;
+            } else if let Some(summary) = data.downcast_ref::<data::Summary<f64>>() {
+                writeln!(w, "\t\tType         : Summary")?;
+                print_summary(w, summary)?;
             } else if let Some(sum) = data.downcast_ref::<data::Sum<u64>>() {
-                println!("\t\tType         : Sum");
-                print_sum(sum);
+                writeln!(w, "\t\tType         : Sum")?;
+                print_sum(w, sum)?;
             } else if let Some(sum) = data.downcast_ref::<data::Sum<i64>>() {
-                println!("\t\tType         : Sum");
-                print_sum(sum);
+                writeln!(w, "\t\tType         : Sum")?;
+                print_sum(w, sum)?;
             } else if let Some(sum) = data.downcast_ref::<data::Sum<f64>>() {
-                println!("\t\tType         : Sum");
-                print_sum(sum);
+                writeln!(w, "\t\tType         : Sum")?;
+                print_sum(w, sum)?;
             } else if let Some(gauge) = data.downcast_ref::<data::Gauge<u64>>() {
-                println!("\t\tType         : Gauge");
-                print_gauge(gauge);
+                writeln!(w, "\t\tType         : Gauge")?;
+                print_gauge(w, gauge)?;
             } else if let Some(gauge) = data.downcast_ref::<data::Gauge<i64>>() {
-                println!("\t\tType         : Gauge");
-                print_gauge(gauge);
+                writeln!(w, "\t\tType         : Gauge")?;
+                print_gauge(w, gauge)?;
             } else if let Some(gauge) = data.downcast_ref::<data::Gauge<f64>>() {
-                println!("\t\tType         : Gauge");
-                print_gauge(gauge);
+                writeln!(w, "\t\tType         : Gauge")?;
+                print_gauge(w, gauge)?;
             } else {
-                println!("Unsupported data type");
+                writeln!(w, "Unsupported data type")?;
             }
-        });
+        }
     }
+    Ok(())
 }
 
-fn print_sum<T: Debug>(sum: &data::Sum<T>) {
-    println!("\t\tSum DataPoints");
-    println!("\t\tMonotonic    : {}", sum.is_monotonic);
+fn print_sum<T: Debug>(w: &mut dyn Write, sum: &data::Sum<T>) -> io::Result<()> {
+    writeln!(w, "\t\tSum DataPoints")?;
+    writeln!(w, "\t\tMonotonic    : {}", sum.is_monotonic)?;
     if sum.temporality == data::Temporality::Cumulative {
-        println!("\t\tTemporality  : Cumulative");
+        writeln!(w, "\t\tTemporality  : Cumulative")?;
     } else {
-        println!("\t\tTemporality  : Delta");
+        writeln!(w, "\t\tTemporality  : Delta")?;
     }
-    print_data_points(&sum.data_points);
+    print_data_points(w, &sum.data_points)?;
+    Ok(())
 }
 
-fn print_gauge<T: Debug>(gauge: &data::Gauge<T>) {
-    println!("\t\tGauge DataPoints");
-    print_data_points(&gauge.data_points);
+fn print_gauge<T: Debug>(w: &mut dyn Write, gauge: &data::Gauge<T>) -> io::Result<()> {
+    writeln!(w, "\t\tGauge DataPoints")?;
+    print_data_points(w, &gauge.data_points)?;
+    Ok(())
 }
 
-fn print_histogram<T: Debug>(histogram: &data::Histogram<T>) {
+fn print_histogram<T: Debug>(w: &mut dyn Write, histogram: &data::Histogram<T>) -> io::Result<()> {
     if histogram.temporality == data::Temporality::Cumulative {
-        println!("\t\tTemporality  : Cumulative");
+        writeln!(w, "\t\tTemporality  : Cumulative")?;
     } else {
-        println!("\t\tTemporality  : Delta");
+        writeln!(w, "\t\tTemporality  : Delta")?;
     }
-    println!("\t\tHistogram DataPoints");
-    print_hist_data_points(&histogram.data_points);
+    writeln!(w, "\t\tHistogram DataPoints")?;
+    print_hist_data_points(w, &histogram.data_points)?;
+    Ok(())
 }
 
-fn print_data_points<T: Debug>(data_points: &[data::DataPoint<T>]) {
+fn print_summary<T: Debug>(w: &mut dyn Write, summary: &data::Summary<T>) -> io::Result<()> {
+    if summary.temporality == data::Temporality::Cumulative {
+        writeln!(w, "\t\tTemporality  : Cumulative")?;
+    } else {
+        writeln!(w, "\t\tTemporality  : Delta")?;
+    }
+    writeln!(w, "\t\tSummary DataPoints")?;
+    print_summary_data_points(w, &summary.data_points)?;
+    Ok(())
+}
+
+fn print_data_points<T: Debug>(
+    w: &mut dyn Write,
+    data_points: &[data::DataPoint<T>],
+) -> io::Result<()> {
     for (i, data_point) in data_points.iter().enumerate() {
-        println!("\t\tDataPoint #{}", i);
+        writeln!(w, "\t\tDataPoint #{}", i)?;
         if let Some(start_time) = data_point.start_time {
-            let datetime: DateTime<Utc> = start_time.into();
-            println!(
-                "\t\t\tStartTime    : {}",
-                datetime.format("%Y-%m-%d %H:%M:%S%.6f")
-            );
+            writeln!(w, "\t\t\tStartTime    : {}", format_time(start_time))?;
         }
         if let Some(end_time) = data_point.time {
-            let datetime: DateTime<Utc> = end_time.into();
-            println!(
-                "\t\t\tEndTime      : {}",
-                datetime.format("%Y-%m-%d %H:%M:%S%.6f")
-            );
+            writeln!(w, "\t\t\tEndTime      : {}", format_time(end_time))?;
+        }
+        writeln!(w, "\t\t\tValue        : {:#?}", data_point.value)?;
+        writeln!(w, "\t\t\tAttributes   :")?;
+        for kv in data_point.attributes.iter() {
+            writeln!(w, "\t\t\t\t ->  {}: {}", kv.key, kv.value.as_str())?;
+        }
+    }
+    Ok(())
+}
+
+fn print_hist_data_points<T: Debug>(
+    w: &mut dyn Write,
+    data_points: &[data::HistogramDataPoint<T>],
+) -> io::Result<()> {
+    for (i, data_point) in data_points.iter().enumerate() {
+        writeln!(w, "\t\tDataPoint #{}", i)?;
+        writeln!(
+            w,
+            "\t\t\tStartTime    : {}",
+            format_time(data_point.start_time)
+        )?;
+        writeln!(w, "\t\t\tEndTime      : {}", format_time(data_point.time))?;
+        writeln!(w, "\t\t\tCount        : {}", data_point.count)?;
+        writeln!(w, "\t\t\tSum          : {:?}", data_point.sum)?;
+        if let Some(min) = &data_point.min {
+            writeln!(w, "\t\t\tMin          : {:?}", min)?;
         }
-        println!("\t\t\tValue        : {:#?}", data_point.value);
-        println!("\t\t\tAttributes   :");
+
+        if let Some(max) = &data_point.max {
+            writeln!(w, "\t\t\tMax          : {:?}", max)?;
+        }
+
+        writeln!(w, "\t\t\tAttributes   :")?;
         for kv in data_point.attributes.iter() {
-            println!("\t\t\t\t ->  {}: {}", kv.key, kv.value.as_str());
+            writeln!(w, "\t\t\t\t ->  {}: {}", kv.key, kv.value.as_str())?;
         }
     }
+    Ok(())
 }
 
-fn print_hist_data_points<T: Debug>(data_points: &[data::HistogramDataPoint<T>]) {
+fn print_summary_data_points<T: Debug>(
+    w: &mut dyn Write,
+    data_points: &[data::SummaryDataPoint<T>],
+) -> io::Result<()> {
     for (i, data_point) in data_points.iter().enumerate() {
-        println!("\t\tDataPoint #{}", i);
-        let datetime: DateTime<Utc> = data_point.start_time.into();
-        println!(
+        writeln!(w, "\t\tDataPoint #{}", i)?;
+        writeln!(
+            w,
             "\t\t\tStartTime    : {}",
-            datetime.format("%Y-%m-%d %H:%M:%S%.6f")
-        );
-        let datetime: DateTime<Utc> = data_point.time.into();
-        println!(
-            "\t\t\tEndTime      : {}",
-            datetime.format("%Y-%m-%d %H:%M:%S%.6f")
-        );
-        println!("\t\t\tCount        : {}", data_point.count);
-        println!("\t\t\tSum          : {:?}", data_point.sum);
+            format_time(data_point.start_time)
+        )?;
+        writeln!(w, "\t\t\tEndTime      : {}", format_time(data_point.time))?;
+        writeln!(w, "\t\t\tCount        : {}", data_point.count)?;
+        writeln!(w, "\t\t\tSum          : {:?}", data_point.sum)?;
         if let Some(min) = &data_point.min {
-            println!("\t\t\tMin          : {:?}", min);
+            writeln!(w, "\t\t\tMin          : {:?}", min)?;
         }
 
         if let Some(max) = &data_point.max {
-            println!("\t\t\tMax          : {:?}", max);
+            writeln!(w, "\t\t\tMax          : {:?}", max)?;
         }
 
-        println!("\t\t\tAttributes   :");
+        writeln!(w, "\t\t\tQuantiles    :")?;
+        for qv in data_point.quantile_values.iter() {
+            writeln!(w, "\t\t\t\t ->  {}: {}", qv.quantile, qv.value)?;
+        }
+
+        writeln!(w, "\t\t\tAttributes   :")?;
         for kv in data_point.attributes.iter() {
-            println!("\t\t\t\t ->  {}: {}", kv.key, kv.value.as_str());
+            writeln!(w, "\t\t\t\t ->  {}: {}", kv.key, kv.value.as_str())?;
+        }
+    }
+    Ok(())
+}
+
+fn print_compact_metrics(w: &mut dyn Write, metrics: &[ScopeMetrics]) -> io::Result<()> {
+    for scope_metrics in metrics {
+        for metric in &scope_metrics.metrics {
+            let name = &metric.name;
+            let data = metric.data.as_any();
+            if let Some(hist) = data.downcast_ref::<data::Histogram<u64>>() {
+                print_compact_hist_data_points(w, name, &hist.data_points)?;
+            } else if let Some(hist) = data.downcast_ref::<data::Histogram<i64>>() {
+                print_compact_hist_data_points(w, name, &hist.data_points)?;
+            } else if let Some(hist) = data.downcast_ref::<data::Histogram<f64>>() {
+                print_compact_hist_data_points(w, name, &hist.data_points)?;
+            } else if let Some(hist) = data.downcast_ref::<data::ExponentialHistogram<u64>>() {
+                print_compact_exp_hist_data_points(w, name, &hist.data_points)?;
+            } else if let Some(hist) = data.downcast_ref::<data::ExponentialHistogram<i64>>() {
+                print_compact_exp_hist_data_points(w, name, &hist.data_points)?;
+            } else if let Some(hist) = data.downcast_ref::<data::ExponentialHistogram<f64>>() {
+                print_compact_exp_hist_data_points(w, name, &hist.data_points)?;
+            } else if let Some(summary) = data.downcast_ref::<data::Summary<u64>>() {
+                print_compact_summary_data_points(w, name, &summary.data_points)?;
+            } else if let Some(summary) = data.downcast_ref::<data::Summary<i64>>() {
+                print_compact_summary_data_points(w, name, &summary.data_points)?;
+            } else if let Some(summary) = data.downcast_ref::<data::Summary<f64>>() {
+                print_compact_summary_data_points(w, name, &summary.data_points)?;
+            } else if let Some(sum) = data.downcast_ref::<data::Sum<u64>>() {
+                print_compact_data_points(w, name, "sum", &sum.data_points)?;
+            } else if let Some(sum) = data.downcast_ref::<data::Sum<i64>>() {
+                print_compact_data_points(w, name, "sum", &sum.data_points)?;
+            } else if let Some(sum) = data.downcast_ref::<data::Sum<f64>>() {
+                print_compact_data_points(w, name, "sum", &sum.data_points)?;
+            } else if let Some(gauge) = data.downcast_ref::<data::Gauge<u64>>() {
+                print_compact_data_points(w, name, "gauge", &gauge.data_points)?;
+            } else if let Some(gauge) = data.downcast_ref::<data::Gauge<i64>>() {
+                print_compact_data_points(w, name, "gauge", &gauge.data_points)?;
+            } else if let Some(gauge) = data.downcast_ref::<data::Gauge<f64>>() {
+                print_compact_data_points(w, name, "gauge", &gauge.data_points)?;
+            } else {
+                writeln!(w, "- metric {} unsupported", name)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_compact_attributes(
+    w: &mut dyn Write,
+    attributes: &[opentelemetry::KeyValue],
+) -> io::Result<()> {
+    for kv in attributes {
+        write!(w, " {}={}", kv.key, kv.value)?;
+    }
+    writeln!(w)
+}
+
+fn print_compact_data_points<T: Debug>(
+    w: &mut dyn Write,
+    name: &str,
+    kind: &str,
+    data_points: &[data::DataPoint<T>],
+) -> io::Result<()> {
+    for data_point in data_points {
+        match data_point.time {
+            Some(time) => write!(w, "{}", format_time(time))?,
+            None => write!(w, "-")?,
+        }
+        write!(w, " {} {} value={:?}", kind, name, data_point.value)?;
+        print_compact_attributes(w, &data_point.attributes)?;
+    }
+    Ok(())
+}
+
+fn print_compact_hist_data_points<T: Debug>(
+    w: &mut dyn Write,
+    name: &str,
+    data_points: &[data::HistogramDataPoint<T>],
+) -> io::Result<()> {
+    for data_point in data_points {
+        write!(
+            w,
+            "{} histogram {} count={} sum={:?}",
+            format_time(data_point.time),
+            name,
+            data_point.count,
+            data_point.sum
+        )?;
+        if let Some(min) = &data_point.min {
+            write!(w, " min={:?}", min)?;
+        }
+        if let Some(max) = &data_point.max {
+            write!(w, " max={:?}", max)?;
+        }
+        print_compact_attributes(w, &data_point.attributes)?;
+    }
+    Ok(())
+}
+
+fn print_compact_exp_hist_data_points<T: Debug>(
+    w: &mut dyn Write,
+    name: &str,
+    data_points: &[data::ExponentialHistogramDataPoint<T>],
+) -> io::Result<()> {
+    for data_point in data_points {
+        write!(
+            w,
+            "{} exponential_histogram {} count={} sum={:?} scale={}",
+            format_time(data_point.time),
+            name,
+            data_point.count,
+            data_point.sum,
+            data_point.scale
+        )?;
+        print_compact_attributes(w, &data_point.attributes)?;
+    }
+    Ok(())
+}
+
+fn print_compact_summary_data_points<T: Debug>(
+    w: &mut dyn Write,
+    name: &str,
+    data_points: &[data::SummaryDataPoint<T>],
+) -> io::Result<()> {
+    for data_point in data_points {
+        write!(
+            w,
+            "{} summary {} count={} sum={:?}",
+            format_time(data_point.time),
+            name,
+            data_point.count,
+            data_point.sum
+        )?;
+        for qv in data_point.quantile_values.iter() {
+            write!(w, " q{}={}", qv.quantile, qv.value)?;
         }
+        print_compact_attributes(w, &data_point.attributes)?;
     }
+    Ok(())
+}
+
+#[cfg(feature = "otlp-json")]
+fn print_otlp_json_metrics(w: &mut dyn Write, metrics: &data::ResourceMetrics) -> io::Result<()> {
+    use opentelemetry_proto::tonic::collector::metrics::v1::ExportMetricsServiceRequest;
+
+    format::write_json_line(w, &ExportMetricsServiceRequest::from(metrics))
 }
 
 /// Configuration for the stdout metrics exporter
@@ -235,9 +458,23 @@ fn print_hist_data_points<T: Debug>(data_points: &[data::HistogramDataPoint<T>])
 pub struct MetricsExporterBuilder {
     temporality_selector: Option<Box<dyn TemporalitySelector>>,
     aggregation_selector: Option<Box<dyn AggregationSelector>>,
+    writer: Option<Writer>,
+    format: OutputFormat,
 }
 
 impl MetricsExporterBuilder {
+    /// Write metrics to `writer` instead of stdout
+    pub fn with_writer(mut self, writer: impl Write + Send + 'static) -> Self {
+        self.writer = Some(Box::new(writer));
+        self
+    }
+
+    /// Set the format metrics are written in, [`OutputFormat::Pretty`] by default
+    pub fn with_format(mut self, format: OutputFormat) -> Self {
+        self.format = format;
+        self
+    }
+
     /// Set the temporality exporter for the exporter
     pub fn with_temporality_selector(
         mut self,
@@ -266,6 +503,8 @@ impl MetricsExporterBuilder {
                 .aggregation_selector
                 .unwrap_or_else(|| Box::new(DefaultAggregationSelector::new())),
             is_shutdown: atomic::AtomicBool::new(false),
+            writer: Mutex::new(self.writer.unwrap_or_else(format::stdout)),
+            format: self.format,
         }
     }
 }
@@ -1,9 +1,10 @@
-use chrono::{DateTime, Utc};
+use crate::format::{self, format_time, OutputFormat, Writer};
 use core::fmt;
 use futures_util::future::BoxFuture;
 use opentelemetry::trace::TraceError;
 use opentelemetry_sdk::export::{self, trace::ExportResult};
-use std::sync::atomic;
+use std::io::{self, Write};
+use std::sync::{atomic, Mutex};
 
 use opentelemetry_sdk::resource::Resource;
 
@@ -12,6 +13,15 @@ pub struct SpanExporter {
     resource: Resource,
     is_shutdown: atomic::AtomicBool,
     resource_emitted: bool,
+    writer: Mutex<Writer>,
+    format: OutputFormat,
+}
+
+impl SpanExporter {
+    /// Create a builder to configure this exporter.
+    pub fn builder() -> SpanExporterBuilder {
+        SpanExporterBuilder::default()
+    }
 }
 
 impl fmt::Debug for SpanExporter {
@@ -22,11 +32,7 @@ impl fmt::Debug for SpanExporter {
 
 impl Default for SpanExporter {
     fn default() -> Self {
-        SpanExporter {
-            resource: Resource::default(),
-            is_shutdown: atomic::AtomicBool::new(false),
-            resource_emitted: false,
-        }
+        SpanExporterBuilder::default().build()
     }
 }
 
@@ -34,29 +40,34 @@ impl opentelemetry_sdk::export::trace::SpanExporter for SpanExporter {
     /// Write Spans to stdout
     fn export(&mut self, batch: Vec<export::trace::SpanData>) -> BoxFuture<'static, ExportResult> {
         if self.is_shutdown.load(atomic::Ordering::SeqCst) {
-            Box::pin(std::future::ready(Err(TraceError::from(
+            return Box::pin(std::future::ready(Err(TraceError::from(
                 "exporter is shut down",
-            ))))
-        } else {
-            println!("Spans");
-            if self.resource_emitted {
-                print_spans(batch);
-            } else {
-                self.resource_emitted = true;
-                println!("Resource");
-                if let Some(schema_url) = self.resource.schema_url() {
-                    println!("\tResource SchemaUrl: {:?}", schema_url);
-                }
-
-                self.resource.iter().for_each(|(k, v)| {
-                    println!("\t ->  {}={:?}", k, v);
-                });
+            ))));
+        }
 
-                print_spans(batch);
+        let emit_resource = !self.resource_emitted;
+        self.resource_emitted = true;
+        let writer = match self.writer.get_mut() {
+            Ok(writer) => writer,
+            Err(_) => {
+                return Box::pin(std::future::ready(Err(TraceError::from(
+                    "exporter writer is poisoned",
+                ))))
             }
-
-            Box::pin(std::future::ready(Ok(())))
+        };
+        let result = match self.format {
+            OutputFormat::Pretty => {
+                print_spans(writer, emit_resource.then_some(&self.resource), batch)
+            }
+            OutputFormat::Compact => print_compact_spans(writer, batch),
+            #[cfg(feature = "otlp-json")]
+            OutputFormat::OtlpJson => print_otlp_json_spans(writer, &self.resource, batch),
         }
+        .and_then(|_| writer.flush());
+
+        Box::pin(std::future::ready(
+            result.map_err(|err| TraceError::Other(Box::new(err))),
+        ))
     }
 
     fn shutdown(&mut self) {
@@ -68,81 +79,283 @@ impl opentelemetry_sdk::export::trace::SpanExporter for SpanExporter {
     }
 }
 
-fn print_spans(batch: Vec<export::trace::SpanData>) {
+fn print_spans(
+    w: &mut dyn Write,
+    resource: Option<&Resource>,
+    batch: Vec<export::trace::SpanData>,
+) -> io::Result<()> {
+    writeln!(w, "Spans")?;
+    if let Some(resource) = resource {
+        writeln!(w, "Resource")?;
+        if let Some(schema_url) = resource.schema_url() {
+            writeln!(w, "\tResource SchemaUrl: {:?}", schema_url)?;
+        }
+
+        for (k, v) in resource.iter() {
+            writeln!(w, "\t ->  {}={:?}", k, v)?;
+        }
+    }
+
     for (i, span) in batch.into_iter().enumerate() {
-        println!("Span #{}", i);
-        println!("\tInstrumentation Scope");
-        println!("\t\tName         : {:?}", &span.instrumentation_lib.name);
+        writeln!(w, "Span #{}", i)?;
+        writeln!(w, "\tInstrumentation Scope")?;
+        writeln!(w, "\t\tName         : {:?}", &span.instrumentation_lib.name)?;
         if let Some(version) = &span.instrumentation_lib.version {
-            println!("\t\tVersion  : {:?}", version);
+            writeln!(w, "\t\tVersion  : {:?}", version)?;
         }
         if let Some(schema_url) = &span.instrumentation_lib.schema_url {
-            println!("\t\tSchemaUrl: {:?}", schema_url);
+            writeln!(w, "\t\tSchemaUrl: {:?}", schema_url)?;
         }
-        span.instrumentation_lib
-            .attributes
-            .iter()
-            .enumerate()
-            .for_each(|(index, kv)| {
-                if index == 0 {
-                    println!("\t\tScope Attributes:");
-                }
-                println!("\t\t\t ->  {}: {}", kv.key, kv.value);
-            });
-
-        println!();
-        println!("\tName        : {}", &span.name);
-        println!("\tTraceId     : {}", &span.span_context.trace_id());
-        println!("\tSpanId      : {}", &span.span_context.span_id());
-        println!("\tParentSpanId: {}", &span.parent_span_id);
-        println!("\tKind        : {:?}", &span.span_kind);
-
-        let datetime: DateTime<Utc> = span.start_time.into();
-        println!("\tStart time: {}", datetime.format("%Y-%m-%d %H:%M:%S%.6f"));
-        let datetime: DateTime<Utc> = span.end_time.into();
-        println!("\tEnd time: {}", datetime.format("%Y-%m-%d %H:%M:%S%.6f"));
-        println!("\tStatus: {:?}", &span.status);
+        for (index, kv) in span.instrumentation_lib.attributes.iter().enumerate() {
+            if index == 0 {
+                writeln!(w, "\t\tScope Attributes:")?;
+            }
+            writeln!(w, "\t\t\t ->  {}: {}", kv.key, kv.value)?;
+        }
+
+        writeln!(w)?;
+        writeln!(w, "\tName        : {}", &span.name)?;
+        writeln!(w, "\tTraceId     : {}", &span.span_context.trace_id())?;
+        writeln!(w, "\tSpanId      : {}", &span.span_context.span_id())?;
+        writeln!(w, "\tParentSpanId: {}", &span.parent_span_id)?;
+        writeln!(w, "\tKind        : {:?}", &span.span_kind)?;
+
+        writeln!(w, "\tStart time: {}", format_time(span.start_time))?;
+        writeln!(w, "\tEnd time: {}", format_time(span.end_time))?;
+        writeln!(w, "\tStatus: {:?}", &span.status)?;
 
         let mut print_header = true;
         for kv in span.attributes.iter() {
             if print_header {
-                println!("\tAttributes:");
+                writeln!(w, "\tAttributes:")?;
                 print_header = false;
             }
-            println!("\t\t ->  {}: {:?}", kv.key, kv.value);
+            writeln!(w, "\t\t ->  {}: {:?}", kv.key, kv.value)?;
         }
 
-        span.events.iter().enumerate().for_each(|(index, event)| {
+        for (index, event) in span.events.iter().enumerate() {
             if index == 0 {
-                println!("\tEvents:");
+                writeln!(w, "\tEvents:")?;
             }
-            println!("\tEvent #{}", index);
-            println!("\tName      : {}", event.name);
-            let datetime: DateTime<Utc> = event.timestamp.into();
-            println!("\tTimestamp : {}", datetime.format("%Y-%m-%d %H:%M:%S%.6f"));
+            writeln!(w, "\tEvent #{}", index)?;
+            writeln!(w, "\tName      : {}", event.name)?;
+            writeln!(w, "\tTimestamp : {}", format_time(event.timestamp))?;
 
-            event.attributes.iter().enumerate().for_each(|(index, kv)| {
+            for (index, kv) in event.attributes.iter().enumerate() {
                 if index == 0 {
-                    println!("\tAttributes:");
+                    writeln!(w, "\tAttributes:")?;
                 }
-                println!("\t\t ->  {}: {:?}", kv.key, kv.value);
-            });
-        });
+                writeln!(w, "\t\t ->  {}: {:?}", kv.key, kv.value)?;
+            }
+        }
 
-        span.links.iter().enumerate().for_each(|(index, link)| {
+        for (index, link) in span.links.iter().enumerate() {
             if index == 0 {
-                println!("\tLinks:");
+                writeln!(w, "\tLinks:")?;
             }
-            println!("\tLink #{}", index);
-            println!("\tTraceId: {}", link.span_context.trace_id());
-            println!("\tSpanId : {}", link.span_context.span_id());
+            writeln!(w, "\tLink #{}", index)?;
+            writeln!(w, "\tTraceId: {}", link.span_context.trace_id())?;
+            writeln!(w, "\tSpanId : {}", link.span_context.span_id())?;
 
-            link.attributes.iter().enumerate().for_each(|(index, kv)| {
+            for (index, kv) in link.attributes.iter().enumerate() {
                 if index == 0 {
-                    println!("\tAttributes:");
+                    writeln!(w, "\tAttributes:")?;
                 }
-                println!("\t\t ->  {}: {:?}", kv.key, kv.value);
-            });
-        });
+                writeln!(w, "\t\t ->  {}: {:?}", kv.key, kv.value)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_compact_spans(w: &mut dyn Write, batch: Vec<export::trace::SpanData>) -> io::Result<()> {
+    for span in batch {
+        let duration = span
+            .end_time
+            .duration_since(span.start_time)
+            .unwrap_or_default();
+        write!(
+            w,
+            "{} span {:?} trace_id={} span_id={} parent_span_id={} kind={:?} duration={:?} status={:?} scope={:?}",
+            format_time(span.start_time),
+            span.name,
+            span.span_context.trace_id(),
+            span.span_context.span_id(),
+            span.parent_span_id,
+            span.span_kind,
+            duration,
+            span.status,
+            span.instrumentation_lib.name,
+        )?;
+        for kv in span.attributes.iter() {
+            write!(w, " {}={}", kv.key, kv.value)?;
+        }
+        if !span.events.is_empty() {
+            write!(w, " events={}", span.events.len())?;
+        }
+        if !span.links.is_empty() {
+            write!(w, " links={}", span.links.len())?;
+        }
+        writeln!(w)?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "otlp-json")]
+fn print_otlp_json_spans(
+    w: &mut dyn Write,
+    resource: &Resource,
+    batch: Vec<export::trace::SpanData>,
+) -> io::Result<()> {
+    use opentelemetry_proto::tonic::collector::trace::v1::ExportTraceServiceRequest;
+    use opentelemetry_proto::transform::trace::tonic::group_spans_by_resource_and_scope;
+
+    let request = ExportTraceServiceRequest {
+        resource_spans: group_spans_by_resource_and_scope(batch, &resource.into()),
+    };
+    format::write_json_line(w, &request)
+}
+
+/// Configuration for the stdout span exporter
+#[derive(Default)]
+pub struct SpanExporterBuilder {
+    writer: Option<Writer>,
+    format: OutputFormat,
+}
+
+impl SpanExporterBuilder {
+    /// Write spans to `writer` instead of stdout
+    pub fn with_writer(mut self, writer: impl Write + Send + 'static) -> Self {
+        self.writer = Some(Box::new(writer));
+        self
+    }
+
+    /// Set the format spans are written in, [`OutputFormat::Pretty`] by default
+    pub fn with_format(mut self, format: OutputFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Create a span exporter with the current configuration
+    pub fn build(self) -> SpanExporter {
+        SpanExporter {
+            resource: Resource::default(),
+            is_shutdown: atomic::AtomicBool::new(false),
+            resource_emitted: false,
+            writer: Mutex::new(self.writer.unwrap_or_else(format::stdout)),
+            format: self.format,
+        }
+    }
+}
+
+impl fmt::Debug for SpanExporterBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpanExporterBuilder")
+            .field("format", &self.format)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::trace::{SpanContext, SpanId, SpanKind, Status, TraceFlags, TraceId};
+    use opentelemetry::KeyValue;
+    use opentelemetry_sdk::export::trace::SpanExporter as _;
+    use opentelemetry_sdk::trace::{SpanEvents, SpanLinks};
+    use std::sync::Arc;
+    use std::time::{Duration, SystemTime};
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Buffer {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    fn span_data(name: &'static str) -> export::trace::SpanData {
+        let start_time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        export::trace::SpanData {
+            span_context: SpanContext::new(
+                TraceId::from_bytes(1u128.to_be_bytes()),
+                SpanId::from_bytes(2u64.to_be_bytes()),
+                TraceFlags::SAMPLED,
+                false,
+                Default::default(),
+            ),
+            parent_span_id: SpanId::INVALID,
+            span_kind: SpanKind::Server,
+            name: name.into(),
+            start_time,
+            end_time: start_time + Duration::from_millis(5),
+            attributes: vec![KeyValue::new("http.route", "/users")],
+            dropped_attributes_count: 0,
+            events: SpanEvents::default(),
+            links: SpanLinks::default(),
+            status: Status::Unset,
+            instrumentation_lib: Default::default(),
+        }
+    }
+
+    fn export(format: OutputFormat, batch: Vec<export::trace::SpanData>) -> String {
+        let buffer = Buffer::default();
+        let mut exporter = SpanExporter::builder()
+            .with_writer(buffer.clone())
+            .with_format(format)
+            .build();
+        futures_executor::block_on(exporter.export(batch)).unwrap();
+        buffer.contents()
+    }
+
+    #[test]
+    fn pretty_to_writer() {
+        let output = export(OutputFormat::Pretty, vec![span_data("pretty")]);
+        assert!(output.starts_with("Spans\nResource\n"));
+        assert!(output.contains("\tName        : pretty\n"));
+    }
+
+    #[test]
+    fn compact_one_line_per_span() {
+        let output = export(
+            OutputFormat::Compact,
+            vec![span_data("first"), span_data("second")],
+        );
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0],
+            "2023-11-14 22:13:20.000000 span \"first\" trace_id=00000000000000000000000000000001 \
+             span_id=0000000000000002 parent_span_id=0000000000000000 kind=Server duration=5ms \
+             status=Unset scope=\"\" http.route=/users"
+        );
+    }
+
+    #[cfg(feature = "otlp-json")]
+    #[test]
+    fn otlp_json_lines() {
+        use opentelemetry_proto::tonic::collector::trace::v1::ExportTraceServiceRequest;
+
+        let output = export(OutputFormat::OtlpJson, vec![span_data("json")]);
+        let request: ExportTraceServiceRequest =
+            serde_json::from_str(output.lines().next().unwrap()).unwrap();
+        let span = &request.resource_spans[0].scope_spans[0].spans[0];
+        assert_eq!(span.name, "json");
+        assert_eq!(
+            span.trace_id,
+            TraceId::from_bytes(1u128.to_be_bytes()).to_bytes().to_vec()
+        );
+        assert!(output.contains("\"traceId\":\"00000000000000000000000000000001\""));
     }
 }
@@ -1,10 +1,9 @@
 mod env;
-mod model;
+pub(crate) mod model;
 mod uploader;
 
 use async_trait::async_trait;
 use futures_core::future::BoxFuture;
-use http::Uri;
 use model::endpoint::Endpoint;
 use opentelemetry::{global, trace::TraceError, KeyValue};
 use opentelemetry_http::HttpClient;
@@ -17,6 +16,7 @@ use opentelemetry_sdk::{
 };
 use opentelemetry_semantic_conventions as semcov;
 use std::borrow::Cow;
+use std::fmt;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
@@ -29,10 +29,41 @@ pub struct Exporter {
 }
 
 impl Exporter {
-    fn new(local_endpoint: Endpoint, client: Arc<dyn HttpClient>, collector_endpoint: Uri) -> Self {
+    fn new(local_endpoint: Endpoint, uploader: uploader::Uploader) -> Self {
         Exporter {
             local_endpoint,
-            uploader: uploader::Uploader::new(client, collector_endpoint),
+            uploader,
+        }
+    }
+}
+
+/// The format spans are encoded in when uploaded to the collector.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Encoding {
+    /// JSON encoded list of v2 spans, sent as `application/json`.
+    #[default]
+    JsonV2,
+    /// Protocol buffers encoded zipkin.proto3 `ListOfSpans`, sent as `application/x-protobuf`.
+    ///
+    /// Requires the `proto3` feature.
+    Proto3,
+}
+
+/// The compression applied to uploaded spans, sent as the `Content-Encoding` header.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Compression {
+    /// Compresses data using gzip.
+    ///
+    /// Requires the `gzip` feature.
+    Gzip,
+}
+
+impl fmt::Display for Compression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Compression::Gzip => write!(f, "gzip"),
         }
     }
 }
@@ -50,6 +81,8 @@ pub struct ZipkinPipelineBuilder {
     collector_endpoint: String,
     trace_config: Option<Config>,
     client: Option<Arc<dyn HttpClient>>,
+    encoding: Encoding,
+    compression: Option<Compression>,
 }
 
 impl Default for ZipkinPipelineBuilder {
@@ -80,6 +113,8 @@ impl Default for ZipkinPipelineBuilder {
             service_addr: None,
             collector_endpoint: env::get_endpoint(),
             trace_config: None,
+            encoding: Encoding::default(),
+            compression: None,
         }
     }
 }
@@ -123,18 +158,39 @@ impl ZipkinPipelineBuilder {
     }
 
     fn init_exporter_with_endpoint(self, endpoint: Endpoint) -> Result<Exporter, TraceError> {
-        if let Some(client) = self.client {
-            let exporter = Exporter::new(
-                endpoint,
-                client,
-                self.collector_endpoint
-                    .parse()
-                    .map_err::<Error, _>(Into::into)?,
-            );
-            Ok(exporter)
-        } else {
-            Err(Error::NoHttpClient.into())
+        let Some(client) = self.client else {
+            return Err(Error::NoHttpClient.into());
+        };
+        let collector_endpoint = self
+            .collector_endpoint
+            .parse()
+            .map_err::<Error, _>(Into::into)?;
+
+        let uploader = match self.encoding {
+            Encoding::JsonV2 => uploader::Uploader::new(client, collector_endpoint),
+            #[cfg(feature = "proto3")]
+            Encoding::Proto3 => uploader::Uploader::new_proto3(client, collector_endpoint),
+            #[cfg(not(feature = "proto3"))]
+            Encoding::Proto3 => {
+                return Err(Error::FeatureRequired {
+                    option: "proto3 encoding".into(),
+                    feature: "proto3",
+                }
+                .into())
+            }
+        };
+        if cfg!(not(feature = "gzip")) && self.compression == Some(Compression::Gzip) {
+            return Err(Error::FeatureRequired {
+                option: "gzip compression".into(),
+                feature: "gzip",
+            }
+            .into());
         }
+
+        Ok(Exporter::new(
+            endpoint,
+            uploader.with_compression(self.compression),
+        ))
     }
 
     /// Install the Zipkin trace exporter pipeline with a simple span processor.
@@ -194,6 +250,18 @@ impl ZipkinPipelineBuilder {
         self
     }
 
+    /// Assign the encoding of uploaded spans, [`Encoding::JsonV2`] by default.
+    pub fn with_encoding(mut self, encoding: Encoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Compress uploaded spans, spans are not compressed by default.
+    pub fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
     /// Assign the SDK trace configuration.
     pub fn with_trace_config(mut self, config: Config) -> Self {
         self.trace_config = Some(config);
@@ -242,6 +310,15 @@ pub enum Error {
     #[error("invalid uri")]
     InvalidUri(#[from] http::uri::InvalidUri),
 
+    /// An option was configured which requires a crate feature that is not enabled
+    #[error("{option} requires the {feature} feature to be enabled")]
+    FeatureRequired {
+        /// The configured option
+        option: String,
+        /// The feature to enable
+        feature: &'static str,
+    },
+
     /// Other errors
     #[error("export error: {0}")]
     Other(String),
@@ -1,16 +1,19 @@
 use opentelemetry::trace::Event;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::time::{Duration, SystemTime};
 
-#[derive(TypedBuilder, Clone, Debug, Serialize)]
+/// An event recorded on a span.
+#[derive(TypedBuilder, Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Annotation {
+pub struct Annotation {
+    /// Time of the event in microseconds since the unix epoch.
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    timestamp: Option<u64>,
+    pub timestamp: Option<u64>,
+    /// The event name.
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    value: Option<String>,
+    pub value: Option<String>,
 }
 
 /// Converts `Event` into an `annotation::Annotation`
@@ -1,21 +1,26 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
 
-#[derive(TypedBuilder, Clone, Debug, Serialize)]
+/// The network context of a service recording or taking part in a span.
+#[derive(TypedBuilder, Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Endpoint {
+pub struct Endpoint {
+    /// Lowercase name of the service.
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    service_name: Option<String>,
+    pub service_name: Option<String>,
+    /// IPv4 address of the service.
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    ipv4: Option<Ipv4Addr>,
+    pub ipv4: Option<Ipv4Addr>,
+    /// IPv6 address of the service.
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    ipv6: Option<Ipv6Addr>,
+    pub ipv6: Option<Ipv6Addr>,
+    /// Port of the service.
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    port: Option<u16>,
+    pub port: Option<u16>,
 }
 
 impl Endpoint {
@@ -1,19 +1,31 @@
+// The model is only part of the public API through the `testing` module.
+#![cfg_attr(not(feature = "testing"), allow(unreachable_pub))]
+
 use opentelemetry::{
     trace::{SpanKind, Status},
-    Key, KeyValue,
+    Key, KeyValue, Value,
 };
 use opentelemetry_sdk::export::trace::SpanData;
+use opentelemetry_semantic_conventions::attribute as semcov;
 use std::collections::HashMap;
+use std::net::IpAddr;
 use std::time::{Duration, SystemTime};
 
 pub(crate) mod annotation;
 pub(crate) mod endpoint;
+#[cfg(feature = "proto3")]
+pub(crate) mod proto3;
 pub(crate) mod span;
 
 use endpoint::Endpoint;
 
 const INSTRUMENTATION_LIBRARY_NAME: &str = "otel.library.name";
 const INSTRUMENTATION_LIBRARY_VERSION: &str = "otel.library.version";
+const INSTRUMENTATION_SCOPE_NAME: &str = "otel.scope.name";
+const INSTRUMENTATION_SCOPE_VERSION: &str = "otel.scope.version";
+const OTEL_DROPPED_ATTRIBUTES_COUNT: &str = "otel.dropped_attributes_count";
+const OTEL_DROPPED_EVENTS_COUNT: &str = "otel.dropped_events_count";
+const OTEL_DROPPED_LINKS_COUNT: &str = "otel.dropped_links_count";
 const OTEL_ERROR_DESCRIPTION: &str = "error";
 const OTEL_STATUS_CODE: &str = "otel.status_code";
 
@@ -28,10 +40,91 @@ fn into_zipkin_span_kind(kind: SpanKind) -> Option<span::Kind> {
     }
 }
 
+/// Attributes the service name of the remote endpoint is read from, in order of precedence.
+#[allow(deprecated)]
+const REMOTE_SERVICE_NAME_KEYS: [&str; 9] = [
+    semcov::PEER_SERVICE,
+    semcov::SERVER_ADDRESS,
+    semcov::NET_PEER_NAME,
+    semcov::NETWORK_PEER_ADDRESS,
+    semcov::NET_SOCK_PEER_NAME,
+    semcov::NET_SOCK_PEER_ADDR,
+    "peer.hostname",
+    "peer.address",
+    semcov::DB_NAME,
+];
+
+/// Attributes the ip address of the remote endpoint is read from, in order of precedence.
+#[allow(deprecated)]
+const REMOTE_IP_KEYS: [&str; 5] = [
+    semcov::NETWORK_PEER_ADDRESS,
+    semcov::NET_PEER_IP,
+    semcov::NET_SOCK_PEER_ADDR,
+    semcov::SERVER_ADDRESS,
+    semcov::NET_PEER_NAME,
+];
+
+/// Attributes the port of the remote endpoint is read from, in order of precedence.
+#[allow(deprecated)]
+const REMOTE_PORT_KEYS: [&str; 3] = [
+    semcov::SERVER_PORT,
+    semcov::NETWORK_PEER_PORT,
+    semcov::NET_PEER_PORT,
+];
+
+/// Derives the remote endpoint of client, producer and consumer spans from
+/// their peer attributes, as described in the [Zipkin exporter specification].
+///
+/// [Zipkin exporter specification]: https://github.com/open-telemetry/opentelemetry-specification/blob/main/specification/trace/sdk_exporters/zipkin.md#remote-endpoint
+fn remote_endpoint(kind: &SpanKind, attributes: &[KeyValue]) -> Option<Endpoint> {
+    if !matches!(
+        kind,
+        SpanKind::Client | SpanKind::Producer | SpanKind::Consumer
+    ) {
+        return None;
+    }
+    let get = |key: &&str| {
+        attributes
+            .iter()
+            .find(|kv| kv.key.as_str() == *key)
+            .map(|kv| &kv.value)
+    };
+
+    let service_name = REMOTE_SERVICE_NAME_KEYS
+        .iter()
+        .find_map(get)
+        .map(|value| value.to_string());
+    let ip = REMOTE_IP_KEYS
+        .iter()
+        .find_map(|key| get(key)?.as_str().parse::<IpAddr>().ok());
+    if service_name.is_none() && ip.is_none() {
+        return None;
+    }
+    let port = REMOTE_PORT_KEYS.iter().find_map(|key| match get(key)? {
+        Value::I64(port) => u16::try_from(*port).ok(),
+        Value::String(port) => port.as_str().parse().ok(),
+        _ => None,
+    });
+
+    Some(Endpoint {
+        service_name,
+        ipv4: match ip {
+            Some(IpAddr::V4(ip)) => Some(ip),
+            _ => None,
+        },
+        ipv6: match ip {
+            Some(IpAddr::V6(ip)) => Some(ip),
+            _ => None,
+        },
+        port,
+    })
+}
+
 /// Converts a `SpanData` to a `SpanData` for a given `ExporterConfig`, which can then
 /// be ingested into a Zipkin collector.
 pub(crate) fn into_zipkin_span(local_endpoint: Endpoint, span_data: SpanData) -> span::Span {
     // see tests in create/exporter/model/span.rs
+    let remote_endpoint = remote_endpoint(&span_data.span_kind, &span_data.attributes);
     let mut user_defined_span_kind = false;
     let mut tags = map_from_kvs(
         span_data
@@ -45,6 +138,14 @@ pub(crate) fn into_zipkin_span(local_endpoint: Endpoint, span_data: SpanData) ->
             })
             .chain(
                 [
+                    (
+                        INSTRUMENTATION_SCOPE_NAME,
+                        Some(span_data.instrumentation_lib.name.clone()),
+                    ),
+                    (
+                        INSTRUMENTATION_SCOPE_VERSION,
+                        span_data.instrumentation_lib.version.clone(),
+                    ),
                     (
                         INSTRUMENTATION_LIBRARY_NAME,
                         Some(span_data.instrumentation_lib.name),
@@ -57,6 +158,19 @@ pub(crate) fn into_zipkin_span(local_endpoint: Endpoint, span_data: SpanData) ->
                 .into_iter()
                 .filter_map(|(key, val)| val.map(|val| KeyValue::new(key, val))),
             )
+            .chain(
+                [
+                    (
+                        OTEL_DROPPED_ATTRIBUTES_COUNT,
+                        span_data.dropped_attributes_count,
+                    ),
+                    (OTEL_DROPPED_EVENTS_COUNT, span_data.events.dropped_count),
+                    (OTEL_DROPPED_LINKS_COUNT, span_data.links.dropped_count),
+                ]
+                .into_iter()
+                .filter(|(_, count)| *count > 0)
+                .map(|(key, count)| KeyValue::new(key, i64::from(count))),
+            )
             .filter(|kv| kv.key.as_str() != "error"),
     );
 
@@ -73,7 +187,7 @@ pub(crate) fn into_zipkin_span(local_endpoint: Endpoint, span_data: SpanData) ->
         }
     };
 
-    span::Span::builder()
+    let mut span = span::Span::builder()
         .trace_id(span_data.span_context.trace_id().to_string())
         .parent_id(span_data.parent_span_id.to_string())
         .id(span_data.span_context.span_id().to_string())
@@ -100,7 +214,9 @@ pub(crate) fn into_zipkin_span(local_endpoint: Endpoint, span_data: SpanData) ->
         .local_endpoint(local_endpoint)
         .annotations(span_data.events.into_iter().map(Into::into).collect())
         .tags(tags)
-        .build()
+        .build();
+    span.remote_endpoint = remote_endpoint;
+    span
 }
 
 fn map_from_kvs<T>(kvs: T) -> HashMap<String, String>
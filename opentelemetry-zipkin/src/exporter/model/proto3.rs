@@ -0,0 +1,280 @@
+//! Messages of the [zipkin.proto3] v2 model.
+//!
+//! [zipkin.proto3]: https://github.com/openzipkin/zipkin-api/blob/master/zipkin.proto3
+use crate::exporter::model::{annotation, endpoint, span};
+use std::collections::HashMap;
+
+/// A list of spans with possibly different trace ids, in no particular order.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub(crate) struct ListOfSpans {
+    #[prost(message, repeated, tag = "1")]
+    pub(crate) spans: Vec<Span>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub(crate) struct Span {
+    #[prost(bytes = "vec", tag = "1")]
+    pub(crate) trace_id: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub(crate) parent_id: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub(crate) id: Vec<u8>,
+    #[prost(enumeration = "Kind", tag = "4")]
+    pub(crate) kind: i32,
+    #[prost(string, tag = "5")]
+    pub(crate) name: String,
+    #[prost(fixed64, tag = "6")]
+    pub(crate) timestamp: u64,
+    #[prost(uint64, tag = "7")]
+    pub(crate) duration: u64,
+    #[prost(message, optional, tag = "8")]
+    pub(crate) local_endpoint: Option<Endpoint>,
+    #[prost(message, optional, tag = "9")]
+    pub(crate) remote_endpoint: Option<Endpoint>,
+    #[prost(message, repeated, tag = "10")]
+    pub(crate) annotations: Vec<Annotation>,
+    #[prost(map = "string, string", tag = "11")]
+    pub(crate) tags: HashMap<String, String>,
+    #[prost(bool, tag = "12")]
+    pub(crate) debug: bool,
+    #[prost(bool, tag = "13")]
+    pub(crate) shared: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub(crate) enum Kind {
+    Unspecified = 0,
+    Client = 1,
+    Server = 2,
+    Producer = 3,
+    Consumer = 4,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub(crate) struct Endpoint {
+    #[prost(string, tag = "1")]
+    pub(crate) service_name: String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub(crate) ipv4: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub(crate) ipv6: Vec<u8>,
+    #[prost(int32, tag = "4")]
+    pub(crate) port: i32,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub(crate) struct Annotation {
+    #[prost(fixed64, tag = "1")]
+    pub(crate) timestamp: u64,
+    #[prost(string, tag = "2")]
+    pub(crate) value: String,
+}
+
+impl From<span::Span> for Span {
+    fn from(span: span::Span) -> Self {
+        Span {
+            trace_id: decode_id(span.trace_id),
+            parent_id: decode_id(span.parent_id),
+            id: decode_id(span.id),
+            kind: match span.kind {
+                Some(span::Kind::Client) => Kind::Client,
+                Some(span::Kind::Server) => Kind::Server,
+                Some(span::Kind::Producer) => Kind::Producer,
+                Some(span::Kind::Consumer) => Kind::Consumer,
+                None => Kind::Unspecified,
+            } as i32,
+            name: span.name.unwrap_or_default(),
+            timestamp: span.timestamp.unwrap_or_default(),
+            duration: span.duration.unwrap_or_default(),
+            local_endpoint: span.local_endpoint.map(Into::into),
+            remote_endpoint: span.remote_endpoint.map(Into::into),
+            annotations: span
+                .annotations
+                .unwrap_or_default()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            tags: span.tags.unwrap_or_default(),
+            debug: span.debug,
+            shared: span.shared,
+        }
+    }
+}
+
+impl From<endpoint::Endpoint> for Endpoint {
+    fn from(endpoint: endpoint::Endpoint) -> Self {
+        Endpoint {
+            service_name: endpoint.service_name.unwrap_or_default(),
+            ipv4: endpoint
+                .ipv4
+                .map(|ip| ip.octets().to_vec())
+                .unwrap_or_default(),
+            ipv6: endpoint
+                .ipv6
+                .map(|ip| ip.octets().to_vec())
+                .unwrap_or_default(),
+            port: endpoint.port.map(i32::from).unwrap_or_default(),
+        }
+    }
+}
+
+impl From<annotation::Annotation> for Annotation {
+    fn from(annotation: annotation::Annotation) -> Self {
+        Annotation {
+            timestamp: annotation.timestamp.unwrap_or_default(),
+            value: annotation.value.unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(feature = "testing")]
+impl From<Span> for span::Span {
+    fn from(span: Span) -> Self {
+        span::Span {
+            trace_id: encode_id(span.trace_id),
+            parent_id: encode_id(span.parent_id),
+            id: encode_id(span.id),
+            kind: match Kind::try_from(span.kind) {
+                Ok(Kind::Client) => Some(span::Kind::Client),
+                Ok(Kind::Server) => Some(span::Kind::Server),
+                Ok(Kind::Producer) => Some(span::Kind::Producer),
+                Ok(Kind::Consumer) => Some(span::Kind::Consumer),
+                Ok(Kind::Unspecified) | Err(_) => None,
+            },
+            name: Some(span.name).filter(|name| !name.is_empty()),
+            timestamp: Some(span.timestamp).filter(|timestamp| *timestamp != 0),
+            duration: Some(span.duration).filter(|duration| *duration != 0),
+            local_endpoint: span.local_endpoint.map(Into::into),
+            remote_endpoint: span.remote_endpoint.map(Into::into),
+            annotations: Some(span.annotations.into_iter().map(Into::into).collect())
+                .filter(|annotations: &Vec<_>| !annotations.is_empty()),
+            tags: Some(span.tags).filter(|tags| !tags.is_empty()),
+            debug: span.debug,
+            shared: span.shared,
+        }
+    }
+}
+
+#[cfg(feature = "testing")]
+impl From<Endpoint> for endpoint::Endpoint {
+    fn from(endpoint: Endpoint) -> Self {
+        endpoint::Endpoint {
+            service_name: Some(endpoint.service_name).filter(|name| !name.is_empty()),
+            ipv4: <[u8; 4]>::try_from(endpoint.ipv4).ok().map(Into::into),
+            ipv6: <[u8; 16]>::try_from(endpoint.ipv6).ok().map(Into::into),
+            port: u16::try_from(endpoint.port).ok().filter(|port| *port != 0),
+        }
+    }
+}
+
+#[cfg(feature = "testing")]
+impl From<Annotation> for annotation::Annotation {
+    fn from(annotation: Annotation) -> Self {
+        annotation::Annotation {
+            timestamp: Some(annotation.timestamp),
+            value: Some(annotation.value),
+        }
+    }
+}
+
+/// Encodes an id as lower hex, empty ids are absent.
+#[cfg(feature = "testing")]
+fn encode_id(id: Vec<u8>) -> Option<String> {
+    if id.is_empty() {
+        return None;
+    }
+    Some(id.iter().map(|b| format!("{b:02x}")).collect())
+}
+
+/// Decodes a lower hex id, ids which are invalid or all zeros, like the parent id of root spans,
+/// are omitted.
+fn decode_id(id: Option<String>) -> Vec<u8> {
+    let Some(id) = id else {
+        return Vec::new();
+    };
+    let bytes = (0..id.len())
+        .step_by(2)
+        .map(|i| {
+            id.get(i..i + 2)
+                .and_then(|b| u8::from_str_radix(b, 16).ok())
+        })
+        .collect::<Option<Vec<u8>>>()
+        .unwrap_or_default();
+    if bytes.iter().all(|b| *b == 0) {
+        Vec::new()
+    } else {
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exporter::model::into_zipkin_span;
+    use opentelemetry::trace::{SpanContext, SpanId, SpanKind, Status, TraceFlags, TraceId};
+    use opentelemetry_sdk::export::trace::SpanData;
+    use opentelemetry_sdk::trace::{SpanEvents, SpanLinks};
+    use prost::Message;
+    use std::net::Ipv4Addr;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn encode_span() {
+        let start_time = SystemTime::UNIX_EPOCH + Duration::from_micros(1_502_787_600_000_000);
+        let span_data = SpanData {
+            span_context: SpanContext::new(
+                TraceId::from_u128(0x0102),
+                SpanId::from_u64(0x0304),
+                TraceFlags::default(),
+                false,
+                Default::default(),
+            ),
+            parent_span_id: SpanId::INVALID,
+            span_kind: SpanKind::Server,
+            name: "proto".into(),
+            start_time,
+            end_time: start_time + Duration::from_micros(50),
+            attributes: vec![],
+            dropped_attributes_count: 0,
+            events: SpanEvents::default(),
+            links: SpanLinks::default(),
+            status: Status::Unset,
+            instrumentation_lib: Default::default(),
+        };
+        let local_endpoint = endpoint::Endpoint::new(
+            "proto-service".into(),
+            Some((Ipv4Addr::new(127, 0, 0, 1), 8080).into()),
+        );
+
+        let span: Span = into_zipkin_span(local_endpoint, span_data).into();
+        let decoded = ListOfSpans::decode(
+            ListOfSpans {
+                spans: vec![span.clone()],
+            }
+            .encode_to_vec()
+            .as_slice(),
+        )
+        .unwrap();
+
+        assert_eq!(decoded.spans, vec![span.clone()]);
+        let mut trace_id = vec![0; 14];
+        trace_id.extend([1, 2]);
+        assert_eq!(span.trace_id, trace_id);
+        assert_eq!(span.id, vec![0, 0, 0, 0, 0, 0, 3, 4]);
+        assert!(span.parent_id.is_empty());
+        assert_eq!(span.kind, Kind::Server as i32);
+        assert_eq!(span.name, "proto");
+        assert_eq!(span.timestamp, 1_502_787_600_000_000);
+        assert_eq!(span.duration, 50);
+        assert_eq!(
+            span.local_endpoint,
+            Some(Endpoint {
+                service_name: "proto-service".into(),
+                ipv4: vec![127, 0, 0, 1],
+                ipv6: vec![],
+                port: 8080,
+            })
+        );
+    }
+}
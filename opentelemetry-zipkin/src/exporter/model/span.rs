@@ -1,56 +1,77 @@
 use crate::exporter::model::{annotation::Annotation, endpoint::Endpoint};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Clone, Debug, Serialize)]
+/// The role of a span in an RPC or messaging exchange.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub(crate) enum Kind {
+pub enum Kind {
+    /// The client side of an RPC.
     Client,
+    /// The server side of an RPC.
     Server,
+    /// The sender of a message.
     Producer,
+    /// The receiver of a message.
     Consumer,
 }
 
-#[derive(TypedBuilder, Clone, Debug, Serialize)]
+/// A Zipkin v2 span.
+#[derive(TypedBuilder, Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Span {
+pub struct Span {
+    /// Lower hex encoded trace id.
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    trace_id: Option<String>,
+    pub trace_id: Option<String>,
+    /// Lower hex encoded id of the parent span.
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    parent_id: Option<String>,
+    pub parent_id: Option<String>,
+    /// Lower hex encoded span id.
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    id: Option<String>,
+    pub id: Option<String>,
+    /// The kind of span, absent for internal spans.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    kind: Option<Kind>,
+    pub kind: Option<Kind>,
+    /// The span name.
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    name: Option<String>,
+    pub name: Option<String>,
+    /// Start of the span in microseconds since the unix epoch.
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    timestamp: Option<u64>,
+    pub timestamp: Option<u64>,
+    /// Duration of the span in microseconds.
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    duration: Option<u64>,
+    pub duration: Option<u64>,
+    /// The service which recorded the span.
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    local_endpoint: Option<Endpoint>,
+    pub local_endpoint: Option<Endpoint>,
+    /// The remote service of client, producer and consumer spans.
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    remote_endpoint: Option<Endpoint>,
+    pub remote_endpoint: Option<Endpoint>,
+    /// Events recorded on the span.
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    annotations: Option<Vec<Annotation>>,
+    pub annotations: Option<Vec<Annotation>>,
+    /// Attributes and status of the span.
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    tags: Option<HashMap<String, String>>,
+    pub tags: Option<HashMap<String, String>>,
+    /// Whether the span is forcibly sampled.
     #[builder(default = false)]
-    debug: bool,
+    #[serde(default)]
+    pub debug: bool,
+    /// Whether the span id is shared with the remote side.
     #[builder(default = false)]
-    shared: bool,
+    #[serde(default)]
+    pub shared: bool,
 }
 
 #[cfg(test)]
@@ -60,10 +81,12 @@ mod tests {
     use crate::exporter::model::span::{Kind, Span};
     use crate::exporter::model::{into_zipkin_span, OTEL_ERROR_DESCRIPTION, OTEL_STATUS_CODE};
     use opentelemetry::trace::{SpanContext, SpanId, SpanKind, Status, TraceFlags, TraceId};
+    use opentelemetry::KeyValue;
     use opentelemetry_sdk::export::trace::SpanData;
     use opentelemetry_sdk::trace::{SpanEvents, SpanLinks};
+    use opentelemetry_sdk::InstrumentationLibrary;
     use std::collections::HashMap;
-    use std::net::Ipv4Addr;
+    use std::net::{Ipv4Addr, Ipv6Addr};
     use std::time::SystemTime;
 
     #[test]
@@ -175,4 +198,159 @@ mod tests {
             };
         }
     }
+
+    fn span_data(span_kind: SpanKind, attributes: Vec<KeyValue>) -> SpanData {
+        SpanData {
+            span_context: SpanContext::new(
+                TraceId::from_u128(1),
+                SpanId::from_u64(1),
+                TraceFlags::default(),
+                false,
+                Default::default(),
+            ),
+            parent_span_id: SpanId::from_u64(1),
+            span_kind,
+            name: "".into(),
+            start_time: SystemTime::now(),
+            end_time: SystemTime::now(),
+            attributes,
+            dropped_attributes_count: 0,
+            events: SpanEvents::default(),
+            links: SpanLinks::default(),
+            status: Status::Unset,
+            instrumentation_lib: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_remote_endpoint() {
+        let test_data = vec![
+            (
+                SpanKind::Client,
+                vec![
+                    KeyValue::new("server.address", "example.com"),
+                    KeyValue::new("peer.service", "backend"),
+                    KeyValue::new("network.peer.address", "10.0.0.1"),
+                    KeyValue::new("server.port", 443),
+                ],
+                Some("{\"serviceName\":\"backend\",\"ipv4\":\"10.0.0.1\",\"port\":443}"),
+            ),
+            (
+                SpanKind::Producer,
+                vec![
+                    KeyValue::new("server.address", "example.com"),
+                    KeyValue::new("network.peer.address", "::1"),
+                    KeyValue::new("network.peer.port", 8080),
+                ],
+                Some("{\"serviceName\":\"example.com\",\"ipv6\":\"::1\",\"port\":8080}"),
+            ),
+            (
+                SpanKind::Consumer,
+                vec![
+                    KeyValue::new("net.peer.name", "broker"),
+                    KeyValue::new("net.peer.port", "9092"),
+                ],
+                Some("{\"serviceName\":\"broker\",\"port\":9092}"),
+            ),
+            (
+                SpanKind::Client,
+                vec![
+                    KeyValue::new("network.peer.address", "10.0.0.1"),
+                    KeyValue::new("net.peer.name", "backend"),
+                ],
+                Some("{\"serviceName\":\"backend\",\"ipv4\":\"10.0.0.1\"}"),
+            ),
+            (
+                SpanKind::Client,
+                vec![
+                    KeyValue::new("net.sock.peer.name", "backend.local"),
+                    KeyValue::new("network.peer.address", "10.0.0.1"),
+                ],
+                Some("{\"serviceName\":\"10.0.0.1\",\"ipv4\":\"10.0.0.1\"}"),
+            ),
+            (
+                SpanKind::Client,
+                vec![
+                    KeyValue::new("db.name", "orders"),
+                    KeyValue::new("peer.address", "db:5432"),
+                    KeyValue::new("peer.hostname", "db"),
+                ],
+                Some("{\"serviceName\":\"db\"}"),
+            ),
+            (
+                SpanKind::Client,
+                vec![
+                    KeyValue::new("db.name", "orders"),
+                    KeyValue::new("net.sock.peer.addr", "192.168.0.1"),
+                ],
+                Some("{\"serviceName\":\"192.168.0.1\",\"ipv4\":\"192.168.0.1\"}"),
+            ),
+            (
+                SpanKind::Client,
+                vec![KeyValue::new("net.peer.ip", "192.168.0.1")],
+                Some("{\"ipv4\":\"192.168.0.1\"}"),
+            ),
+            (
+                SpanKind::Client,
+                vec![KeyValue::new("server.port", 443)],
+                None,
+            ),
+            (
+                SpanKind::Server,
+                vec![KeyValue::new("peer.service", "backend")],
+                None,
+            ),
+            (
+                SpanKind::Internal,
+                vec![KeyValue::new("peer.service", "backend")],
+                None,
+            ),
+        ];
+
+        for (span_kind, attributes, expected) in test_data {
+            let local_endpoint = Endpoint::new("test".into(), None);
+            let span = into_zipkin_span(local_endpoint, span_data(span_kind, attributes));
+            let remote_endpoint = span
+                .remote_endpoint
+                .map(|endpoint| serde_json::to_string(&endpoint).unwrap());
+            assert_eq!(remote_endpoint.as_deref(), expected);
+        }
+    }
+
+    #[test]
+    fn test_remote_endpoint_ipv6() {
+        let span = into_zipkin_span(
+            Endpoint::new("test".into(), None),
+            span_data(
+                SpanKind::Client,
+                vec![KeyValue::new("network.peer.address", "2001:db8::1")],
+            ),
+        );
+        let remote_endpoint = span.remote_endpoint.unwrap();
+        assert_eq!(
+            remote_endpoint.ipv6,
+            Some(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1))
+        );
+        assert_eq!(remote_endpoint.ipv4, None);
+    }
+
+    #[test]
+    fn test_scope_and_dropped_count_tags() {
+        let mut data = span_data(SpanKind::Internal, vec![]);
+        data.instrumentation_lib = InstrumentationLibrary::builder("component")
+            .with_version("1.0.0")
+            .build();
+        data.dropped_attributes_count = 1;
+        data.events.dropped_count = 2;
+
+        let span = into_zipkin_span(Endpoint::new("test".into(), None), data);
+        let tags = span.tags.unwrap();
+        assert_tag_contains(&tags, "otel.scope.name", Some("component"));
+        assert_tag_contains(&tags, "otel.scope.version", Some("1.0.0"));
+        assert_tag_contains(&tags, "otel.library.name", Some("component"));
+        assert_tag_contains(&tags, "otel.library.version", Some("1.0.0"));
+        assert_tag_contains(&tags, "otel.dropped_attributes_count", Some("1"));
+        assert_tag_contains(&tags, "otel.dropped_events_count", Some("2"));
+        assert_tag_contains(&tags, "otel.dropped_links_count", None);
+    }
 }
@@ -1,8 +1,11 @@
 //! # Zipkin Span Exporter
 use crate::exporter::model::span::Span;
-use crate::exporter::Error;
-use http::{header::CONTENT_TYPE, Method, Request, Uri};
-use opentelemetry_http::{HttpClient, ResponseExt};
+use crate::exporter::{Compression, Error};
+use http::{
+    header::{CONTENT_ENCODING, CONTENT_TYPE},
+    Method, Request, Uri,
+};
+use opentelemetry_http::{suppress_telemetry, HttpClient, ResponseExt};
 use opentelemetry_sdk::export::trace::ExportResult;
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -10,40 +13,222 @@ use std::sync::Arc;
 #[derive(Debug, Clone)]
 pub(crate) enum Uploader {
     Http(JsonV2Client),
+    #[cfg(feature = "proto3")]
+    Proto3(Proto3Client),
 }
 
 impl Uploader {
     /// Create a new http uploader
     pub(crate) fn new(client: Arc<dyn HttpClient>, collector_endpoint: Uri) -> Self {
         Uploader::Http(JsonV2Client {
-            client,
-            collector_endpoint,
+            sender: Sender {
+                client,
+                collector_endpoint,
+                compression: None,
+            },
         })
     }
 
+    /// Create a new http uploader encoding spans as a zipkin.proto3 `ListOfSpans`
+    #[cfg(feature = "proto3")]
+    pub(crate) fn new_proto3(client: Arc<dyn HttpClient>, collector_endpoint: Uri) -> Self {
+        Uploader::Proto3(Proto3Client {
+            sender: Sender {
+                client,
+                collector_endpoint,
+                compression: None,
+            },
+        })
+    }
+
+    /// Compress uploaded spans
+    pub(crate) fn with_compression(mut self, compression: Option<Compression>) -> Self {
+        match &mut self {
+            Uploader::Http(client) => client.sender.compression = compression,
+            #[cfg(feature = "proto3")]
+            Uploader::Proto3(client) => client.sender.compression = compression,
+        }
+        self
+    }
+
     /// Upload spans to Zipkin
     pub(crate) async fn upload(&self, spans: Vec<Span>) -> ExportResult {
         match self {
             Uploader::Http(client) => client.upload(spans).await,
+            #[cfg(feature = "proto3")]
+            Uploader::Proto3(client) => client.upload(spans).await,
         }
     }
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct JsonV2Client {
-    client: Arc<dyn HttpClient>,
-    collector_endpoint: Uri,
+    sender: Sender,
 }
 
 impl JsonV2Client {
     async fn upload(&self, spans: Vec<Span>) -> ExportResult {
-        let req = Request::builder()
+        let body = serde_json::to_vec(&spans).unwrap_or_default();
+        self.sender.send("application/json", body).await
+    }
+}
+
+#[cfg(feature = "proto3")]
+#[derive(Debug, Clone)]
+pub(crate) struct Proto3Client {
+    sender: Sender,
+}
+
+#[cfg(feature = "proto3")]
+impl Proto3Client {
+    async fn upload(&self, spans: Vec<Span>) -> ExportResult {
+        use crate::exporter::model::proto3::ListOfSpans;
+        use prost::Message;
+
+        let body = ListOfSpans {
+            spans: spans.into_iter().map(Into::into).collect(),
+        }
+        .encode_to_vec();
+        self.sender.send("application/x-protobuf", body).await
+    }
+}
+
+/// Posts encoded spans to the collector.
+#[derive(Debug, Clone)]
+struct Sender {
+    client: Arc<dyn HttpClient>,
+    collector_endpoint: Uri,
+    compression: Option<Compression>,
+}
+
+impl Sender {
+    async fn send(&self, content_type: &'static str, body: Vec<u8>) -> ExportResult {
+        let mut builder = Request::builder()
             .method(Method::POST)
             .uri(self.collector_endpoint.clone())
-            .header(CONTENT_TYPE, "application/json")
-            .body(serde_json::to_vec(&spans).unwrap_or_default())
-            .map_err::<Error, _>(Into::into)?;
-        let _ = self.client.send(req).await?.error_for_status()?;
+            .header(CONTENT_TYPE, content_type);
+        let body = match self.compression {
+            Some(compression) => {
+                builder = builder.header(CONTENT_ENCODING, compression.to_string());
+                compress(compression, body)?
+            }
+            None => body,
+        };
+        let req = builder.body(body).map_err::<Error, _>(Into::into)?;
+        let _ = suppress_telemetry(self.client.send(req))
+            .await?
+            .error_for_status()?;
         Ok(())
     }
 }
+
+#[cfg(feature = "gzip")]
+fn compress(compression: Compression, body: Vec<u8>) -> Result<Vec<u8>, Error> {
+    use flate2::{write::GzEncoder, Compression as Level};
+    use std::io::Write;
+
+    match compression {
+        Compression::Gzip => {
+            let mut encoder = GzEncoder::new(Vec::new(), Level::default());
+            encoder
+                .write_all(&body)
+                .and_then(|_| encoder.finish())
+                .map_err(|err| Error::Other(format!("gzip compression failed: {err}")))
+        }
+    }
+}
+
+#[cfg(not(feature = "gzip"))]
+fn compress(compression: Compression, _body: Vec<u8>) -> Result<Vec<u8>, Error> {
+    Err(Error::FeatureRequired {
+        option: compression.to_string(),
+        feature: "gzip",
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exporter::model::endpoint::Endpoint;
+    use bytes::Bytes;
+    use http::Response;
+    use opentelemetry_http::HttpError;
+    use std::sync::Mutex;
+
+    #[derive(Clone, Debug, Default)]
+    struct RecordingClient {
+        requests: Arc<Mutex<Vec<Request<Vec<u8>>>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl HttpClient for RecordingClient {
+        async fn send(&self, request: Request<Vec<u8>>) -> Result<Response<Bytes>, HttpError> {
+            self.requests.lock().unwrap().push(request);
+            Ok(Response::new(Bytes::new()))
+        }
+    }
+
+    fn spans() -> Vec<Span> {
+        vec![Span::builder()
+            .trace_id("0102030405060708090a0b0c0d0e0f10".to_owned())
+            .id("0102030405060708".to_owned())
+            .name("upload".to_owned())
+            .local_endpoint(Endpoint::new("service".to_owned(), None))
+            .build()]
+    }
+
+    fn upload(uploader: Uploader, client: &RecordingClient) -> Request<Vec<u8>> {
+        futures_executor::block_on(uploader.upload(spans())).unwrap();
+        client.requests.lock().unwrap().pop().unwrap()
+    }
+
+    #[test]
+    fn upload_json() {
+        let client = RecordingClient::default();
+        let uploader = Uploader::new(Arc::new(client.clone()), "http://zipkin".parse().unwrap());
+
+        let request = upload(uploader, &client);
+        assert_eq!(request.headers()[CONTENT_TYPE], "application/json");
+        assert!(request.headers().get(CONTENT_ENCODING).is_none());
+        assert_eq!(
+            request.body().as_slice(),
+            serde_json::to_vec(&spans()).unwrap()
+        );
+    }
+
+    #[cfg(feature = "proto3")]
+    #[test]
+    fn upload_proto3() {
+        use crate::exporter::model::proto3::ListOfSpans;
+        use prost::Message;
+
+        let client = RecordingClient::default();
+        let uploader =
+            Uploader::new_proto3(Arc::new(client.clone()), "http://zipkin".parse().unwrap());
+
+        let request = upload(uploader, &client);
+        assert_eq!(request.headers()[CONTENT_TYPE], "application/x-protobuf");
+        let decoded = ListOfSpans::decode(request.body().as_slice()).unwrap();
+        assert_eq!(decoded.spans.len(), 1);
+        assert_eq!(decoded.spans[0].name, "upload");
+        assert_eq!(decoded.spans[0].id, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn upload_gzip() {
+        use std::io::Read;
+
+        let client = RecordingClient::default();
+        let uploader = Uploader::new(Arc::new(client.clone()), "http://zipkin".parse().unwrap())
+            .with_compression(Some(Compression::Gzip));
+
+        let request = upload(uploader, &client);
+        assert_eq!(request.headers()[CONTENT_ENCODING], "gzip");
+        let mut body = Vec::new();
+        flate2::read::GzDecoder::new(request.body().as_slice())
+            .read_to_end(&mut body)
+            .unwrap();
+        assert_eq!(body, serde_json::to_vec(&spans()).unwrap());
+    }
+}
@@ -169,6 +169,9 @@
 //! * `reqwest-blocking-client`: Export spans using the reqwest blocking http
 //!   client (enabled by default).
 //! * `reqwest-client`: Export spans using the reqwest non-blocking http client.
+//! * `proto3`: Support uploading spans encoded as zipkin.proto3 with [`Encoding::Proto3`].
+//! * `gzip`: Support compressing uploaded spans with [`Compression::Gzip`].
+//! * `testing`: Provides `testing::ZipkinReceiver`, an in-process server receiving uploaded spans.
 //!
 //! ## Supported Rust Versions
 //!
@@ -207,6 +210,8 @@ extern crate typed_builder;
 
 mod exporter;
 mod propagator;
+#[cfg(feature = "testing")]
+pub mod testing;
 
-pub use exporter::{new_pipeline, Error, Exporter, ZipkinPipelineBuilder};
+pub use exporter::{new_pipeline, Compression, Encoding, Error, Exporter, ZipkinPipelineBuilder};
 pub use propagator::{B3Encoding, Propagator};
@@ -0,0 +1,209 @@
+//! Utilities for testing what the Zipkin exporter uploads.
+//!
+//! [`ZipkinReceiver`] is an in-process HTTP server accepting the same requests
+//! as a Zipkin collector, which decodes the uploaded spans back into the
+//! [`Span`] model of this crate.
+//!
+//! ```no_run
+//! # async fn test(client: impl opentelemetry_http::HttpClient + 'static) -> Result<(), Box<dyn std::error::Error>> {
+//! use opentelemetry_zipkin::testing::ZipkinReceiver;
+//!
+//! let receiver = ZipkinReceiver::start().await?;
+//! let exporter = opentelemetry_zipkin::new_pipeline()
+//!     .with_http_client(client)
+//!     .with_collector_endpoint(receiver.endpoint())
+//!     .init_exporter()?;
+//!
+//! // export spans with `exporter`
+//!
+//! for span in receiver.spans() {
+//!     println!("{:?}", span.name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use http::{header, Method, Request, Response, StatusCode};
+use http_body_util::{BodyExt, Full};
+use hyper::{
+    body::{Bytes, Incoming},
+    service::service_fn,
+};
+use hyper_util::rt::TokioIo;
+use std::{
+    convert::Infallible,
+    io,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+use tokio::{net::TcpListener, task::JoinHandle};
+
+pub use crate::exporter::model::{
+    annotation::Annotation,
+    endpoint::Endpoint,
+    span::{Kind, Span},
+};
+
+/// The path spans are uploaded to, as served by a Zipkin collector.
+const SPANS_PATH: &str = "/api/v2/spans";
+
+/// An in-process HTTP server receiving spans like a Zipkin collector.
+///
+/// Accepts Zipkin v2 JSON uploads, proto3 uploads with the `proto3` feature
+/// and gzip compressed uploads with the `gzip` feature. Received spans are
+/// kept in memory until the receiver is dropped, which also stops the server.
+#[derive(Debug)]
+pub struct ZipkinReceiver {
+    addr: SocketAddr,
+    spans: Arc<Mutex<Vec<Span>>>,
+    server: JoinHandle<()>,
+}
+
+impl ZipkinReceiver {
+    /// Start a receiver listening on an ephemeral port of the loopback interface.
+    ///
+    /// Must be called from within a Tokio runtime, which the server is
+    /// spawned on.
+    pub async fn start() -> io::Result<Self> {
+        let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0))).await?;
+        let addr = listener.local_addr()?;
+        let spans = Arc::new(Mutex::new(Vec::new()));
+
+        let received = spans.clone();
+        let server = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let received = received.clone();
+                tokio::spawn(async move {
+                    let service = service_fn(move |request| {
+                        let received = received.clone();
+                        async move { Ok::<_, Infallible>(receive(request, &received).await) }
+                    });
+                    let _ = hyper::server::conn::http1::Builder::new()
+                        .serve_connection(TokioIo::new(stream), service)
+                        .await;
+                });
+            }
+        });
+
+        Ok(ZipkinReceiver {
+            addr,
+            spans,
+            server,
+        })
+    }
+
+    /// The collector endpoint to upload spans to.
+    pub fn endpoint(&self) -> String {
+        format!("http://{}{}", self.addr, SPANS_PATH)
+    }
+
+    /// All spans received so far, in the order they were received.
+    pub fn spans(&self) -> Vec<Span> {
+        self.spans
+            .lock()
+            .map(|spans| spans.clone())
+            .unwrap_or_default()
+    }
+
+    /// Remove and return all spans received so far.
+    pub fn take_spans(&self) -> Vec<Span> {
+        self.spans
+            .lock()
+            .map(|mut spans| std::mem::take(&mut *spans))
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for ZipkinReceiver {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+async fn receive(request: Request<Incoming>, received: &Mutex<Vec<Span>>) -> Response<Full<Bytes>> {
+    if request.uri().path() != SPANS_PATH {
+        return response(StatusCode::NOT_FOUND, "not found");
+    }
+    if request.method() != Method::POST {
+        return response(StatusCode::METHOD_NOT_ALLOWED, "method not allowed");
+    }
+
+    let (parts, body) = request.into_parts();
+    let body = match body.collect().await {
+        Ok(body) => body.to_bytes(),
+        Err(err) => return response(StatusCode::BAD_REQUEST, err.to_string()),
+    };
+    let body = match parts.headers.get(header::CONTENT_ENCODING) {
+        None => body.to_vec(),
+        Some(encoding) => match decompress(encoding.as_bytes(), &body) {
+            Ok(body) => body,
+            Err(status) => return response(status, "unsupported content encoding"),
+        },
+    };
+    let content_type = parts
+        .headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .unwrap_or("application/json");
+
+    let spans = match decode(content_type.trim(), &body) {
+        Ok(spans) => spans,
+        Err((status, message)) => return response(status, message),
+    };
+    if let Ok(mut received) = received.lock() {
+        received.extend(spans);
+    }
+    response(StatusCode::ACCEPTED, "")
+}
+
+fn decode(content_type: &str, body: &[u8]) -> Result<Vec<Span>, (StatusCode, String)> {
+    match content_type {
+        "application/json" => {
+            serde_json::from_slice(body).map_err(|err| (StatusCode::BAD_REQUEST, err.to_string()))
+        }
+        #[cfg(feature = "proto3")]
+        "application/x-protobuf" => {
+            use crate::exporter::model::proto3::ListOfSpans;
+            use prost::Message;
+
+            ListOfSpans::decode(body)
+                .map(|list| list.spans.into_iter().map(Into::into).collect())
+                .map_err(|err| (StatusCode::BAD_REQUEST, err.to_string()))
+        }
+        _ => Err((
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            format!("unsupported content type {content_type}"),
+        )),
+    }
+}
+
+#[cfg(feature = "gzip")]
+fn decompress(encoding: &[u8], body: &[u8]) -> Result<Vec<u8>, StatusCode> {
+    use std::io::Read;
+
+    match encoding {
+        b"gzip" => {
+            let mut decompressed = Vec::new();
+            flate2::read::GzDecoder::new(body)
+                .read_to_end(&mut decompressed)
+                .map_err(|_| StatusCode::BAD_REQUEST)?;
+            Ok(decompressed)
+        }
+        b"identity" => Ok(body.to_vec()),
+        _ => Err(StatusCode::UNSUPPORTED_MEDIA_TYPE),
+    }
+}
+
+#[cfg(not(feature = "gzip"))]
+fn decompress(encoding: &[u8], body: &[u8]) -> Result<Vec<u8>, StatusCode> {
+    match encoding {
+        b"identity" => Ok(body.to_vec()),
+        _ => Err(StatusCode::UNSUPPORTED_MEDIA_TYPE),
+    }
+}
+
+fn response(status: StatusCode, body: impl Into<Bytes>) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::new(body.into()));
+    *response.status_mut() = status;
+    response
+}
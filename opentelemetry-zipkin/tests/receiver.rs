@@ -0,0 +1,146 @@
+#![cfg(feature = "testing")]
+
+use hyper_util::client::legacy::{connect::HttpConnector, Client};
+use hyper_util::rt::TokioExecutor;
+use opentelemetry::trace::{SpanContext, SpanId, SpanKind, Status, TraceFlags, TraceId};
+use opentelemetry::KeyValue;
+use opentelemetry_http::hyper::HyperClient;
+use opentelemetry_sdk::export::trace::{SpanData, SpanExporter};
+use opentelemetry_sdk::trace::{SpanEvents, SpanLinks};
+use opentelemetry_zipkin::testing::{Kind, ZipkinReceiver};
+use opentelemetry_zipkin::ZipkinPipelineBuilder;
+use std::time::{Duration, SystemTime};
+use tokio::runtime::Runtime;
+
+/// The receiver and exporter run on the runtime, while pipelines are built
+/// outside of it as the default blocking http client cannot be dropped in an
+/// asynchronous context.
+fn start() -> (Runtime, ZipkinReceiver) {
+    let runtime = Runtime::new().unwrap();
+    let receiver = runtime.block_on(ZipkinReceiver::start()).unwrap();
+    (runtime, receiver)
+}
+
+fn pipeline(receiver: &ZipkinReceiver) -> ZipkinPipelineBuilder {
+    let client = Client::builder(TokioExecutor::new()).build(HttpConnector::new());
+    opentelemetry_zipkin::new_pipeline()
+        .with_service_name("receiver-test")
+        .with_http_client(HyperClient::new_with_timeout(
+            client,
+            Duration::from_secs(5),
+        ))
+        .with_collector_endpoint(receiver.endpoint())
+}
+
+fn span_data() -> SpanData {
+    let start_time = SystemTime::UNIX_EPOCH + Duration::from_micros(1_502_787_600_000_000);
+    SpanData {
+        span_context: SpanContext::new(
+            TraceId::from_u128(0x4e441824ec2b6a44ffdc9bb9a6453df3),
+            SpanId::from_u64(0xefdc9cd9a1849df3),
+            TraceFlags::SAMPLED,
+            false,
+            Default::default(),
+        ),
+        parent_span_id: SpanId::from_u64(0xffdc9bb9a6453df3),
+        span_kind: SpanKind::Client,
+        name: "upload".into(),
+        start_time,
+        end_time: start_time + Duration::from_micros(150),
+        attributes: vec![
+            KeyValue::new("peer.service", "backend"),
+            KeyValue::new("server.port", 8080),
+        ],
+        dropped_attributes_count: 0,
+        events: SpanEvents::default(),
+        links: SpanLinks::default(),
+        status: Status::Unset,
+        instrumentation_lib: Default::default(),
+    }
+}
+
+fn export_and_receive(
+    runtime: &Runtime,
+    pipeline: ZipkinPipelineBuilder,
+    receiver: &ZipkinReceiver,
+) {
+    let mut exporter = pipeline.init_exporter().unwrap();
+    runtime
+        .block_on(exporter.export(vec![span_data()]))
+        .unwrap();
+
+    let spans = receiver.take_spans();
+    assert_eq!(spans.len(), 1);
+    let span = &spans[0];
+    assert_eq!(
+        span.trace_id.as_deref(),
+        Some("4e441824ec2b6a44ffdc9bb9a6453df3")
+    );
+    assert_eq!(span.parent_id.as_deref(), Some("ffdc9bb9a6453df3"));
+    assert_eq!(span.id.as_deref(), Some("efdc9cd9a1849df3"));
+    assert_eq!(span.kind, Some(Kind::Client));
+    assert_eq!(span.name.as_deref(), Some("upload"));
+    assert_eq!(span.timestamp, Some(1_502_787_600_000_000));
+    assert_eq!(span.duration, Some(150));
+    assert_eq!(
+        span.local_endpoint
+            .as_ref()
+            .and_then(|endpoint| endpoint.service_name.as_deref()),
+        Some("receiver-test")
+    );
+    let remote_endpoint = span.remote_endpoint.as_ref().unwrap();
+    assert_eq!(remote_endpoint.service_name.as_deref(), Some("backend"));
+    assert_eq!(remote_endpoint.port, Some(8080));
+    assert_eq!(
+        span.tags
+            .as_ref()
+            .and_then(|tags| tags.get("peer.service"))
+            .map(String::as_str),
+        Some("backend")
+    );
+}
+
+#[test]
+fn receive_json() {
+    let (runtime, receiver) = start();
+    export_and_receive(&runtime, pipeline(&receiver), &receiver);
+}
+
+#[cfg(feature = "proto3")]
+#[test]
+fn receive_proto3() {
+    let (runtime, receiver) = start();
+    export_and_receive(
+        &runtime,
+        pipeline(&receiver).with_encoding(opentelemetry_zipkin::Encoding::Proto3),
+        &receiver,
+    );
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn receive_gzip() {
+    let (runtime, receiver) = start();
+    export_and_receive(
+        &runtime,
+        pipeline(&receiver).with_compression(opentelemetry_zipkin::Compression::Gzip),
+        &receiver,
+    );
+}
+
+#[test]
+fn reject_unknown_path() {
+    let (runtime, receiver) = start();
+    let endpoint = receiver
+        .endpoint()
+        .replace("/api/v2/spans", "/api/v1/spans");
+    let mut exporter = pipeline(&receiver)
+        .with_collector_endpoint(endpoint)
+        .init_exporter()
+        .unwrap();
+
+    assert!(runtime
+        .block_on(exporter.export(vec![span_data()]))
+        .is_err());
+    assert!(receiver.spans().is_empty());
+}
@@ -79,6 +79,7 @@ pub struct Context {
     #[cfg(feature = "trace")]
     pub(super) span: Option<Arc<SynchronizedSpan>>,
     entries: HashMap<TypeId, Arc<dyn Any + Sync + Send>, BuildHasherDefault<IdHasher>>,
+    suppress_telemetry: bool,
 }
 
 impl Context {
@@ -308,12 +309,105 @@ impl Context {
         }
     }
 
+    /// Wraps a closure so that it runs with the current context attached.
+    ///
+    /// The context is captured when `wrap_fn` is called, so the returned
+    /// closure can be handed to another thread, e.g. through
+    /// [`std::thread::spawn`] or `tokio::task::spawn_blocking`, and spans
+    /// started in it keep the caller's span as their parent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use opentelemetry::Context;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct ValueA(&'static str);
+    ///
+    /// let _guard = Context::new().with_value(ValueA("a")).attach();
+    ///
+    /// let handle = std::thread::spawn(Context::wrap_fn(|| {
+    ///     assert_eq!(Context::current().get(), Some(&ValueA("a")));
+    /// }));
+    /// handle.join().unwrap();
+    /// ```
+    pub fn wrap_fn<F, R>(f: F) -> impl FnOnce() -> R
+    where
+        F: FnOnce() -> R,
+    {
+        let cx = Context::current();
+        move || {
+            let _guard = cx.attach();
+            f()
+        }
+    }
+
+    /// Returns a clone of this context with telemetry suppressed.
+    ///
+    /// The SDK records no telemetry while a context with telemetry suppressed
+    /// is current: spans are not recording, log records are dropped and
+    /// measurements are ignored. Exporters suppress telemetry while they send
+    /// their data, which would otherwise produce telemetry about the exports
+    /// to be exported in turn.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use opentelemetry::Context;
+    ///
+    /// let cx = Context::new().with_telemetry_suppressed();
+    /// assert!(cx.is_telemetry_suppressed());
+    /// assert!(!Context::new().is_telemetry_suppressed());
+    /// ```
+    pub fn with_telemetry_suppressed(&self) -> Self {
+        Context {
+            suppress_telemetry: true,
+            ..self.clone()
+        }
+    }
+
+    /// Returns whether telemetry is suppressed in this context.
+    ///
+    /// See [`with_telemetry_suppressed`] for details.
+    ///
+    /// [`with_telemetry_suppressed`]: Context::with_telemetry_suppressed()
+    pub fn is_telemetry_suppressed(&self) -> bool {
+        self.suppress_telemetry
+    }
+
+    /// Suppresses telemetry in the current context until the returned guard
+    /// is dropped.
+    ///
+    /// This is a shorthand for
+    /// `Context::current().with_telemetry_suppressed().attach()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use opentelemetry::Context;
+    ///
+    /// {
+    ///     let _guard = Context::enter_telemetry_suppressed();
+    ///     assert!(Context::is_current_telemetry_suppressed());
+    /// }
+    /// assert!(!Context::is_current_telemetry_suppressed());
+    /// ```
+    pub fn enter_telemetry_suppressed() -> ContextGuard {
+        Context::map_current(|cx| cx.with_telemetry_suppressed()).attach()
+    }
+
+    /// Returns whether telemetry is suppressed in the current context.
+    pub fn is_current_telemetry_suppressed() -> bool {
+        Context::map_current(|cx| cx.suppress_telemetry)
+    }
+
     #[cfg(feature = "trace")]
     pub(super) fn current_with_synchronized_span(value: SynchronizedSpan) -> Self {
-        Context {
+        Context::map_current(|cx| Context {
             span: Some(Arc::new(value)),
-            entries: Context::map_current(|cx| cx.entries.clone()),
-        }
+            entries: cx.entries.clone(),
+            suppress_telemetry: cx.suppress_telemetry,
+        })
     }
 
     #[cfg(feature = "trace")]
@@ -321,6 +415,7 @@ impl Context {
         Context {
             span: Some(Arc::new(value)),
             entries: self.entries.clone(),
+            suppress_telemetry: self.suppress_telemetry,
         }
     }
 }
@@ -329,6 +424,7 @@ impl fmt::Debug for Context {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Context")
             .field("entries", &self.entries.len())
+            .field("suppress_telemetry", &self.suppress_telemetry)
             .finish()
     }
 }
@@ -413,4 +509,24 @@ mod tests {
             true
         }));
     }
+
+    #[test]
+    fn suppressed_telemetry() {
+        #[derive(Debug, PartialEq)]
+        struct ValueA(&'static str);
+        let _outer_guard = Context::new().with_value(ValueA("a")).attach();
+        assert!(!Context::is_current_telemetry_suppressed());
+
+        {
+            let _guard = Context::enter_telemetry_suppressed();
+            let current = Context::current();
+            assert!(current.is_telemetry_suppressed());
+            assert_eq!(current.get(), Some(&ValueA("a")));
+
+            // Derived contexts keep telemetry suppressed.
+            assert!(current.with_value(42u64).is_telemetry_suppressed());
+        }
+
+        assert!(!Context::is_current_telemetry_suppressed());
+    }
 }
@@ -0,0 +1,260 @@
+use crate::{Array, KeyValue, StringValue, Value};
+use std::any::type_name;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::error::Error;
+use std::fmt;
+
+/// An error recorded as an exception on a span or log record.
+///
+/// The exception is recorded with the attributes of the [exception semantic
+/// conventions]:
+///
+/// - `exception.type`: the [type name] of the error, unless it is only known
+///   as a trait object.
+/// - `exception.message`: the [`Display`] representation of the error.
+/// - `exception.stacktrace`: the backtrace of the exception, if one was
+///   [captured].
+/// - `exception.escaped`: whether the exception escaped the scope of the span.
+///
+/// and `exception.source_chain`, the messages of the errors returned by
+/// [`Error::source`] in order, if the error has a source.
+///
+/// A backtrace set with [`with_backtrace`] is preferred, e.g. the backtrace
+/// captured where the error was created. Otherwise a backtrace of the caller is
+/// captured when recording the exception, which depends on the
+/// `RUST_LIB_BACKTRACE` or `RUST_BACKTRACE` environment variables, see
+/// [`Backtrace::capture`].
+///
+/// # Examples
+///
+/// ```
+/// use opentelemetry::{global, trace::{Span, Tracer}, Exception};
+///
+/// let mut span = global::tracer("my-component").start("read-config");
+///
+/// if let Err(err) = std::fs::read_to_string("config.toml") {
+///     span.record_exception(Exception::new(&err).with_escaped(true));
+/// }
+/// ```
+///
+/// [exception semantic conventions]: https://github.com/open-telemetry/semantic-conventions/blob/v1.26.0/docs/exceptions/exceptions-spans.md
+/// [type name]: std::any::type_name
+/// [`Display`]: std::fmt::Display
+/// [captured]: BacktraceStatus::Captured
+/// [`with_backtrace`]: Exception::with_backtrace
+#[derive(Clone, Copy)]
+pub struct Exception<'a> {
+    error: &'a (dyn Error + 'a),
+    error_type: Option<&'static str>,
+    backtrace: Option<&'a Backtrace>,
+    escaped: bool,
+}
+
+impl<'a> Exception<'a> {
+    /// Creates an exception for the given error.
+    ///
+    /// Errors only known as trait objects, e.g. a `Box<dyn Error>`, are
+    /// converted with [`From`] and recorded without `exception.type`.
+    pub fn new<E: Error + 'a>(error: &'a E) -> Self {
+        Exception {
+            error,
+            error_type: Some(type_name::<E>()),
+            backtrace: None,
+            escaped: false,
+        }
+    }
+
+    fn from_dyn(error: &'a (dyn Error + 'a)) -> Self {
+        Exception {
+            error,
+            error_type: None,
+            backtrace: None,
+            escaped: false,
+        }
+    }
+
+    /// Sets the backtrace of the exception.
+    ///
+    /// Backtraces which were not [captured] are ignored.
+    ///
+    /// [captured]: BacktraceStatus::Captured
+    pub fn with_backtrace(self, backtrace: &'a Backtrace) -> Self {
+        Exception {
+            backtrace: Some(backtrace),
+            ..self
+        }
+    }
+
+    /// Sets whether the exception escaped the scope of the span, e.g. because
+    /// it is returned by the function the span covers.
+    pub fn with_escaped(self, escaped: bool) -> Self {
+        Exception { escaped, ..self }
+    }
+
+    /// Returns the attributes describing this exception.
+    pub fn attributes(&self) -> Vec<KeyValue> {
+        let mut attributes = Vec::with_capacity(5);
+        if let Some(error_type) = self.error_type {
+            attributes.push(KeyValue::new("exception.type", error_type));
+        }
+        attributes.push(KeyValue::new("exception.message", self.error.to_string()));
+
+        let captured;
+        let backtrace = match self.backtrace {
+            Some(backtrace) => backtrace,
+            None => {
+                captured = Backtrace::capture();
+                &captured
+            }
+        };
+        if backtrace.status() == BacktraceStatus::Captured {
+            attributes.push(KeyValue::new("exception.stacktrace", backtrace.to_string()));
+        }
+
+        let mut source_chain = Vec::new();
+        let mut source = self.error.source();
+        while let Some(error) = source {
+            source_chain.push(StringValue::from(error.to_string()));
+            source = error.source();
+        }
+        if !source_chain.is_empty() {
+            attributes.push(KeyValue::new(
+                "exception.source_chain",
+                Value::Array(Array::String(source_chain)),
+            ));
+        }
+
+        attributes.push(KeyValue::new("exception.escaped", self.escaped));
+        attributes
+    }
+}
+
+impl<'a, E: Error + 'a> From<&'a E> for Exception<'a> {
+    fn from(error: &'a E) -> Self {
+        Exception::new(error)
+    }
+}
+
+impl<'a, 'b: 'a> From<&'a (dyn Error + 'b)> for Exception<'a> {
+    fn from(error: &'a (dyn Error + 'b)) -> Self {
+        Exception::from_dyn(error)
+    }
+}
+
+impl<'a, 'b: 'a> From<&'a (dyn Error + Send + 'b)> for Exception<'a> {
+    fn from(error: &'a (dyn Error + Send + 'b)) -> Self {
+        Exception::from_dyn(error)
+    }
+}
+
+impl<'a, 'b: 'a> From<&'a (dyn Error + Send + Sync + 'b)> for Exception<'a> {
+    fn from(error: &'a (dyn Error + Send + Sync + 'b)) -> Self {
+        Exception::from_dyn(error)
+    }
+}
+
+impl fmt::Debug for Exception<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Exception")
+            .field("error", &self.error)
+            .field("error_type", &self.error_type)
+            .field("escaped", &self.escaped)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct ConfigError(std::io::Error);
+
+    impl fmt::Display for ConfigError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("failed to read config")
+        }
+    }
+
+    impl Error for ConfigError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    fn attribute(attributes: &[KeyValue], key: &str) -> Option<Value> {
+        attributes
+            .iter()
+            .find(|kv| kv.key.as_str() == key)
+            .map(|kv| kv.value.clone())
+    }
+
+    #[test]
+    fn exception_attributes() {
+        let error = ConfigError(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "config.toml not found",
+        ));
+        let attributes = Exception::new(&error).with_escaped(true).attributes();
+
+        assert_eq!(
+            attribute(&attributes, "exception.type"),
+            Some(Value::from(type_name::<ConfigError>()))
+        );
+        assert_eq!(
+            attribute(&attributes, "exception.message"),
+            Some(Value::from("failed to read config"))
+        );
+        assert_eq!(
+            attribute(&attributes, "exception.source_chain"),
+            Some(Value::Array(Array::String(vec![StringValue::from(
+                "config.toml not found"
+            )])))
+        );
+        assert_eq!(
+            attribute(&attributes, "exception.escaped"),
+            Some(Value::Bool(true))
+        );
+    }
+
+    #[test]
+    fn trait_object_has_no_type() {
+        let error: Box<dyn Error + Send + Sync> = "boxed".into();
+        let attributes = Exception::from(&*error).attributes();
+
+        assert_eq!(attribute(&attributes, "exception.type"), None);
+        assert_eq!(
+            attribute(&attributes, "exception.message"),
+            Some(Value::from("boxed"))
+        );
+        assert_eq!(attribute(&attributes, "exception.source_chain"), None);
+        assert_eq!(
+            attribute(&attributes, "exception.escaped"),
+            Some(Value::Bool(false))
+        );
+    }
+
+    #[test]
+    fn captured_backtrace() {
+        let error = std::fmt::Error;
+        let backtrace = Backtrace::force_capture();
+        let attributes = Exception::new(&error)
+            .with_backtrace(&backtrace)
+            .attributes();
+
+        assert_eq!(
+            attribute(&attributes, "exception.stacktrace"),
+            Some(Value::from(backtrace.to_string()))
+        );
+        assert_eq!(
+            attribute(&attributes, "exception.type"),
+            Some(Value::from("core::fmt::Error"))
+        );
+
+        let disabled = Backtrace::disabled();
+        let attributes = Exception::new(&error)
+            .with_backtrace(&disabled)
+            .attributes();
+        assert_eq!(attribute(&attributes, "exception.stacktrace"), None);
+    }
+}
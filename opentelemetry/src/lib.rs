@@ -197,8 +197,14 @@ mod context;
 
 pub use context::{Context, ContextGuard};
 
+pub mod thread;
+
 mod common;
 
+mod exception;
+
+pub use exception::Exception;
+
 #[cfg(any(feature = "testing", test))]
 #[doc(hidden)]
 pub mod testing;
@@ -1,4 +1,4 @@
-use crate::{Array, Key, StringValue, Value};
+use crate::{Array, Exception, Key, StringValue, Value};
 use std::{borrow::Cow, collections::HashMap, time::SystemTime};
 
 /// SDK implemented trait for managing log records
@@ -41,6 +41,12 @@ pub trait LogRecord {
     where
         K: Into<Key>,
         V: Into<AnyValue>;
+
+    /// Adds the attributes describing an exception, see [`Exception`].
+    fn record_exception<'a>(&mut self, exception: impl Into<Exception<'a>>) {
+        let attributes = exception.into().attributes();
+        self.add_attributes(attributes.into_iter().map(|kv| (kv.key, kv.value)));
+    }
 }
 
 /// Value types for representing arbitrary values in a log record.
@@ -261,6 +261,16 @@ pub trait MeterProvider {
 ///         KeyValue::new("mykey2", "myvalue2"),
 ///     ],
 /// );
+///
+/// // i64 Histogram
+/// let i64_histogram = meter.i64_histogram("my_i64_histogram").init();
+/// i64_histogram.record(
+///     -12,
+///     &[
+///         KeyValue::new("mykey1", "myvalue1"),
+///         KeyValue::new("mykey2", "myvalue2"),
+///     ],
+/// );
 /// ```
 ///
 #[derive(Clone)]
@@ -404,6 +414,14 @@ impl Meter {
     ) -> InstrumentBuilder<'_, Histogram<u64>> {
         InstrumentBuilder::new(self, name.into())
     }
+
+    /// creates an instrument builder for recording a distribution of values.
+    pub fn i64_histogram(
+        &self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> InstrumentBuilder<'_, Histogram<i64>> {
+        InstrumentBuilder::new(self, name.into())
+    }
 }
 
 impl fmt::Debug for Meter {
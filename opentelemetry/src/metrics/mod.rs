@@ -291,6 +291,16 @@ pub trait InstrumentProvider {
     ) -> Result<Histogram<u64>> {
         Ok(Histogram::new(Arc::new(noop::NoopSyncInstrument::new())))
     }
+
+    /// creates an instrument for recording a distribution of values.
+    fn i64_histogram(
+        &self,
+        _name: Cow<'static, str>,
+        _description: Option<Cow<'static, str>>,
+        _unit: Option<Cow<'static, str>>,
+    ) -> Result<Histogram<i64>> {
+        Ok(Histogram::new(Arc::new(noop::NoopSyncInstrument::new())))
+    }
 }
 
 #[cfg(test)]
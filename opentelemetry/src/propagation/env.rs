@@ -0,0 +1,159 @@
+//! Carriers propagating context through environment variables.
+//!
+//! Processes started by an instrumented process, e.g. batch jobs or CLI tools,
+//! continue its trace by reading the context from environment variables like
+//! `TRACEPARENT`, `TRACESTATE` and `BAGGAGE`. Carrier keys map to variable
+//! names by upper casing them and replacing characters other than ASCII
+//! letters, digits and `_` with `_`.
+//!
+//! This mapping can't be reversed, so only propagators reading fields with
+//! fixed names are supported. Fields found by scanning the carrier keys for a
+//! prefix, like the `ot-baggage-` or `uberctx-` baggage of the OpenTracing and
+//! Jaeger propagators, are not extracted.
+use super::{Extractor, Injector};
+use std::collections::HashMap;
+use std::process::Command;
+
+/// Returns the environment variable name of a carrier key, e.g. `TRACEPARENT`
+/// for `traceparent`.
+fn env_var_name(key: &str) -> String {
+    key.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Helper for injecting the context into the environment of a child process.
+///
+/// # Examples
+///
+/// ```
+/// use opentelemetry::propagation::{EnvInjector, TextMapPropagator};
+/// use opentelemetry::Context;
+/// use std::process::Command;
+///
+/// # fn example(propagator: &dyn TextMapPropagator) {
+/// let mut command = Command::new("my-job");
+/// propagator.inject_context(&Context::current(), &mut EnvInjector(&mut command));
+/// // command.spawn()
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct EnvInjector<'a>(pub &'a mut Command);
+
+impl<'a> Injector for EnvInjector<'a> {
+    /// Set the environment variable of the key for the process.
+    fn set(&mut self, key: &str, value: String) {
+        self.0.env(env_var_name(key), value);
+    }
+}
+
+/// Helper for extracting the context from environment variables.
+///
+/// # Examples
+///
+/// ```
+/// use opentelemetry::propagation::{EnvExtractor, TextMapPropagator};
+///
+/// # fn example(propagator: &dyn TextMapPropagator) {
+/// // The parent process set `TRACEPARENT`, `TRACESTATE` or `BAGGAGE`.
+/// let cx = propagator.extract(&EnvExtractor::from_env());
+/// let _guard = cx.attach();
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct EnvExtractor {
+    vars: HashMap<String, String>,
+}
+
+impl EnvExtractor {
+    /// Create an extractor reading the environment variables of the current
+    /// process.
+    ///
+    /// Variables whose name or value isn't valid unicode are ignored.
+    pub fn from_env() -> Self {
+        let vars = std::env::vars_os().filter_map(|(name, value)| {
+            Some((name.into_string().ok()?, value.into_string().ok()?))
+        });
+        EnvExtractor::from_vars(vars)
+    }
+
+    /// Create an extractor reading the given environment variables.
+    pub fn from_vars<I, K, V>(vars: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        EnvExtractor {
+            vars: vars
+                .into_iter()
+                .map(|(name, value)| (name.into(), value.into()))
+                .collect(),
+        }
+    }
+}
+
+impl Extractor for EnvExtractor {
+    /// Get the value of the environment variable of the key.
+    fn get(&self, key: &str) -> Option<&str> {
+        self.vars.get(&env_var_name(key)).map(String::as_str)
+    }
+
+    /// Collect the names of all environment variables.
+    ///
+    /// The names are returned as is, e.g. `OT_BAGGAGE_X`, and don't match the
+    /// carrier keys they were set from.
+    fn keys(&self) -> Vec<&str> {
+        self.vars.keys().map(String::as_str).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_var_names() {
+        assert_eq!(env_var_name("traceparent"), "TRACEPARENT");
+        assert_eq!(env_var_name("uber-trace-id"), "UBER_TRACE_ID");
+        assert_eq!(env_var_name("ot.baggage_x"), "OT_BAGGAGE_X");
+    }
+
+    #[test]
+    fn inject_into_command() {
+        let mut command = Command::new("true");
+        let mut injector = EnvInjector(&mut command);
+        injector.set("traceparent", "value".to_string());
+        injector.set("uber-trace-id", "other".to_string());
+
+        let envs = command
+            .get_envs()
+            .map(|(name, value)| (name.to_str().unwrap(), value.and_then(|v| v.to_str())))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            envs,
+            vec![
+                ("TRACEPARENT", Some("value")),
+                ("UBER_TRACE_ID", Some("other"))
+            ]
+        );
+    }
+
+    #[test]
+    fn extract_from_vars() {
+        let extractor = EnvExtractor::from_vars([("TRACEPARENT", "value"), ("PATH", "/bin")]);
+        assert_eq!(extractor.get("traceparent"), Some("value"));
+        assert_eq!(extractor.get("TraceParent"), Some("value"));
+        assert_eq!(extractor.get("tracestate"), None);
+
+        let mut keys = extractor.keys();
+        keys.sort_unstable();
+        assert_eq!(keys, vec!["PATH", "TRACEPARENT"]);
+    }
+}
@@ -23,9 +23,11 @@ use std::collections::HashMap;
 use thiserror::Error;
 
 pub mod composite;
+pub mod env;
 pub mod text_map_propagator;
 
 pub use composite::TextMapCompositePropagator;
+pub use env::{EnvExtractor, EnvInjector};
 pub use text_map_propagator::TextMapPropagator;
 
 /// Injector provides an interface for adding fields from an underlying struct like `HashMap`
@@ -62,6 +64,30 @@ impl<S: std::hash::BuildHasher> Extractor for HashMap<String, String, S> {
     }
 }
 
+/// Message headers as used by message queue clients, e.g. Kafka record headers
+/// or AMQP message properties, with case insensitive keys.
+impl Injector for Vec<(String, Vec<u8>)> {
+    /// Set a header, replacing the existing values of the key.
+    fn set(&mut self, key: &str, value: String) {
+        self.retain(|(name, _)| !name.eq_ignore_ascii_case(key));
+        self.push((key.to_lowercase(), value.into_bytes()));
+    }
+}
+
+impl Extractor for Vec<(String, Vec<u8>)> {
+    /// Get the first value of a key, if it is valid UTF-8.
+    fn get(&self, key: &str) -> Option<&str> {
+        self.iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .and_then(|(_, value)| std::str::from_utf8(value).ok())
+    }
+
+    /// Collect all header names.
+    fn keys(&self) -> Vec<&str> {
+        self.iter().map(|(name, _)| name.as_str()).collect()
+    }
+}
+
 /// Error when extracting or injecting context data(i.e propagating) across application boundaries.
 #[derive(Error, Debug)]
 #[error("Cannot {} from {}, {}", ops, message, propagator_name)]
@@ -120,4 +146,24 @@ mod tests {
         assert!(got.contains(&"headername1"));
         assert!(got.contains(&"headername2"));
     }
+
+    #[test]
+    fn message_headers_get() {
+        let mut carrier: Vec<(String, Vec<u8>)> = vec![("other".to_string(), b"x".to_vec())];
+        carrier.set("headerName", "value".to_string());
+        carrier.set("HeaderName", "replaced".to_string());
+        carrier.push(("binary".to_string(), vec![0xff, 0xfe]));
+
+        assert_eq!(carrier.len(), 3);
+        assert_eq!(
+            Extractor::get(&carrier, "HEADERNAME"),
+            Some("replaced"),
+            "case insensitive extraction"
+        );
+        assert_eq!(Extractor::get(&carrier, "binary"), None);
+        assert_eq!(
+            Extractor::keys(&carrier),
+            vec!["other", "headername", "binary"]
+        );
+    }
 }
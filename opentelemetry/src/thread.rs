@@ -0,0 +1,139 @@
+//! Context propagation to threads and thread pools.
+//!
+//! The current [`Context`] is thread-local, so work handed to another thread
+//! starts in an empty context and its spans lose their parent. The helpers in
+//! this module capture the current context and attach it on the thread that
+//! runs the work. See [`Context::wrap_fn`] to wrap closures for other
+//! executors, e.g. `tokio::task::spawn_blocking`.
+use crate::Context;
+use std::thread::JoinHandle;
+
+/// Spawns a new thread running `f` with the current context attached.
+///
+/// This is equivalent to `std::thread::spawn(Context::wrap_fn(f))`.
+///
+/// # Examples
+///
+/// ```
+/// use opentelemetry::{thread::spawn_with_context, Context};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct ValueA(&'static str);
+///
+/// let _guard = Context::new().with_value(ValueA("a")).attach();
+///
+/// let value = spawn_with_context(|| Context::current().get::<ValueA>().map(|v| v.0))
+///     .join()
+///     .unwrap();
+/// assert_eq!(value, Some("a"));
+/// ```
+pub fn spawn_with_context<F, T>(f: F) -> JoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    std::thread::spawn(Context::wrap_fn(f))
+}
+
+/// Extension trait propagating the current context to a rayon
+/// [`ThreadPool`](rayon::ThreadPool).
+///
+/// The context is only attached to the closure passed to the pool. Work split
+/// off by parallel iterators or `join` inside of it may run on other threads of
+/// the pool, which should attach a clone of the context themselves:
+///
+/// ```
+/// use opentelemetry::Context;
+/// use rayon::prelude::*;
+///
+/// let cx = Context::current();
+/// let sum: u64 = (0..100u64)
+///     .into_par_iter()
+///     .map(|i| {
+///         let _guard = cx.clone().attach();
+///         i * 2
+///     })
+///     .sum();
+/// assert_eq!(sum, 9900);
+/// ```
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub trait ThreadPoolExt {
+    /// Executes `op` within the thread pool with the current context attached,
+    /// like [`ThreadPool::install`](rayon::ThreadPool::install).
+    fn install_with_context<OP, R>(&self, op: OP) -> R
+    where
+        OP: FnOnce() -> R + Send,
+        R: Send;
+
+    /// Spawns `op` into the thread pool with the current context attached,
+    /// like [`ThreadPool::spawn`](rayon::ThreadPool::spawn).
+    fn spawn_with_context<OP>(&self, op: OP)
+    where
+        OP: FnOnce() + Send + 'static;
+}
+
+#[cfg(feature = "rayon")]
+impl ThreadPoolExt for rayon::ThreadPool {
+    fn install_with_context<OP, R>(&self, op: OP) -> R
+    where
+        OP: FnOnce() -> R + Send,
+        R: Send,
+    {
+        self.install(Context::wrap_fn(op))
+    }
+
+    fn spawn_with_context<OP>(&self, op: OP)
+    where
+        OP: FnOnce() + Send + 'static,
+    {
+        self.spawn(Context::wrap_fn(op))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct ValueA(&'static str);
+
+    #[test]
+    fn spawned_thread_inherits_context() {
+        let _guard = Context::new().with_value(ValueA("a")).attach();
+
+        let value = spawn_with_context(|| Context::current().get::<ValueA>().map(|v| v.0))
+            .join()
+            .unwrap();
+        assert_eq!(value, Some("a"));
+
+        let value = std::thread::spawn(|| Context::current().get::<ValueA>().map(|v| v.0))
+            .join()
+            .unwrap();
+        assert_eq!(value, None);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn thread_pool_inherits_context() {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .unwrap();
+        let _guard = Context::new().with_value(ValueA("a")).attach();
+
+        let value = pool.install_with_context(|| Context::current().get::<ValueA>().map(|v| v.0));
+        assert_eq!(value, Some("a"));
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        pool.spawn_with_context(move || {
+            let value = Context::current().get::<ValueA>().map(|v| v.0);
+            sender.send(value).unwrap();
+        });
+        assert_eq!(receiver.recv().unwrap(), Some("a"));
+
+        // the pool thread is back in an empty context afterwards
+        let value = pool.install(|| Context::current().get::<ValueA>().map(|v| v.0));
+        assert_eq!(value, None);
+    }
+}
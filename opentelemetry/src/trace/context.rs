@@ -2,7 +2,7 @@
 use crate::{
     global,
     trace::{Span, SpanContext, Status},
-    Context, ContextGuard, KeyValue,
+    Context, ContextGuard, Exception, KeyValue,
 };
 use futures_core::stream::Stream;
 use futures_sink::Sink;
@@ -87,6 +87,15 @@ impl SpanRef<'_> {
         self.with_inner_mut(|inner| inner.record_error(err))
     }
 
+    /// Record an exception as an event for this span.
+    ///
+    /// See [`Span::record_exception`] for details.
+    ///
+    /// If this span is not being recorded then this method does nothing.
+    pub fn record_exception<'a>(&self, exception: impl Into<Exception<'a>>) {
+        self.with_inner_mut(|inner| inner.record_exception(exception))
+    }
+
     /// Record an event with a timestamp in the context this span.
     ///
     /// Note that the OpenTelemetry project documents certain "[standard
@@ -1,4 +1,4 @@
-use crate::{trace::SpanContext, KeyValue};
+use crate::{trace::SpanContext, Exception, KeyValue};
 use std::borrow::Cow;
 use std::error::Error;
 use std::time::SystemTime;
@@ -76,6 +76,22 @@ pub trait Span {
         }
     }
 
+    /// Record an exception as an event for this span.
+    ///
+    /// The event is recorded with the attributes described in [`Exception`],
+    /// including the error type, its source chain and a backtrace if one is
+    /// available, while [Span::record_error] only records the error message.
+    ///
+    /// An additional call to [Span::set_status] is required if the status of the
+    /// span should be set to error, as this method does not change the span status.
+    ///
+    /// If this span is not being recorded then this method does nothing.
+    fn record_exception<'a>(&mut self, exception: impl Into<Exception<'a>>) {
+        if self.is_recording() {
+            self.add_event("exception", exception.into().attributes());
+        }
+    }
+
     /// Record an event with a timestamp in the context this span.
     ///
     /// Note that the OpenTelemetry project documents certain "[standard
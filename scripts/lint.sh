@@ -11,11 +11,17 @@ cargo_feature() {
 
 if rustup component add clippy; then
  crates=( "opentelemetry"
+                "opentelemetry-aws"
                 "opentelemetry-http"
                 "opentelemetry-jaeger-propagator"
+                "opentelemetry-ot-propagator"
+                "opentelemetry-macros"
                 "opentelemetry-appender-log"
                 "opentelemetry-appender-tracing"
+                "opentelemetry-metrics-bridge"
+                "opentelemetry-runtime-metrics"
                 "opentelemetry-otlp"
+                "opentelemetry-otlp-replay"
                 "opentelemetry-prometheus"
                 "opentelemetry-proto"
                 "opentelemetry-sdk"
@@ -29,6 +35,7 @@ if rustup component add clippy; then
   done
 
   cargo_feature opentelemetry "trace,metrics,logs,logs_level_enabled,testing"
+  cargo_feature opentelemetry "rayon"
 
   cargo_feature opentelemetry-otlp "default"
   cargo_feature opentelemetry-otlp "default,tls"
@@ -39,8 +46,16 @@ if rustup component add clippy; then
   cargo_feature opentelemetry-otlp "http-proto, reqwest-rustls"
   cargo_feature opentelemetry-otlp "metrics"
 
+  cargo_feature opentelemetry-http "tower"
+  cargo_feature opentelemetry-http "grpc"
+  cargo_feature opentelemetry-http "instrumented-client"
+
   cargo_feature opentelemetry-jaeger-propagator "default"
 
+  cargo_feature opentelemetry-aws "default"
+
+  cargo_feature opentelemetry-ot-propagator "default"
+
   cargo_feature opentelemetry-proto "default"
   cargo_feature opentelemetry-proto "full"
   cargo_feature opentelemetry-proto "gen-tonic,trace"
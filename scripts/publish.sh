@@ -5,7 +5,10 @@ packages=(
     "opentelemetry-http"
     "opentelemetry-semantic-conventions"
     "opentelemetry-jaeger-propagator"
+    "opentelemetry-ot-propagator"
+    "opentelemetry-macros"
     "opentelemetry-sdk"    
+    "opentelemetry-aws"
     "opentelemetry-proto"
     "opentelemetry-otlp"
     "opentelemetry-stdout"
@@ -13,6 +16,8 @@ packages=(
     "opentelemetry-prometheus"
     "opentelemetry-appender-log"
     "opentelemetry-appender-tracing"
+    "opentelemetry-metrics-bridge"
+    "opentelemetry-runtime-metrics"
 
     # Add more packages as needed, in the right order. A package should only be published after all it's dependencies have been published
 )